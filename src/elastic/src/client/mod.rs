@@ -141,6 +141,9 @@ Client method                                                 | Elasticsearch AP
 [`bulk`][Client.bulk]                                         | [Bulk][docs-bulk]                  | [`BulkRequest`][BulkRequest]                            | [`BulkResponse`][BulkResponse]
 [`ping`][Client.ping]                                         | -                                  | [`PingRequest`][PingRequest]                            | [`PingResponse`][PingResponse]
 [`sql`][Client.sql]                                           | [SQL][docs-sql]                    | [`SqlQueryRequest`][SqlQueryRequest]                    | [`SqlQueryResponse`][SqlQueryResponse]
+[`update_aliases`][Client.update_aliases]                     | [Aliases][docs-aliases]            | [`IndicesUpdateAliasesRequest`][IndicesUpdateAliasesRequest] | [`CommandResponse`][CommandResponse]
+[`reindex`][Client.reindex]                                   | [Reindex][docs-reindex]            | [`ReindexRequest`][ReindexRequest]                      | [`BulkByScrollResponse`][BulkByScrollResponse]
+[`reindex_rethrottle`][Client.reindex_rethrottle]             | [Rethrottle][docs-reindex-rethrottle] | [`ReindexRethrottleRequest`][ReindexRethrottleRequest] | [`ReindexRethrottleResponse`][ReindexRethrottleResponse]
 
 ## Document requests
 
@@ -150,10 +153,20 @@ Client method                                                 | Elasticsearch AP
 ------------------------------------------------------------- | ---------------------------------- | ------------------------------------------------------- | ------------------------------------
 [`document.search`][Client.document.search]                   | [Search][docs-search]              | [`SearchRequest`][SearchRequest]                        | [`SearchResponse`][SearchResponse]
 [`document.get`][Client.document.get]                         | [Get Document][docs-get]           | [`GetRequest`][GetRequest]                              | [`GetResponse`][GetResponse]
+[`document.get_source`][Client.document.get_source]           | [Get Source][docs-get-source]      | [`GetSourceRequest`][GetSourceRequest]                  | `TDocument`
+[`document.explain`][Client.document.explain]                 | [Explain][docs-explain]            | [`ExplainRequest`][ExplainRequest]                      | [`ExplainResponse`][ExplainResponse]
+[`document.term_vectors`][Client.document.term_vectors]       | [Term Vectors][docs-termvectors]   | [`TermvectorsRequest`][TermvectorsRequest]              | [`TermVectorsResponse`][TermVectorsResponse]
+[`document.mterm_vectors`][Client.document.mterm_vectors]     | [Multi Term Vectors][docs-mtermvectors] | [`MtermvectorsRequest`][MtermvectorsRequest]       | [`MtermVectorsResponse`][MtermVectorsResponse]
 [`document.index`][Client.document.index]                     | [Index Document][docs-index]       | [`IndexRequest`][IndexRequest]                          | [`IndexResponse`][IndexResponse]
+[`document.create`][Client.document.create]                   | [Create Document][docs-create]     | [`CreateRequest`][CreateRequest]                        | [`IndexResponse`][IndexResponse]
+[`document.exists`][Client.document.exists]                   | [Document Exists][docs-get]        | [`ExistsRequest`][ExistsRequest]                        | `bool`
 [`document.update`][Client.document.update]                   | [Update Document][docs-update]     | [`UpdateRequest`][UpdateRequest]                        | [`UpdateResponse`][UpdateResponse]
 [`document.delete`][Client.document.delete]                   | [Delete Document][docs-delete]     | [`DeleteRequest`][DeleteRequest]                        | [`DeleteResponse`][DeleteResponse]
+[`document.delete_by_query`][Client.document.delete_by_query] | [Delete By Query][docs-delete-by-query] | [`DeleteByQueryRequest`][DeleteByQueryRequest] | [`BulkByScrollResponse`][BulkByScrollResponse]
+[`document.update_by_query`][Client.document.update_by_query] | [Update By Query][docs-update-by-query] | [`UpdateByQueryRequest`][UpdateByQueryRequest] | [`BulkByScrollResponse`][BulkByScrollResponse]
+[`document.modify`][Client.document.modify]                   | [Optimistic Concurrency][docs-occ] | [`IndexRequest`][IndexRequest]                          | [`IndexResponse`][IndexResponse]
 [`document.put_mapping`][Client.document.put_mapping]         | [Put Mapping][docs-mapping]        | [`IndicesPutMappingRequest`][IndicesPutMappingRequest]  | [`CommandResponse`][CommandResponse]
+[`document.check_mapping`][Client.document.check_mapping]     | [Get Mapping][docs-get-mapping]    | [`IndicesGetMappingRequest`][IndicesGetMappingRequest]  | [`MappingDiff`][MappingDiff]
 
 ## Index requests
 
@@ -166,6 +179,55 @@ Client method                                                 | Elasticsearch AP
 [`index.close`][Client.index.close]                           | [Close Index][docs-close-index]    | [`IndicesCloseRequest`][IndicesCloseRequest]            | [`CommandResponse`][CommandResponse]
 [`index.delete`][Client.index.delete]                         | [Delete Index][docs-delete-index]  | [`IndicesDeleteRequest`][IndicesDeleteRequest]          | [`CommandResponse`][CommandResponse]
 [`index.exists`][Client.index.exists]                         | [Index Exists][docs-index-exists]  | [`IndicesExistsRequest`][IndicesExistsRequest]          | [`IndicesExistsResponse`][IndicesExistsResponse]
+[`index.put_alias`][Client.index.put_alias]                   | [Aliases][docs-aliases]            | [`IndicesPutAliasRequest`][IndicesPutAliasRequest]      | [`CommandResponse`][CommandResponse]
+[`index.get_aliases`][Client.index.get_aliases]               | [Aliases][docs-aliases]            | [`IndicesGetAliasRequest`][IndicesGetAliasRequest]      | [`IndicesGetAliasResponse`][IndicesGetAliasResponse]
+[`index.delete_alias`][Client.index.delete_alias]             | [Aliases][docs-aliases]            | [`IndicesDeleteAliasRequest`][IndicesDeleteAliasRequest] | [`CommandResponse`][CommandResponse]
+[`index.alias_exists`][Client.index.alias_exists]             | [Aliases][docs-aliases]            | [`IndicesExistsAliasRequest`][IndicesExistsAliasRequest] | [`IndicesExistsAliasResponse`][IndicesExistsAliasResponse]
+[`index.get_settings`][Client.index.get_settings]             | [Get Settings][docs-get-settings]  | [`IndicesGetSettingsRequest`][IndicesGetSettingsRequest] | [`IndicesGetSettingsResponse`][IndicesGetSettingsResponse]
+[`index.put_settings`][Client.index.put_settings]             | [Update Settings][docs-update-settings] | [`IndicesPutSettingsRequest`][IndicesPutSettingsRequest] | [`CommandResponse`][CommandResponse]
+[`index.get_mapping`][Client.index.get_mapping]               | [Get Mapping][docs-get-mapping]    | [`IndicesGetMappingRequest`][IndicesGetMappingRequest]  | [`IndicesGetMappingResponse`][IndicesGetMappingResponse]
+[`index.get_field_mapping`][Client.index.get_field_mapping]   | [Get Field Mapping][docs-get-field-mapping] | [`IndicesGetFieldMappingRequest`][IndicesGetFieldMappingRequest] | [`IndicesGetFieldMappingResponse`][IndicesGetFieldMappingResponse]
+[`index.stats`][Client.index.stats]                           | [Index Stats][docs-stats]          | [`IndicesStatsRequest`][IndicesStatsRequest]            | [`IndicesStatsResponse`][IndicesStatsResponse]
+[`index.segments`][Client.index.segments]                     | [Index Segments][docs-segments]    | [`IndicesSegmentsRequest`][IndicesSegmentsRequest]      | [`IndicesSegmentsResponse`][IndicesSegmentsResponse]
+[`index.rollover`][Client.index.rollover]                     | [Rollover Index][docs-rollover]    | [`IndicesRolloverRequest`][IndicesRolloverRequest]      | [`IndicesRolloverResponse`][IndicesRolloverResponse]
+[`index.shrink`][Client.index.shrink]                         | [Shrink Index][docs-shrink]        | [`IndicesShrinkRequest`][IndicesShrinkRequest]          | [`IndicesResizeResponse`][IndicesResizeResponse]
+[`index.split`][Client.index.split]                           | [Split Index][docs-split]          | [`IndicesSplitRequest`][IndicesSplitRequest]            | [`IndicesResizeResponse`][IndicesResizeResponse]
+[`index.refresh`][Client.index.refresh]                       | [Refresh][docs-refresh]            | [`IndicesRefreshRequest`][IndicesRefreshRequest]        | [`IndicesShardsResponse`][IndicesShardsResponse]
+[`index.flush`][Client.index.flush]                           | [Flush][docs-flush]                | [`IndicesFlushRequest`][IndicesFlushRequest]            | [`IndicesShardsResponse`][IndicesShardsResponse]
+[`index.forcemerge`][Client.index.forcemerge]                 | [Force Merge][docs-forcemerge]     | [`IndicesForcemergeRequest`][IndicesForcemergeRequest]  | [`IndicesShardsResponse`][IndicesShardsResponse]
+[`index.clear_cache`][Client.index.clear_cache]               | [Clear Cache][docs-clear-cache]    | [`IndicesClearCacheRequest`][IndicesClearCacheRequest]  | [`IndicesShardsResponse`][IndicesShardsResponse]
+
+## Index template requests
+
+These request methods are called on an [`IndexTemplateClient`][`IndexTemplateClient`].
+
+Client method                                                 | Elasticsearch API                  | Raw request type                                        | Response type
+------------------------------------------------------------- | ---------------------------------- | ------------------------------------------------------- | ------------------------------------
+[`index_template.put`][Client.index_template.put]             | [Index Templates][docs-templates]  | [`IndicesPutTemplateRequest`][IndicesPutTemplateRequest] | [`CommandResponse`][CommandResponse]
+[`index_template.get`][Client.index_template.get]             | [Index Templates][docs-templates]  | [`IndicesGetTemplateRequest`][IndicesGetTemplateRequest] | [`IndicesGetTemplateResponse`][IndicesGetTemplateResponse]
+[`index_template.delete`][Client.index_template.delete]       | [Index Templates][docs-templates]  | [`IndicesDeleteTemplateRequest`][IndicesDeleteTemplateRequest] | [`CommandResponse`][CommandResponse]
+[`index_template.exists`][Client.index_template.exists]       | [Index Templates][docs-templates]  | [`IndicesExistsTemplateRequest`][IndicesExistsTemplateRequest] | [`IndicesExistsTemplateResponse`][IndicesExistsTemplateResponse]
+
+## Cluster requests
+
+These request methods are called on a [`ClusterClient`][`ClusterClient`].
+
+Client method                                                 | Elasticsearch API                  | Raw request type                                        | Response type
+------------------------------------------------------------- | ---------------------------------- | ------------------------------------------------------- | ------------------------------------
+[`cluster.health`][Client.cluster.health]                     | [Cluster Health][docs-cluster-health] | [`ClusterHealthRequest`][ClusterHealthRequest]       | [`ClusterHealthResponse`][ClusterHealthResponse]
+[`cluster.get_settings`][Client.cluster.get_settings]         | [Cluster Get Settings][docs-cluster-get-settings] | [`ClusterGetSettingsRequest`][ClusterGetSettingsRequest] | [`ClusterGetSettingsResponse`][ClusterGetSettingsResponse]
+[`cluster.put_settings`][Client.cluster.put_settings]         | [Cluster Update Settings][docs-cluster-update-settings] | [`ClusterPutSettingsRequest`][ClusterPutSettingsRequest] | [`ClusterPutSettingsResponse`][ClusterPutSettingsResponse]
+[`cluster.state`][Client.cluster.state]                       | [Cluster State][docs-cluster-state] | [`ClusterStateRequest`][ClusterStateRequest]         | [`ClusterStateResponse`][ClusterStateResponse]
+
+## Task requests
+
+These request methods are called on a [`TasksClient`][`TasksClient`].
+
+Client method                                                 | Elasticsearch API                  | Raw request type                                        | Response type
+------------------------------------------------------------- | ---------------------------------- | ------------------------------------------------------- | ------------------------------------
+[`tasks.list`][Client.tasks.list]                             | [Task Management][docs-tasks]      | [`TasksListRequest`][TasksListRequest]                  | [`TasksListResponse`][TasksListResponse]
+[`tasks.get`][Client.tasks.get]                               | [Task Management][docs-tasks]      | [`TasksGetRequest`][TasksGetRequest]                    | [`TasksGetResponse`][TasksGetResponse]
+[`tasks.cancel`][Client.tasks.cancel]                         | [Task Management][docs-tasks]      | [`TasksCancelRequest`][TasksCancelRequest]              | [`TasksCancelResponse`][TasksCancelResponse]
 
 All builders follow a standard pattern:
 
@@ -461,8 +523,18 @@ For more details see the [`responses`][responses-mod] module.
 [docs-search]: http://www.elastic.co/guide/en/elasticsearch/reference/current/search-search.html
 [docs-sql]: https://www.elastic.co/guide/en/elasticsearch/reference/current/sql-spec.html
 [docs-get]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html
+[docs-get-source]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html#_source
+[docs-explain]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html
+[docs-termvectors]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-termvectors.html
+[docs-mtermvectors]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-termvectors.html
 [docs-update]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update.html
+[docs-create]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-index_.html#operation-type
+[docs-occ]: https://www.elastic.co/guide/en/elasticsearch/reference/current/optimistic-concurrency-control.html
 [docs-delete]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete.html
+[docs-delete-by-query]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html
+[docs-update-by-query]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html
+[docs-reindex]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html
+[docs-reindex-rethrottle]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html#docs-reindex-rethrottle
 [docs-index]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-index_.html
 [docs-mapping]: https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping.html
 [docs-create-index]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-create-index.html
@@ -470,6 +542,26 @@ For more details see the [`responses`][responses-mod] module.
 [docs-open-index]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-open-close.html
 [docs-index-exists]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-exists.html
 [docs-delete-index]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-delete-index.html
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+[docs-get-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-settings.html
+[docs-update-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-update-settings.html
+[docs-rollover]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-rollover-index.html
+[docs-shrink]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-shrink-index.html
+[docs-split]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-split-index.html
+[docs-refresh]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-refresh.html
+[docs-flush]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-flush.html
+[docs-forcemerge]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-forcemerge.html
+[docs-clear-cache]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-clearcache.html
+[docs-get-mapping]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html
+[docs-get-field-mapping]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-field-mapping.html
+[docs-stats]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-stats.html
+[docs-segments]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-segments.html
+[docs-cluster-health]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-health.html
+[docs-cluster-get-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-get-settings.html
+[docs-cluster-update-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-update-settings.html
+[docs-cluster-state]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-state.html
+[docs-tasks]: https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html
 
 [tokio]: https://tokio.rs
 
@@ -482,21 +574,65 @@ For more details see the [`responses`][responses-mod] module.
 [`Client`]: struct.Client.html
 [`DocumentClient`]: struct.DocumentClient.html
 [`IndexClient`]: struct.IndexClient.html
+[`IndexTemplateClient`]: struct.IndexTemplateClient.html
+[`ClusterClient`]: struct.ClusterClient.html
+[`TasksClient`]: struct.TasksClient.html
 [Client.request]: struct.Client.html#method.request
 [Client.bulk]: struct.Client.html#bulk-request
 [Client.search]: struct.Client.html#search-request
 [Client.sql]: struct.Client.html#sql-request
+[Client.update_aliases]: struct.Client.html#update-aliases-request
+[Client.reindex]: struct.Client.html#reindex-request
+[Client.reindex_rethrottle]: struct.Client.html#reindex-rethrottle-request
 [Client.document.search]: struct.DocumentClient.html#search-request
 [Client.document.get]: struct.DocumentClient.html#get-document-request
+[Client.document.get_source]: struct.DocumentClient.html#get-source-request
+[Client.document.explain]: struct.DocumentClient.html#explain-request
+[Client.document.term_vectors]: struct.DocumentClient.html#term-vectors-request
+[Client.document.mterm_vectors]: struct.DocumentClient.html#multi-term-vectors-request
 [Client.document.update]: struct.DocumentClient.html#update-document-request
+[Client.document.create]: struct.DocumentClient.html#create-document-request
+[Client.document.exists]: struct.DocumentClient.html#document-exists-request
 [Client.document.delete]: struct.DocumentClient.html#delete-document-request
+[Client.document.delete_by_query]: struct.DocumentClient.html#delete-by-query-request
+[Client.document.update_by_query]: struct.DocumentClient.html#update-by-query-request
+[Client.document.modify]: struct.DocumentClient.html#modify-document-request
 [Client.document.index]: struct.DocumentClient.html#index-document-request
 [Client.document.put_mapping]: struct.DocumentClient.html#method.put_mapping
+[Client.document.check_mapping]: struct.DocumentClient.html#method.check_mapping
 [Client.index.create]: struct.IndexClient.html#create-index-request
 [Client.index.open]: struct.IndexClient.html#open-index-request
 [Client.index.close]: struct.IndexClient.html#close-index-request
 [Client.index.delete]: struct.IndexClient.html#delete-index-request
 [Client.index.exists]: struct.IndexClient.html#index-exists-request
+[Client.index.put_alias]: struct.IndexClient.html#put-alias-request
+[Client.index.get_aliases]: struct.IndexClient.html#get-alias-request
+[Client.index.delete_alias]: struct.IndexClient.html#delete-alias-request
+[Client.index.alias_exists]: struct.IndexClient.html#alias-exists-request
+[Client.index.get_settings]: struct.IndexClient.html#get-index-settings-request
+[Client.index.put_settings]: struct.IndexClient.html#put-index-settings-request
+[Client.index.rollover]: struct.IndexClient.html#rollover-index-request
+[Client.index.shrink]: struct.IndexClient.html#shrink-index-request
+[Client.index.split]: struct.IndexClient.html#split-index-request
+[Client.index.refresh]: struct.IndexClient.html#refresh-index-request
+[Client.index.flush]: struct.IndexClient.html#flush-index-request
+[Client.index.forcemerge]: struct.IndexClient.html#force-merge-index-request
+[Client.index.clear_cache]: struct.IndexClient.html#clear-index-cache-request
+[Client.index.get_mapping]: struct.IndexClient.html#get-mapping-request
+[Client.index.get_field_mapping]: struct.IndexClient.html#get-field-mapping-request
+[Client.index.stats]: struct.IndexClient.html#index-stats-request
+[Client.index.segments]: struct.IndexClient.html#index-segments-request
+[Client.index_template.put]: struct.IndexTemplateClient.html#put-index-template-request
+[Client.index_template.get]: struct.IndexTemplateClient.html#get-index-template-request
+[Client.index_template.delete]: struct.IndexTemplateClient.html#delete-index-template-request
+[Client.index_template.exists]: struct.IndexTemplateClient.html#index-template-exists-request
+[Client.cluster.health]: struct.ClusterClient.html#cluster-health-request
+[Client.cluster.get_settings]: struct.ClusterClient.html#get-cluster-settings-request
+[Client.cluster.put_settings]: struct.ClusterClient.html#update-cluster-settings-request
+[Client.cluster.state]: struct.ClusterClient.html#cluster-state-request
+[Client.tasks.list]: struct.TasksClient.html#list-tasks-request
+[Client.tasks.get]: struct.TasksClient.html#get-task-request
+[Client.tasks.cancel]: struct.TasksClient.html#cancel-task-request
 [Client.ping]: struct.Client.html#ping-request
 
 [RequestBuilder]: requests/struct.RequestBuilder.html
@@ -506,8 +642,18 @@ For more details see the [`responses`][responses-mod] module.
 [SqlQueryRequest]: ../endpoints/struct.SqlQueryRequest.html
 [BulkRequest]: ../endpoints/struct.BulkRequest.html
 [GetRequest]: ../endpoints/struct.GetRequest.html
+[CreateRequest]: ../endpoints/struct.CreateRequest.html
+[ExistsRequest]: ../endpoints/struct.ExistsRequest.html
+[GetSourceRequest]: ../endpoints/struct.GetSourceRequest.html
+[ExplainRequest]: ../endpoints/struct.ExplainRequest.html
+[TermvectorsRequest]: ../endpoints/struct.TermvectorsRequest.html
+[MtermvectorsRequest]: ../endpoints/struct.MtermvectorsRequest.html
 [UpdateRequest]: ../endpoints/struct.UpdateRequest.html
 [DeleteRequest]: ../endpoints/struct.DeleteRequest.html
+[DeleteByQueryRequest]: ../endpoints/struct.DeleteByQueryRequest.html
+[UpdateByQueryRequest]: ../endpoints/struct.UpdateByQueryRequest.html
+[ReindexRequest]: ../endpoints/struct.ReindexRequest.html
+[ReindexRethrottleRequest]: ../endpoints/struct.ReindexRethrottleRequest.html
 [IndexRequest]: ../endpoints/struct.IndexRequest.html
 [IndicesPutMappingRequest]: ../endpoints/struct.IndicesPutMappingRequest.html
 [IndicesCreateRequest]: ../endpoints/struct.IndicesCreateRequest.html
@@ -515,6 +661,35 @@ For more details see the [`responses`][responses-mod] module.
 [IndicesCloseRequest]: ../endpoints/struct.IndicesCloseRequest.html
 [IndicesDeleteRequest]: ../endpoints/struct.IndicesDeleteRequest.html
 [IndicesExistsRequest]: ../endpoints/struct.IndicesExistsRequest.html
+[IndicesUpdateAliasesRequest]: ../endpoints/struct.IndicesUpdateAliasesRequest.html
+[IndicesPutAliasRequest]: ../endpoints/struct.IndicesPutAliasRequest.html
+[IndicesGetAliasRequest]: ../endpoints/struct.IndicesGetAliasRequest.html
+[IndicesDeleteAliasRequest]: ../endpoints/struct.IndicesDeleteAliasRequest.html
+[IndicesExistsAliasRequest]: ../endpoints/struct.IndicesExistsAliasRequest.html
+[IndicesGetSettingsRequest]: ../endpoints/struct.IndicesGetSettingsRequest.html
+[IndicesPutSettingsRequest]: ../endpoints/struct.IndicesPutSettingsRequest.html
+[IndicesRolloverRequest]: ../endpoints/struct.IndicesRolloverRequest.html
+[IndicesShrinkRequest]: ../endpoints/struct.IndicesShrinkRequest.html
+[IndicesSplitRequest]: ../endpoints/struct.IndicesSplitRequest.html
+[IndicesRefreshRequest]: ../endpoints/struct.IndicesRefreshRequest.html
+[IndicesFlushRequest]: ../endpoints/struct.IndicesFlushRequest.html
+[IndicesForcemergeRequest]: ../endpoints/struct.IndicesForcemergeRequest.html
+[IndicesClearCacheRequest]: ../endpoints/struct.IndicesClearCacheRequest.html
+[IndicesGetMappingRequest]: ../endpoints/struct.IndicesGetMappingRequest.html
+[IndicesGetFieldMappingRequest]: ../endpoints/struct.IndicesGetFieldMappingRequest.html
+[IndicesStatsRequest]: ../endpoints/struct.IndicesStatsRequest.html
+[IndicesSegmentsRequest]: ../endpoints/struct.IndicesSegmentsRequest.html
+[ClusterHealthRequest]: ../endpoints/struct.ClusterHealthRequest.html
+[ClusterGetSettingsRequest]: ../endpoints/struct.ClusterGetSettingsRequest.html
+[ClusterPutSettingsRequest]: ../endpoints/struct.ClusterPutSettingsRequest.html
+[ClusterStateRequest]: ../endpoints/struct.ClusterStateRequest.html
+[TasksListRequest]: ../endpoints/struct.TasksListRequest.html
+[TasksGetRequest]: ../endpoints/struct.TasksGetRequest.html
+[TasksCancelRequest]: ../endpoints/struct.TasksCancelRequest.html
+[IndicesPutTemplateRequest]: ../endpoints/struct.IndicesPutTemplateRequest.html
+[IndicesGetTemplateRequest]: ../endpoints/struct.IndicesGetTemplateRequest.html
+[IndicesDeleteTemplateRequest]: ../endpoints/struct.IndicesDeleteTemplateRequest.html
+[IndicesExistsTemplateRequest]: ../endpoints/struct.IndicesExistsTemplateRequest.html
 [PingRequest]: ../endpoints/struct.PingRequest.html
 
 [responses-mod]: responses/index.html
@@ -528,10 +703,35 @@ For more details see the [`responses`][responses-mod] module.
 [SqlQueryResponse]: responses/struct.SqlQueryResponse.html
 [BulkResponse]: responses/struct.BulkResponse.html
 [GetResponse]: responses/struct.GetResponse.html
+[ExplainResponse]: responses/struct.ExplainResponse.html
+[TermVectorsResponse]: responses/struct.TermVectorsResponse.html
+[MtermVectorsResponse]: responses/struct.MtermVectorsResponse.html
 [UpdateResponse]: responses/struct.UpdateResponse.html
 [DeleteResponse]: responses/struct.DeleteResponse.html
+[BulkByScrollResponse]: responses/struct.BulkByScrollResponse.html
+[ReindexRethrottleResponse]: responses/struct.ReindexRethrottleResponse.html
 [IndexResponse]: responses/struct.IndexResponse.html
 [IndicesExistsResponse]: responses/struct.IndicesExistsResponse.html
+[IndicesGetAliasResponse]: responses/struct.IndicesGetAliasResponse.html
+[IndicesExistsAliasResponse]: responses/struct.IndicesExistsAliasResponse.html
+[IndicesGetSettingsResponse]: responses/struct.IndicesGetSettingsResponse.html
+[IndicesRolloverResponse]: responses/struct.IndicesRolloverResponse.html
+[IndicesResizeResponse]: responses/struct.IndicesResizeResponse.html
+[IndicesShardsResponse]: responses/struct.IndicesShardsResponse.html
+[IndicesGetMappingResponse]: responses/struct.IndicesGetMappingResponse.html
+[IndicesGetFieldMappingResponse]: responses/struct.IndicesGetFieldMappingResponse.html
+[IndicesStatsResponse]: responses/struct.IndicesStatsResponse.html
+[IndicesSegmentsResponse]: responses/struct.IndicesSegmentsResponse.html
+[ClusterHealthResponse]: responses/struct.ClusterHealthResponse.html
+[ClusterGetSettingsResponse]: responses/struct.ClusterGetSettingsResponse.html
+[ClusterPutSettingsResponse]: responses/struct.ClusterPutSettingsResponse.html
+[ClusterStateResponse]: responses/struct.ClusterStateResponse.html
+[TasksListResponse]: responses/struct.TasksListResponse.html
+[TasksGetResponse]: responses/struct.TasksGetResponse.html
+[TasksCancelResponse]: responses/struct.TasksCancelResponse.html
+[MappingDiff]: responses/struct.MappingDiff.html
+[IndicesGetTemplateResponse]: responses/struct.IndicesGetTemplateResponse.html
+[IndicesExistsTemplateResponse]: responses/struct.IndicesExistsTemplateResponse.html
 [PingResponse]: responses/struct.PingResponse.html
 [CommandResponse]: responses/struct.CommandResponse.html
 [SyncHttpResponse]: ../http/receiver/struct.SyncHttpResponse.html
@@ -562,7 +762,10 @@ use crate::{
         NodeAddresses,
         Sender,
     },
-    params::Index,
+    params::{
+        Index,
+        Name,
+    },
 };
 
 use std::marker::PhantomData;
@@ -644,6 +847,34 @@ where
             index: index.into(),
         }
     }
+
+    /**
+    Get a client for working with a specific index template.
+    */
+    pub fn index_template(&self, name: impl Into<Name<'static>>) -> IndexTemplateClient<TSender> {
+        IndexTemplateClient {
+            inner: (*self).clone(),
+            name: name.into(),
+        }
+    }
+
+    /**
+    Get a client for working with the cluster.
+    */
+    pub fn cluster(&self) -> ClusterClient<TSender> {
+        ClusterClient {
+            inner: (*self).clone(),
+        }
+    }
+
+    /**
+    Get a client for working with tasks.
+    */
+    pub fn tasks(&self) -> TasksClient<TSender> {
+        TasksClient {
+            inner: (*self).clone(),
+        }
+    }
 }
 
 /**
@@ -668,6 +899,37 @@ pub struct IndexClient<TSender> {
     index: Index<'static>,
 }
 
+/**
+A [`Client`] for a specific index template.
+
+[`Client`]: struct.Client.html
+*/
+#[derive(Clone)]
+pub struct IndexTemplateClient<TSender> {
+    inner: Client<TSender>,
+    name: Name<'static>,
+}
+
+/**
+A [`Client`] for cluster-wide requests.
+
+[`Client`]: struct.Client.html
+*/
+#[derive(Clone)]
+pub struct ClusterClient<TSender> {
+    inner: Client<TSender>,
+}
+
+/**
+A [`Client`] for task management requests.
+
+[`Client`]: struct.Client.html
+*/
+#[derive(Clone)]
+pub struct TasksClient<TSender> {
+    inner: Client<TSender>,
+}
+
 pub mod prelude {
     /*! A glob import for convenience. */
 
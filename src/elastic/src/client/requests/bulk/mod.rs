@@ -8,6 +8,7 @@ use std::{
     error::Error as StdError,
     fmt,
     marker::PhantomData,
+    sync::Arc,
     time::Duration,
 };
 
@@ -70,11 +71,15 @@ Call [`Client.bulk_stream`][Client.bulk_stream] to get a `BulkRequestBuilder` th
 pub type BulkRequestBuilder<TSender, TBody, TResponse> =
     RequestBuilder<TSender, BulkRequestInner<TBody, TResponse>>;
 
+mod ndjson;
 mod operation;
+mod stats;
 mod stream;
 
 pub use self::{
+    ndjson::*,
     operation::*,
+    stats::*,
     stream::*,
 };
 
@@ -483,6 +488,40 @@ impl<TDocument, TResponse> BulkRequestBuilder<AsyncSender, Streamed<TDocument>,
         self
     }
 
+    /**
+    Specify a maximum number of operations in a request.
+
+    This parameter can be used alongside [`body_size_bytes`](#method.body_size_bytes) to control the maximum size of a single bulk request emitted.
+    Operations will be appended to the request until either `max_ops` or the `body_size` is reached.
+    By default there's no limit on the number of operations in a request.
+    */
+    pub fn body_size_ops(mut self, max_ops: usize) -> Self {
+        self.inner.body.with_inner_mut(|s| {
+            s.max_ops = Some(max_ops);
+            Ok(())
+        });
+
+        self
+    }
+
+    /**
+    Specify a callback to invoke after each bulk request completes or fails.
+
+    The callback is given statistics for the individual request.
+    Running totals for the whole stream are available from [`BulkSender.stats`][BulkSender.stats].
+    The callback is invoked on the task polling the `BulkSender`, so it shouldn't block.
+
+    [BulkSender.stats]: struct.BulkSender.html#method.stats
+    */
+    pub fn on_batch(mut self, on_batch: impl Fn(&BulkBatchStats) + Send + Sync + 'static) -> Self {
+        self.inner.body.with_inner_mut(|s| {
+            s.on_batch = Some(Arc::new(on_batch));
+            Ok(())
+        });
+
+        self
+    }
+
     /**
     Create a channel for streaming bulk operations.
 
@@ -503,22 +542,21 @@ impl<TDocument, TResponse> BulkRequestBuilder<AsyncSender, Streamed<TDocument>,
             .expect("building a stream should be infallible");
 
         let body_size = body.body_size;
+        let max_ops = body.max_ops;
         let duration = body.timeout;
+        let on_batch = body.on_batch;
 
         let params = self.params_builder.into_value(RequestParams::default);
-        let body = SenderBody::new(body_size);
+        let body = SenderBody::new(body_size, max_ops);
         let timeout = Timeout::new(duration);
         let req_template =
             SenderRequestTemplate::new(self.client, params, self.inner.index, self.inner.ty);
 
-        BulkSender::new(req_template, timeout, body)
+        BulkSender::new(req_template, timeout, body, on_batch)
     }
 }
 
-impl<TBody, TResponse> BulkRequestInner<TBody, TResponse>
-where
-    TBody: BulkBody,
-{
+impl<TBody, TResponse> BulkRequestInner<TBody, TResponse> {
     fn into_request(self) -> Result<BulkRequest<'static, TBody>, Error> {
         let body = self.body.try_into_inner()?;
 
@@ -606,7 +644,7 @@ where
 */
 impl<TBody, TResponse> BulkRequestBuilder<AsyncSender, TBody, TResponse>
 where
-    TBody: Into<AsyncBody> + BulkBody + Send + 'static,
+    TBody: Into<AsyncBody> + Send + 'static,
     TResponse: DeserializeOwned + IsOk + Send + 'static,
 {
     /**
@@ -683,7 +721,9 @@ A streaming bulk request body.
 */
 pub struct Streamed<TDocument> {
     body_size: usize,
+    max_ops: Option<usize>,
     timeout: Duration,
+    on_batch: Option<OnBatch>,
     _marker: PhantomData<TDocument>,
 }
 
@@ -691,7 +731,9 @@ impl<TDocument> Streamed<TDocument> {
     fn new() -> Self {
         Streamed {
             body_size: DEFAULT_BODY_SIZE,
+            max_ops: None,
             timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+            on_batch: None,
             _marker: PhantomData,
         }
     }
@@ -0,0 +1,929 @@
+use std::{
+    error::Error as StdError,
+    fmt,
+    io::{
+        BufRead,
+        BufReader,
+        Read,
+        Write,
+    },
+};
+
+use futures::{
+    Async,
+    Poll,
+    Stream,
+};
+use serde::ser::Serialize;
+use serde_json::{
+    Map,
+    Value,
+};
+use tokio::{
+    codec::{
+        FramedRead,
+        LinesCodec,
+    },
+    io::AsyncRead,
+};
+
+use super::{
+    Action,
+    BulkBody,
+    BulkOperation,
+};
+use crate::{
+    client::requests::common::VersionType,
+    error::{
+        self,
+        Error,
+    },
+};
+
+/**
+The default maximum length of a line read by a [`BulkReader`][BulkReader] or [`AsyncBulkReader`][AsyncBulkReader].
+
+This is the default maximum size of a request body in Elasticsearch, so a longer line couldn't be sent anyway.
+
+[BulkReader]: struct.BulkReader.html
+[AsyncBulkReader]: struct.AsyncBulkReader.html
+*/
+pub const DEFAULT_MAX_LINE_LENGTH: usize = 100 * 1024 * 1024;
+
+/**
+A reader for bulk operations in the line-delimited [bulk format][docs-bulk].
+
+Each operation is an action line, like `{"index":{"_id":"1"}}`, followed by a source line for every action except `delete`.
+Blank lines are skipped.
+Action lines can set the standard bulk metadata: the index, type, id and routing, versioning with `version` or `if_seq_no` and `if_primary_term`, an ingest `pipeline` and `retry_on_conflict`.
+Operations are read lazily, so the whole source doesn't need to fit in memory.
+Lines longer than [`DEFAULT_MAX_LINE_LENGTH`][DEFAULT_MAX_LINE_LENGTH] bytes are reported as an error instead of being buffered, which can be changed with [`max_line_length`][max_line_length].
+
+# Examples
+
+Read a file of bulk operations and send them in requests of at most 5MB:
+
+```no_run
+# use std::fs::File;
+# use elastic::prelude::*;
+# use elastic::client::requests::bulk::BulkReader;
+# fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+# let client = SyncClientBuilder::new().build()?;
+let file = File::open("fixtures.ndjson")?;
+
+for ops in BulkReader::new(file).chunks(1024 * 1024 * 5) {
+    let response = client.bulk()
+                         .index("myindex")
+                         .extend(ops?)
+                         .send()?;
+
+    assert!(response.is_ok());
+}
+# Ok(())
+# }
+```
+
+[docs-bulk]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html
+[DEFAULT_MAX_LINE_LENGTH]: constant.DEFAULT_MAX_LINE_LENGTH.html
+[max_line_length]: #method.max_line_length
+*/
+pub struct BulkReader<R> {
+    reader: BufReader<R>,
+    max_line_length: usize,
+    parser: LineParser,
+}
+
+impl<R> BulkReader<R>
+where
+    R: Read,
+{
+    /**
+    Create a reader over the given source of bulk operations.
+    */
+    pub fn new(reader: R) -> Self {
+        BulkReader {
+            reader: BufReader::new(reader),
+            max_line_length: DEFAULT_MAX_LINE_LENGTH,
+            parser: LineParser::new(),
+        }
+    }
+
+    /**
+    Specify the maximum length of a line in bytes.
+
+    Reading a line that's longer than `max_line_length` will return an error.
+    */
+    pub fn max_line_length(mut self, max_line_length: usize) -> Self {
+        self.max_line_length = max_line_length;
+        self
+    }
+
+    /**
+    Group the operations into chunks that each fit within a request body of `body_size` bytes.
+
+    The size of a chunk is measured using the lines as they were read.
+    An operation that's larger than `body_size` on its own will be emitted as a chunk by itself.
+    The number of operations in each chunk can also be limited with [`BulkChunks.body_size_ops`][BulkChunks.body_size_ops].
+
+    [BulkChunks.body_size_ops]: struct.BulkChunks.html#method.body_size_ops
+    */
+    pub fn chunks(self, body_size: usize) -> BulkChunks<R> {
+        BulkChunks {
+            reader: self,
+            body_size,
+            max_ops: None,
+            next: None,
+        }
+    }
+
+    fn next_line(&mut self) -> Option<Result<String, Error>> {
+        let mut line = Vec::new();
+
+        // Read at most one byte past the limit so a line that's too long can be detected without buffering all of it
+        let limit = self.max_line_length as u64 + 1;
+
+        match (&mut self.reader).take(limit).read_until(b'\n', &mut line) {
+            Ok(0) => None,
+            Ok(_) => {
+                if line.last() == Some(&b'\n') {
+                    line.pop();
+
+                    if line.last() == Some(&b'\r') {
+                        line.pop();
+                    }
+                } else if line.len() > self.max_line_length {
+                    return Some(Err(read_error(
+                        self.parser.line + 1,
+                        format!("line is longer than {} bytes", self.max_line_length),
+                    )));
+                }
+
+                Some(String::from_utf8(line).map_err(error::request))
+            }
+            Err(e) => Some(Err(error::request(e))),
+        }
+    }
+
+    fn next_parsed(&mut self) -> Option<Result<ParsedOperation, Error>> {
+        loop {
+            match self.next_line() {
+                Some(Ok(line)) => match self.parser.push(&line) {
+                    Ok(Some(op)) => return Some(Ok(op)),
+                    Ok(None) => continue,
+                    Err(e) => return Some(Err(e)),
+                },
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    return match self.parser.finish() {
+                        Ok(()) => None,
+                        Err(e) => Some(Err(e)),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<R> Iterator for BulkReader<R>
+where
+    R: Read,
+{
+    type Item = Result<BulkOperation<Value>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_parsed().map(|op| op.map(|op| op.op))
+    }
+}
+
+/**
+Chunks of bulk operations returned by [`BulkReader.chunks`][BulkReader.chunks].
+
+Each chunk can be pushed onto a single bulk request.
+
+[BulkReader.chunks]: struct.BulkReader.html#method.chunks
+*/
+pub struct BulkChunks<R> {
+    reader: BulkReader<R>,
+    body_size: usize,
+    max_ops: Option<usize>,
+    next: Option<ParsedOperation>,
+}
+
+impl<R> BulkChunks<R> {
+    /**
+    Specify a maximum number of operations in each chunk.
+
+    A chunk will be emitted when either `max_ops` or the `body_size` is reached.
+    A chunk always contains at least one operation.
+    */
+    pub fn body_size_ops(mut self, max_ops: usize) -> Self {
+        self.max_ops = Some(max_ops);
+        self
+    }
+
+    fn is_full(&self, chunk_ops: usize, chunk_size: usize, op: &ParsedOperation) -> bool {
+        let max_ops = self
+            .max_ops
+            .map(|max_ops| chunk_ops >= max_ops)
+            .unwrap_or(false);
+
+        chunk_ops > 0 && (max_ops || chunk_size + op.size > self.body_size)
+    }
+}
+
+impl<R> Iterator for BulkChunks<R>
+where
+    R: Read,
+{
+    type Item = Result<Vec<BulkOperation<Value>>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = Vec::new();
+        let mut size = 0;
+
+        loop {
+            let op = match self.next.take() {
+                Some(op) => op,
+                None => match self.reader.next_parsed() {
+                    Some(Ok(op)) => op,
+                    Some(Err(e)) => return Some(Err(e)),
+                    None => break,
+                },
+            };
+
+            // Keep the operation for the next chunk if it doesn't fit in this one
+            if self.is_full(chunk.len(), size, &op) {
+                self.next = Some(op);
+                break;
+            }
+
+            size += op.size;
+            chunk.push(op.op);
+        }
+
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(Ok(chunk))
+        }
+    }
+}
+
+/**
+An asynchronous reader for bulk operations in the line-delimited [bulk format][docs-bulk].
+
+This is the asynchronous equivalent of a [`BulkReader`][BulkReader].
+The reader is a `Stream` of operations, so it can be sent straight into a [`BulkSender`][BulkSender], which will batch operations according to its `body_size_bytes`.
+Lines longer than [`DEFAULT_MAX_LINE_LENGTH`][DEFAULT_MAX_LINE_LENGTH] bytes are reported as an error instead of being buffered, which can be changed with [`max_line_length`][max_line_length].
+
+# Examples
+
+Stream a file of bulk operations to Elasticsearch:
+
+```no_run
+# use futures::{Future, Sink};
+# use elastic::prelude::*;
+# use elastic::client::requests::bulk::AsyncBulkReader;
+# fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+# let client = AsyncClientBuilder::new().build()?;
+# let file: &[u8] = b"";
+let (bulk_stream, bulk_responses) = client.bulk_stream()
+    .index("myindex")
+    .body_size_bytes(1024 * 1024 * 5)
+    .build();
+
+let req_future = bulk_stream.send_all(AsyncBulkReader::new(file));
+# Ok(())
+# }
+```
+
+[docs-bulk]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html
+[BulkReader]: struct.BulkReader.html
+[BulkSender]: struct.BulkSender.html
+[DEFAULT_MAX_LINE_LENGTH]: constant.DEFAULT_MAX_LINE_LENGTH.html
+[max_line_length]: #method.max_line_length
+*/
+pub struct AsyncBulkReader<R> {
+    lines: FramedRead<R, LinesCodec>,
+    parser: LineParser,
+}
+
+impl<R> AsyncBulkReader<R>
+where
+    R: AsyncRead,
+{
+    /**
+    Create a reader over the given asynchronous source of bulk operations.
+    */
+    pub fn new(reader: R) -> Self {
+        AsyncBulkReader {
+            lines: FramedRead::new(
+                reader,
+                LinesCodec::new_with_max_length(DEFAULT_MAX_LINE_LENGTH),
+            ),
+            parser: LineParser::new(),
+        }
+    }
+
+    /**
+    Specify the maximum length of a line in bytes.
+
+    Reading a line that's longer than `max_line_length` will return an error.
+    This should be called before the reader is polled.
+    */
+    pub fn max_line_length(mut self, max_line_length: usize) -> Self {
+        *self.lines.decoder_mut() = LinesCodec::new_with_max_length(max_line_length);
+        self
+    }
+}
+
+impl<R> Stream for AsyncBulkReader<R>
+where
+    R: AsyncRead,
+{
+    type Item = BulkOperation<Value>;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        loop {
+            match try_ready!(self.lines.poll().map_err(error::request)) {
+                Some(line) => {
+                    if let Some(op) = self.parser.push(&line)? {
+                        return Ok(Async::Ready(Some(op.op)));
+                    }
+                }
+                None => {
+                    self.parser.finish()?;
+                    return Ok(Async::Ready(None));
+                }
+            }
+        }
+    }
+}
+
+/**
+A writer for bulk operations in the line-delimited [bulk format][docs-bulk].
+
+Operations written can be read back later by a [`BulkReader`][BulkReader] for replay.
+The writer isn't buffered, so wrap files in a `BufWriter`.
+
+# Examples
+
+Write some bulk operations to a file:
+
+```no_run
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use std::{fs::File, io::BufWriter};
+# use elastic::prelude::*;
+# use elastic::client::requests::bulk::BulkWriter;
+# fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+# #[derive(Serialize, Deserialize, ElasticType)]
+# struct MyType {
+#     pub id: String,
+#     pub title: String,
+# }
+let mut writer = BulkWriter::new(BufWriter::new(File::create("fixtures.ndjson")?));
+
+for i in 0..1000 {
+    writer.push(bulk::<MyType>().index(MyType {
+        id: i.to_string(),
+        title: "some string value".to_owned()
+    }))?;
+}
+
+writer.flush()?;
+# Ok(())
+# }
+```
+
+[docs-bulk]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html
+[BulkReader]: struct.BulkReader.html
+*/
+pub struct BulkWriter<W> {
+    writer: W,
+}
+
+impl<W> BulkWriter<W>
+where
+    W: Write,
+{
+    /**
+    Create a writer for bulk operations.
+    */
+    pub fn new(writer: W) -> Self {
+        BulkWriter { writer }
+    }
+
+    /**
+    Write an operation.
+
+    # Errors
+
+    If the document can't be serialized or written then this method will return an error.
+    */
+    pub fn push<TDocument>(&mut self, op: BulkOperation<TDocument>) -> Result<(), Error>
+    where
+        TDocument: Serialize,
+    {
+        op.write(&mut self.writer).map_err(error::request)
+    }
+
+    /**
+    Flush the underlying writer.
+    */
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.writer.flush().map_err(error::request)
+    }
+
+    /**
+    Flush and return the underlying writer.
+    */
+    pub fn into_inner(mut self) -> Result<W, Error> {
+        self.flush()?;
+
+        Ok(self.writer)
+    }
+}
+
+impl<W> BulkBody for BulkWriter<W>
+where
+    W: Write,
+{
+    fn push<TDocument>(&mut self, op: BulkOperation<TDocument>) -> Result<(), Error>
+    where
+        TDocument: Serialize,
+    {
+        BulkWriter::push(self, op)
+    }
+}
+
+struct ParsedOperation {
+    op: BulkOperation<Value>,
+    size: usize,
+}
+
+struct PendingOperation {
+    line: usize,
+    action: Action,
+    header: ReadHeader,
+    size: usize,
+}
+
+/**
+Pairs up action and source lines.
+*/
+struct LineParser {
+    line: usize,
+    pending: Option<PendingOperation>,
+}
+
+impl LineParser {
+    fn new() -> Self {
+        LineParser {
+            line: 0,
+            pending: None,
+        }
+    }
+
+    fn push(&mut self, line: &str) -> Result<Option<ParsedOperation>, Error> {
+        self.line += 1;
+
+        let size = line.len() + 1;
+        let line = line.trim();
+
+        if line.is_empty() {
+            return Ok(None);
+        }
+
+        match self.pending.take() {
+            None => {
+                let (action, header) =
+                    parse_action(line).map_err(|reason| read_error(self.line, reason))?;
+
+                match action {
+                    Action::Delete => Ok(Some(ParsedOperation {
+                        op: header.into_operation(action, None),
+                        size,
+                    })),
+                    action => {
+                        self.pending = Some(PendingOperation {
+                            line: self.line,
+                            action,
+                            header,
+                            size,
+                        });
+
+                        Ok(None)
+                    }
+                }
+            }
+            Some(pending) => {
+                let source = parse_source(line).map_err(|reason| read_error(self.line, reason))?;
+
+                Ok(Some(ParsedOperation {
+                    op: pending.header.into_operation(pending.action, Some(source)),
+                    size: pending.size + size,
+                }))
+            }
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        match self.pending.take() {
+            Some(pending) => Err(read_error(
+                pending.line,
+                "missing source line for action".to_owned(),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReadHeader {
+    #[serde(rename = "_index")]
+    index: Option<String>,
+    #[serde(rename = "_type")]
+    ty: Option<String>,
+    #[serde(rename = "_id")]
+    id: Option<Value>,
+    #[serde(alias = "_routing")]
+    routing: Option<String>,
+    #[serde(alias = "_version")]
+    version: Option<u64>,
+    #[serde(alias = "_version_type")]
+    version_type: Option<String>,
+    if_seq_no: Option<u64>,
+    if_primary_term: Option<u64>,
+    pipeline: Option<String>,
+    #[serde(alias = "_retry_on_conflict")]
+    retry_on_conflict: Option<u32>,
+}
+
+impl ReadHeader {
+    fn into_operation(self, action: Action, source: Option<Value>) -> BulkOperation<Value> {
+        let mut op = BulkOperation::new(action, source);
+
+        if let (Some(seq_no), Some(primary_term)) = (self.if_seq_no, self.if_primary_term) {
+            op = op.if_seq_no_primary_term(seq_no, primary_term);
+        }
+
+        if let (Some(version), Some(version_type)) = (self.version, self.version_type) {
+            let version_type = match &*version_type {
+                "external" => VersionType::External,
+                _ => VersionType::ExternalGte,
+            };
+
+            op = op.version(version, version_type);
+        }
+
+        if let Some(pipeline) = self.pipeline {
+            op = op.pipeline(pipeline);
+        }
+
+        if let Some(retries) = self.retry_on_conflict {
+            op = op.retry_on_conflict(retries);
+        }
+
+        if let Some(index) = self.index {
+            op = op.index(index);
+        }
+
+        if let Some(ty) = self.ty {
+            op = op.ty(ty);
+        }
+
+        if let Some(routing) = self.routing {
+            op = op.routing(routing);
+        }
+
+        match self.id {
+            Some(Value::String(id)) => op.id(id),
+            Some(id) => op.id(id.to_string()),
+            None => op,
+        }
+    }
+}
+
+fn parse_action(line: &str) -> Result<(Action, ReadHeader), String> {
+    let action: Map<String, Value> =
+        serde_json::from_str(line).map_err(|e| format!("invalid action: {}", e))?;
+
+    let mut action = action.into_iter();
+    let (key, header) = match (action.next(), action.next()) {
+        (Some(entry), None) => entry,
+        _ => return Err("expected an action with a single key".to_owned()),
+    };
+
+    let action = match &*key {
+        "index" => Action::Index,
+        "create" => Action::Create,
+        "update" => Action::Update,
+        "delete" => Action::Delete,
+        _ => return Err(format!("unknown action `{}`", key)),
+    };
+
+    let header: ReadHeader = serde_json::from_value(header)
+        .map_err(|e| format!("invalid metadata for `{}` action: {}", key, e))?;
+
+    match header.id {
+        None | Some(Value::String(_)) | Some(Value::Number(_)) => (),
+        _ => {
+            return Err(format!(
+                "invalid metadata for `{}` action: `_id` must be a string or number",
+                key
+            ))
+        }
+    }
+
+    if header.if_seq_no.is_some() != header.if_primary_term.is_some() {
+        return Err(format!(
+            "invalid metadata for `{}` action: `if_seq_no` and `if_primary_term` must be set together",
+            key
+        ));
+    }
+
+    match (header.version, header.version_type.as_deref()) {
+        (None, None) | (Some(_), Some("external")) | (Some(_), Some("external_gte")) => (),
+        _ => {
+            return Err(format!(
+                "invalid metadata for `{}` action: `version` must be set with a `version_type` of `external` or `external_gte`",
+                key
+            ))
+        }
+    }
+
+    Ok((action, header))
+}
+
+fn parse_source(line: &str) -> Result<Value, String> {
+    let source: Map<String, Value> =
+        serde_json::from_str(line).map_err(|e| format!("invalid source: {}", e))?;
+
+    Ok(Value::Object(source))
+}
+
+fn read_error(line: usize, reason: String) -> Error {
+    error::request(BulkReadError { line, reason })
+}
+
+#[derive(Debug)]
+struct BulkReadError {
+    line: usize,
+    reason: String,
+}
+
+impl fmt::Display for BulkReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "error reading bulk operation at line {}: {}",
+            self.line, self.reason
+        )
+    }
+}
+
+impl StdError for BulkReadError {
+    fn description(&self) -> &str {
+        "error reading bulk operation"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{
+        Future,
+        Stream,
+    };
+    use serde_json::Value;
+    use std::error::Error as StdError;
+
+    use super::*;
+    use crate::client::requests::bulk::bulk_raw;
+
+    fn error_chain(err: Error) -> String {
+        match err {
+            Error::Client(err) => err.source().unwrap().to_string(),
+            err => panic!("unexpected error: {}", err),
+        }
+    }
+
+    const OPS: &str = r#"{"index":{"_index":"idx","_id":"1"}}
+{"title":"one"}
+
+{"delete":{"_index":"idx","_id":2}}
+{"update":{"_id":"3"}}
+{"doc":{"title":"three"}}
+"#;
+
+    fn write_all(ops: Vec<BulkOperation<Value>>) -> String {
+        let mut writer = BulkWriter::new(Vec::new());
+
+        for op in ops {
+            writer.push(op).unwrap();
+        }
+
+        String::from_utf8(writer.into_inner().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn read_ops() {
+        let ops = BulkReader::new(OPS.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(3, ops.len());
+
+        let expected = r#"{"index":{"_index":"idx","_id":"1"}}
+{"title":"one"}
+{"delete":{"_index":"idx","_id":"2"}}
+{"update":{"_id":"3"}}
+{"doc":{"title":"three"}}
+"#;
+
+        assert_eq!(expected, write_all(ops));
+    }
+
+    #[test]
+    fn read_written_ops() {
+        let mut writer = BulkWriter::new(Vec::new());
+
+        writer
+            .push(
+                bulk_raw()
+                    .index(json!({ "title": "one" }))
+                    .index("idx")
+                    .id("1")
+                    .routing("user-1"),
+            )
+            .unwrap();
+        writer
+            .push(bulk_raw().delete().index("idx").id("2").routing("user-2"))
+            .unwrap();
+
+        let written = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+
+        let read = BulkReader::new(written.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(2, read.len());
+        assert!(written.contains(r#"{"index":{"_index":"idx","_id":"1","routing":"user-1"}}"#));
+        assert_eq!(written, write_all(read));
+    }
+
+    #[test]
+    fn read_metadata() {
+        let ops = r#"{"index":{"_index":"idx","_id":"1","routing":"user-1","version":1556000000000,"version_type":"external","pipeline":"timestamps"}}
+{"title":"one"}
+{"delete":{"_index":"idx","_id":"2","if_seq_no":5,"if_primary_term":1}}
+{"update":{"_index":"idx","_id":"3","_routing":"user-3","retry_on_conflict":3}}
+{"doc":{"title":"three"}}
+"#;
+
+        let read = BulkReader::new(ops.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let expected = r#"{"index":{"_index":"idx","_id":"1","routing":"user-1","version":1556000000000,"version_type":"external","pipeline":"timestamps"}}
+{"title":"one"}
+{"delete":{"_index":"idx","_id":"2","if_seq_no":5,"if_primary_term":1}}
+{"update":{"_index":"idx","_id":"3","routing":"user-3","retry_on_conflict":3}}
+{"doc":{"title":"three"}}
+"#;
+
+        assert_eq!(expected, write_all(read));
+    }
+
+    #[test]
+    fn read_invalid_metadata() {
+        let invalid = vec![
+            "{\"delete\":{\"_id\":\"1\",\"if_seq_no\":5}}\n",
+            "{\"delete\":{\"_id\":\"1\",\"version\":5}}\n",
+            "{\"delete\":{\"_id\":\"1\",\"version\":5,\"version_type\":\"internal\"}}\n",
+            "{\"delete\":{\"_id\":\"1\",\"refresh\":true}}\n",
+        ];
+
+        for ops in invalid {
+            let err = BulkReader::new(ops.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .err()
+                .unwrap();
+
+            assert!(error_chain(err).contains("line 1"));
+        }
+    }
+
+    #[test]
+    fn read_ops_async() {
+        let ops = AsyncBulkReader::new(OPS.as_bytes())
+            .collect()
+            .wait()
+            .unwrap();
+
+        assert_eq!(3, ops.len());
+    }
+
+    #[test]
+    fn read_long_line() {
+        let err = BulkReader::new(OPS.as_bytes())
+            .max_line_length(16)
+            .collect::<Result<Vec<_>, _>>()
+            .err()
+            .unwrap();
+
+        assert!(error_chain(err).contains("line 1"));
+
+        let ops = BulkReader::new(OPS.as_bytes())
+            .max_line_length(36)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(3, ops.len());
+    }
+
+    #[test]
+    fn read_long_line_async() {
+        let ops = AsyncBulkReader::new(OPS.as_bytes())
+            .max_line_length(16)
+            .collect()
+            .wait();
+
+        assert!(ops.is_err());
+    }
+
+    #[test]
+    fn read_chunks() {
+        let chunks = BulkReader::new(OPS.as_bytes())
+            .chunks(60)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let lens: Vec<_> = chunks.iter().map(|chunk| chunk.len()).collect();
+
+        assert_eq!(vec![1, 1, 1], lens);
+    }
+
+    #[test]
+    fn read_chunks_large_body() {
+        let chunks = BulkReader::new(OPS.as_bytes())
+            .chunks(1024)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(1, chunks.len());
+        assert_eq!(3, chunks[0].len());
+    }
+
+    #[test]
+    fn read_chunks_max_ops() {
+        let chunks = BulkReader::new(OPS.as_bytes())
+            .chunks(1024)
+            .body_size_ops(2)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let lens: Vec<_> = chunks.iter().map(|chunk| chunk.len()).collect();
+
+        assert_eq!(vec![2, 1], lens);
+    }
+
+    #[test]
+    fn read_missing_source() {
+        let ops = "{\"index\":{\"_id\":\"1\"}}\n{\"title\":\"one\"}\n{\"create\":{}}\n";
+
+        let err = BulkReader::new(ops.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .err()
+            .unwrap();
+
+        assert!(error_chain(err).contains("line 3"));
+    }
+
+    #[test]
+    fn read_unknown_action() {
+        let ops = "{\"upsert\":{\"_id\":\"1\"}}\n{\"title\":\"one\"}\n";
+
+        let err = BulkReader::new(ops.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .err()
+            .unwrap();
+
+        assert!(error_chain(err).contains("line 1"));
+    }
+
+    #[test]
+    fn read_invalid_source() {
+        let ops = "{\"index\":{\"_id\":\"1\"}}\n[1, 2, 3]\n";
+
+        let err = AsyncBulkReader::new(ops.as_bytes())
+            .collect()
+            .wait()
+            .err()
+            .unwrap();
+
+        assert!(error_chain(err).contains("line 2"));
+    }
+}
@@ -8,11 +8,15 @@ use std::{
 };
 
 use serde::ser::{
+    Error as SerError,
     Serialize,
     SerializeMap,
     Serializer,
 };
-use serde_json::Value;
+use serde_json::{
+    value::RawValue,
+    Value,
+};
 
 use crate::{
     client::requests::common::{
@@ -20,10 +24,12 @@ use crate::{
         Doc,
         Script,
         ScriptBuilder,
+        VersionType,
     },
     params::{
         Id,
         Index,
+        Routing,
         Type,
     },
     types::document::DocumentType,
@@ -40,7 +46,7 @@ pub struct BulkOperation<TValue> {
     inner: Option<TValue>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Default)]
 struct BulkHeader {
     #[serde(
         rename = "_index",
@@ -60,6 +66,33 @@ struct BulkHeader {
         skip_serializing_if = "Option::is_none"
     )]
     id: Option<Id<'static>>,
+    #[serde(
+        serialize_with = "serialize_param",
+        skip_serializing_if = "Option::is_none"
+    )]
+    routing: Option<Routing<'static>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<u64>,
+    #[serde(
+        serialize_with = "serialize_version_type",
+        skip_serializing_if = "Option::is_none"
+    )]
+    version_type: Option<VersionType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    if_seq_no: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    if_primary_term: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pipeline: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry_on_conflict: Option<u32>,
+}
+
+fn serialize_version_type<S>(field: &Option<VersionType>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(field.expect("serialize `None` value").as_str())
 }
 
 fn serialize_param<S, T>(field: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
@@ -152,6 +185,14 @@ impl<TParams> BulkOperation<Script<TParams>> {
 }
 
 impl<TValue> BulkOperation<TValue> {
+    pub(super) fn new(action: Action, inner: Option<TValue>) -> Self {
+        BulkOperation {
+            action,
+            header: BulkHeader::default(),
+            inner,
+        }
+    }
+
     /**
     Set the index for this bulk operation.
     */
@@ -178,6 +219,58 @@ impl<TValue> BulkOperation<TValue> {
         self.header.id = Some(id.into());
         self
     }
+
+    /**
+    Set the routing value for this bulk operation.
+
+    Operations for documents that declare a routing value using `#[elastic(routing)]` will use it by default.
+    */
+    pub fn routing(mut self, routing: impl Into<Routing<'static>>) -> Self {
+        self.header.routing = Some(routing.into());
+        self
+    }
+
+    /**
+    Only apply this bulk operation if the document hasn't changed since it was read.
+
+    If the document has changed then the operation will fail with a version conflict.
+    */
+    pub fn if_seq_no_primary_term(mut self, seq_no: u64, primary_term: u64) -> Self {
+        self.header.if_seq_no = Some(seq_no);
+        self.header.if_primary_term = Some(primary_term);
+        self
+    }
+
+    /**
+    Only apply this bulk operation if the given externally maintained version is newer than the current one.
+
+    If the document has a newer version then the operation will fail with a version conflict.
+    */
+    pub fn version(mut self, version: u64, version_type: VersionType) -> Self {
+        self.header.version = Some(version);
+        self.header.version_type = Some(version_type);
+        self
+    }
+
+    /**
+    Set the ingest pipeline to run the document through before indexing it.
+
+    This only applies to `index` and `create` operations.
+    */
+    pub fn pipeline(mut self, pipeline: impl Into<String>) -> Self {
+        self.header.pipeline = Some(pipeline.into());
+        self
+    }
+
+    /**
+    Set the number of times to retry this bulk operation if there's a version conflict.
+
+    This only applies to `update` operations.
+    */
+    pub fn retry_on_conflict(mut self, retries: u32) -> Self {
+        self.header.retry_on_conflict = Some(retries);
+        self
+    }
 }
 
 impl<TDocument> BulkOperation<Doc<TDocument>>
@@ -279,6 +372,75 @@ where
 
         Ok(())
     }
+
+    /**
+    Erase the document type of this bulk operation.
+
+    The document is serialized eagerly and the operation keeps its index, type and id.
+    Erased operations for different document types can be sent through the same bulk request or stream.
+
+    # Examples
+
+    Send operations for two different document types through the same bulk stream:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use futures::{Future, Sink};
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct Customer { id: String }
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct Order { id: String, customer_id: String }
+    # let client = AsyncClientBuilder::new().build()?;
+    let (bulk_stream, bulk_responses) = client.bulk_stream::<ErasedDocument>().build();
+
+    let ops = vec![
+        bulk::<Customer>()
+            .index(Customer { id: "c1".into() })
+            .erase(),
+        bulk::<Order>()
+            .index(Order { id: "o1".into(), customer_id: "c1".into() })
+            .erase(),
+    ];
+
+    let req_future = bulk_stream.send_all(futures::stream::iter_ok(ops));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn erase(self) -> BulkOperation<ErasedDocument> {
+        BulkOperation {
+            action: self.action,
+            header: self.header,
+            inner: self
+                .inner
+                .map(|inner| ErasedDocument(serde_json::value::to_raw_value(&inner))),
+        }
+    }
+}
+
+/**
+A pre-serialized document with its type erased.
+
+Use [`BulkOperation.erase`][BulkOperation.erase] to get an operation for an erased document.
+If the original document failed to serialize then the error will be returned when the operation is written.
+
+[BulkOperation.erase]: struct.BulkOperation.html#method.erase
+*/
+pub struct ErasedDocument(Result<Box<RawValue>, serde_json::Error>);
+
+impl Serialize for ErasedDocument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            Ok(ref raw) => raw.serialize(serializer),
+            Err(ref err) => Err(S::Error::custom(err)),
+        }
+    }
 }
 
 /**
@@ -320,6 +482,8 @@ where
                 index: Some(doc.index().to_owned()),
                 ty: Some(doc.ty().to_owned()),
                 id: doc.partial_id().map(|id| id.to_owned()),
+                routing: doc.partial_routing().map(|routing| routing.into_owned()),
+                ..Default::default()
             },
             inner: Some(doc),
         }
@@ -335,6 +499,8 @@ where
                 index: Some(doc.index().to_owned()),
                 ty: Some(doc.ty().to_owned()),
                 id: doc.partial_id().map(|id| id.to_owned()),
+                routing: doc.partial_routing().map(|routing| routing.into_owned()),
+                ..Default::default()
             },
             inner: Some(Doc::value(doc)),
         }
@@ -358,6 +524,8 @@ where
                 index: TDocument::partial_static_index(),
                 ty: TDocument::partial_static_ty(),
                 id: Some(id.into()),
+                routing: None,
+                ..Default::default()
             },
             inner: Some(Script::new(script)),
         }
@@ -383,6 +551,8 @@ where
                 index: TDocument::partial_static_index().map(Into::into),
                 ty: TDocument::partial_static_ty().map(Into::into),
                 id: Some(id.into()),
+                routing: None,
+                ..Default::default()
             },
             inner: Some(Script::new(script)),
         }
@@ -399,6 +569,8 @@ where
                 index: Some(doc.index().to_owned()),
                 ty: Some(doc.ty().to_owned()),
                 id: doc.partial_id().map(|id| id.to_owned()),
+                routing: doc.partial_routing().map(|routing| routing.into_owned()),
+                ..Default::default()
             },
             inner: Some(doc),
         }
@@ -417,6 +589,8 @@ where
                 index: TDocument::partial_static_index(),
                 ty: TDocument::partial_static_ty(),
                 id: Some(id.into()),
+                routing: None,
+                ..Default::default()
             },
             inner: None,
         }
@@ -455,11 +629,7 @@ impl BulkRawOperation {
     pub fn index<TDocument>(self, doc: TDocument) -> BulkOperation<TDocument> {
         BulkOperation {
             action: Action::Index,
-            header: BulkHeader {
-                index: None,
-                ty: None,
-                id: None,
-            },
+            header: BulkHeader::default(),
             inner: Some(doc),
         }
     }
@@ -470,11 +640,7 @@ impl BulkRawOperation {
     pub fn update<TDocument>(self, doc: TDocument) -> BulkOperation<Doc<TDocument>> {
         BulkOperation {
             action: Action::Update,
-            header: BulkHeader {
-                index: None,
-                ty: None,
-                id: None,
-            },
+            header: BulkHeader::default(),
             inner: Some(Doc::value(doc)),
         }
     }
@@ -488,11 +654,7 @@ impl BulkRawOperation {
     {
         BulkOperation {
             action: Action::Update,
-            header: BulkHeader {
-                index: None,
-                ty: None,
-                id: None,
-            },
+            header: BulkHeader::default(),
             inner: Some(Script::new(script)),
         }
     }
@@ -511,11 +673,7 @@ impl BulkRawOperation {
     {
         BulkOperation {
             action: Action::Update,
-            header: BulkHeader {
-                index: None,
-                ty: None,
-                id: None,
-            },
+            header: BulkHeader::default(),
             inner: Some(Script::new(script)),
         }
         .script_fluent(builder)
@@ -527,11 +685,7 @@ impl BulkRawOperation {
     pub fn create<TDocument>(self, doc: TDocument) -> BulkOperation<TDocument> {
         BulkOperation {
             action: Action::Create,
-            header: BulkHeader {
-                index: None,
-                ty: None,
-                id: None,
-            },
+            header: BulkHeader::default(),
             inner: Some(doc),
         }
     }
@@ -542,11 +696,7 @@ impl BulkRawOperation {
     pub fn delete(self) -> BulkOperation<()> {
         BulkOperation {
             action: Action::Delete,
-            header: BulkHeader {
-                index: None,
-                ty: None,
-                id: None,
-            },
+            header: BulkHeader::default(),
             inner: None,
         }
     }
@@ -558,3 +708,163 @@ Create a builder for a bulk operation.
 pub fn bulk_raw() -> BulkRawOperation {
     BulkRawOperation::new()
 }
+
+#[cfg(test)]
+mod tests {
+    use serde::ser::{
+        Error as SerError,
+        Serialize,
+        Serializer,
+    };
+
+    use crate::{
+        client::requests::common::VersionType,
+        prelude::*,
+    };
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", index = "customers")]
+    struct Customer {
+        #[elastic(id)]
+        id: String,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", index = "orders")]
+    struct Order {
+        #[elastic(id)]
+        id: String,
+        total: i32,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", index = "invoices")]
+    struct Invoice {
+        #[elastic(id)]
+        id: String,
+        #[elastic(routing)]
+        customer_id: String,
+    }
+
+    struct Unserializable;
+
+    impl Serialize for Unserializable {
+        fn serialize<S>(&self, _: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            Err(S::Error::custom("unserializable"))
+        }
+    }
+
+    fn write(ops: Vec<BulkOperation<ErasedDocument>>) -> String {
+        let mut body = Vec::new();
+        for op in ops {
+            op.write(&mut body).unwrap();
+        }
+
+        String::from_utf8(body).unwrap()
+    }
+
+    #[test]
+    fn erase_mixed_documents() {
+        let ops = vec![
+            bulk::<Customer>()
+                .index(Customer { id: "c1".into() })
+                .erase(),
+            bulk::<Order>()
+                .create(Order {
+                    id: "o1".into(),
+                    total: 5,
+                })
+                .erase(),
+            bulk::<Order>().delete("o2").erase(),
+        ];
+
+        let expected = concat!(
+            r#"{"index":{"_index":"customers","_type":"_doc","_id":"c1"}}"#,
+            "\n",
+            r#"{"id":"c1"}"#,
+            "\n",
+            r#"{"create":{"_index":"orders","_type":"_doc","_id":"o1"}}"#,
+            "\n",
+            r#"{"id":"o1","total":5}"#,
+            "\n",
+            r#"{"delete":{"_index":"orders","_type":"_doc","_id":"o2"}}"#,
+            "\n",
+        );
+
+        assert_eq!(expected, write(ops));
+    }
+
+    #[test]
+    fn erase_unserializable_document() {
+        let op = bulk_raw().index(Unserializable).erase();
+
+        let mut body = Vec::new();
+        let err = op.write(&mut body).unwrap_err();
+
+        assert!(err.to_string().contains("unserializable"));
+    }
+
+    #[test]
+    fn routed_documents() {
+        let ops = vec![
+            bulk::<Invoice>()
+                .index(Invoice {
+                    id: "i1".into(),
+                    customer_id: "c1".into(),
+                })
+                .erase(),
+            bulk::<Invoice>().delete("i2").routing("c2").erase(),
+        ];
+
+        let expected = concat!(
+            r#"{"index":{"_index":"invoices","_type":"_doc","_id":"i1","routing":"c1"}}"#,
+            "\n",
+            r#"{"id":"i1","customer_id":"c1"}"#,
+            "\n",
+            r#"{"delete":{"_index":"invoices","_type":"_doc","_id":"i2","routing":"c2"}}"#,
+            "\n",
+        );
+
+        assert_eq!(expected, write(ops));
+    }
+
+    #[test]
+    fn concurrency_and_ingest_metadata() {
+        let ops = vec![
+            bulk::<Order>()
+                .index(Order {
+                    id: "o1".into(),
+                    total: 5,
+                })
+                .version(3, VersionType::External)
+                .pipeline("orders")
+                .erase(),
+            bulk::<Order>()
+                .delete("o2")
+                .if_seq_no_primary_term(7, 1)
+                .erase(),
+            bulk::<Order>()
+                .update_script("o3", "ctx._source.total += 1")
+                .retry_on_conflict(2)
+                .erase(),
+        ];
+
+        let expected = concat!(
+            r#"{"index":{"_index":"orders","_type":"_doc","_id":"o1","version":3,"version_type":"external","pipeline":"orders"}}"#,
+            "\n",
+            r#"{"id":"o1","total":5}"#,
+            "\n",
+            r#"{"delete":{"_index":"orders","_type":"_doc","_id":"o2","if_seq_no":7,"if_primary_term":1}}"#,
+            "\n",
+            r#"{"update":{"_index":"orders","_type":"_doc","_id":"o3","retry_on_conflict":2}}"#,
+            "\n",
+            r#"{"script":{"inline":"ctx._source.total += 1"}}"#,
+            "\n",
+        );
+
+        assert_eq!(expected, write(ops));
+    }
+}
@@ -0,0 +1,419 @@
+use std::{
+    fmt,
+    sync::{
+        atomic::{
+            AtomicU64,
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+    time::Duration,
+};
+
+use serde_json::Value;
+
+use crate::{
+    client::responses::{
+        BulkErrorsResponse,
+        BulkResponse,
+    },
+    private,
+};
+
+/**
+A handle to the statistics for a stream of bulk operations.
+
+The handle can be cloned and sampled from any thread while the stream is running.
+Call [`BulkSender.stats`][BulkSender.stats] or [`BulkReceiver.stats`][BulkReceiver.stats] to get a `BulkStats`.
+
+# Examples
+
+Periodically log the progress of a bulk stream:
+
+```no_run
+# use std::{thread, time::Duration};
+# use elastic::prelude::*;
+# fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+# let client = AsyncClientBuilder::new().build()?;
+let (bulk_stream, bulk_responses) = client.bulk_stream::<()>().build();
+
+let stats = bulk_stream.stats();
+thread::spawn(move || loop {
+    let sample = stats.sample();
+    println!("sent {} operations in {} batches", sample.ops(), sample.batches());
+
+    thread::sleep(Duration::from_secs(10));
+});
+# Ok(())
+# }
+```
+
+[BulkSender.stats]: struct.BulkSender.html#method.stats
+[BulkReceiver.stats]: struct.BulkReceiver.html#method.stats
+*/
+#[derive(Clone, Default)]
+pub struct BulkStats {
+    inner: Arc<BulkStatsInner>,
+}
+
+#[derive(Default)]
+struct BulkStatsInner {
+    ops: AtomicUsize,
+    bytes: AtomicUsize,
+    batches: AtomicUsize,
+    completed_batches: AtomicUsize,
+    failed_batches: AtomicUsize,
+    failed_ops: AtomicUsize,
+    rejected_ops: AtomicUsize,
+    latency_nanos: AtomicU64,
+}
+
+impl BulkStats {
+    /**
+    Take a point-in-time sample of the statistics.
+    */
+    pub fn sample(&self) -> BulkStatsSample {
+        let inner = &self.inner;
+
+        BulkStatsSample {
+            ops: inner.ops.load(Ordering::Relaxed),
+            bytes: inner.bytes.load(Ordering::Relaxed),
+            batches: inner.batches.load(Ordering::Relaxed),
+            completed_batches: inner.completed_batches.load(Ordering::Relaxed),
+            failed_batches: inner.failed_batches.load(Ordering::Relaxed),
+            failed_ops: inner.failed_ops.load(Ordering::Relaxed),
+            rejected_ops: inner.rejected_ops.load(Ordering::Relaxed),
+            latency: Duration::from_nanos(inner.latency_nanos.load(Ordering::Relaxed)),
+        }
+    }
+
+    pub(super) fn record_sent(&self, ops: usize, bytes: usize) {
+        self.inner.ops.fetch_add(ops, Ordering::Relaxed);
+        self.inner.bytes.fetch_add(bytes, Ordering::Relaxed);
+        self.inner.batches.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn record_completed(&self, batch: &BulkBatchStats) {
+        let inner = &self.inner;
+
+        if batch.failed {
+            inner.failed_batches.fetch_add(1, Ordering::Relaxed);
+        } else {
+            inner.completed_batches.fetch_add(1, Ordering::Relaxed);
+        }
+
+        inner
+            .failed_ops
+            .fetch_add(batch.failed_ops, Ordering::Relaxed);
+        inner
+            .rejected_ops
+            .fetch_add(batch.rejected_ops, Ordering::Relaxed);
+        inner
+            .latency_nanos
+            .fetch_add(batch.latency.as_nanos() as u64, Ordering::Relaxed);
+    }
+}
+
+impl fmt::Debug for BulkStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("BulkStats").field(&self.sample()).finish()
+    }
+}
+
+/**
+A point-in-time sample of [`BulkStats`][BulkStats].
+
+[BulkStats]: struct.BulkStats.html
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BulkStatsSample {
+    ops: usize,
+    bytes: usize,
+    batches: usize,
+    completed_batches: usize,
+    failed_batches: usize,
+    failed_ops: usize,
+    rejected_ops: usize,
+    latency: Duration,
+}
+
+impl BulkStatsSample {
+    /** The number of operations sent to Elasticsearch. */
+    pub fn ops(&self) -> usize {
+        self.ops
+    }
+
+    /** The number of request body bytes sent to Elasticsearch. */
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /** The number of bulk requests sent to Elasticsearch. */
+    pub fn batches(&self) -> usize {
+        self.batches
+    }
+
+    /** The number of bulk requests that returned a response. */
+    pub fn completed_batches(&self) -> usize {
+        self.completed_batches
+    }
+
+    /**
+    The number of bulk requests that failed without a response.
+
+    A failed request will also fail the stream.
+    */
+    pub fn failed_batches(&self) -> usize {
+        self.failed_batches
+    }
+
+    /**
+    The number of bulk requests that have been sent but haven't completed or failed yet.
+
+    The counters are sampled independently, so a request that finishes while sampling may be missed here.
+    */
+    pub fn in_flight_batches(&self) -> usize {
+        self.batches
+            .saturating_sub(self.completed_batches)
+            .saturating_sub(self.failed_batches)
+    }
+
+    /** The number of individual operations that Elasticsearch reported as failed. */
+    pub fn failed_ops(&self) -> usize {
+        self.failed_ops
+    }
+
+    /**
+    The number of failed operations that Elasticsearch rejected because it was overloaded.
+
+    The stream doesn't retry operations itself, so these are the operations that need to be sent again.
+    A growing number of rejections means the cluster can't keep up with the stream.
+    */
+    pub fn rejected_ops(&self) -> usize {
+        self.rejected_ops
+    }
+
+    /**
+    The average time between sending a bulk request and it completing or failing.
+
+    This will return `None` if no requests have completed or failed yet.
+    */
+    pub fn average_latency(&self) -> Option<Duration> {
+        let finished = (self.completed_batches + self.failed_batches) as u32;
+
+        if finished == 0 {
+            None
+        } else {
+            Some(self.latency / finished)
+        }
+    }
+}
+
+/**
+Statistics for a single bulk request sent by a [`BulkSender`][BulkSender].
+
+These are passed to the callback given to [`on_batch`][on_batch].
+
+[BulkSender]: struct.BulkSender.html
+[on_batch]: type.BulkRequestBuilder.html#method.on_batch
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BulkBatchStats {
+    pub(super) ops: usize,
+    pub(super) bytes: usize,
+    pub(super) failed_ops: usize,
+    pub(super) rejected_ops: usize,
+    pub(super) failed: bool,
+    pub(super) latency: Duration,
+}
+
+impl BulkBatchStats {
+    /** The number of operations in the request. */
+    pub fn ops(&self) -> usize {
+        self.ops
+    }
+
+    /** The size of the request body in bytes. */
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /** The number of individual operations that Elasticsearch reported as failed. */
+    pub fn failed_ops(&self) -> usize {
+        self.failed_ops
+    }
+
+    /** The number of failed operations that Elasticsearch rejected because it was overloaded. */
+    pub fn rejected_ops(&self) -> usize {
+        self.rejected_ops
+    }
+
+    /** Whether the request failed without a response. */
+    pub fn failed(&self) -> bool {
+        self.failed
+    }
+
+    /** The time between sending the request and it completing or failing. */
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+}
+
+/** A callback invoked with the statistics for each bulk request sent by a stream. */
+pub(super) type OnBatch = Arc<dyn Fn(&BulkBatchStats) + Send + Sync>;
+
+/**
+A bulk response that can report how many operations failed.
+
+This trait is sealed and implemented for the [`BulkResponse`][BulkResponse] and [`BulkErrorsResponse`][BulkErrorsResponse] types that a bulk stream can return.
+
+[BulkResponse]: ../../responses/struct.BulkResponse.html
+[BulkErrorsResponse]: ../../responses/struct.BulkErrorsResponse.html
+*/
+pub trait BulkResponseErrors: private::Sealed {
+    /** The number of operations that failed. */
+    fn failed_ops(&self) -> usize;
+
+    /** The number of failed operations that were rejected because a thread pool queue was full. */
+    fn rejected_ops(&self) -> usize;
+}
+
+impl<TIndex, TType, TId> private::Sealed for BulkResponse<TIndex, TType, TId> {}
+
+impl<TIndex, TType, TId> BulkResponseErrors for BulkResponse<TIndex, TType, TId> {
+    fn failed_ops(&self) -> usize {
+        if self.is_ok() {
+            0
+        } else {
+            self.iter().filter(|item| item.is_err()).count()
+        }
+    }
+
+    fn rejected_ops(&self) -> usize {
+        if self.is_ok() {
+            0
+        } else {
+            self.iter()
+                .filter_map(Result::err)
+                .filter(|item| is_rejected(item.err()))
+                .count()
+        }
+    }
+}
+
+impl<TIndex, TType, TId> private::Sealed for BulkErrorsResponse<TIndex, TType, TId> {}
+
+impl<TIndex, TType, TId> BulkResponseErrors for BulkErrorsResponse<TIndex, TType, TId> {
+    fn failed_ops(&self) -> usize {
+        self.iter().count()
+    }
+
+    fn rejected_ops(&self) -> usize {
+        self.iter().filter(|item| is_rejected(item.err())).count()
+    }
+}
+
+/** Whether a bulk item error is a rejection because a thread pool queue was full. */
+fn is_rejected(err: &Value) -> bool {
+    err.get("type").and_then(Value::as_str) == Some("es_rejected_execution_exception")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn sample_stats() {
+        let stats = BulkStats::default();
+
+        assert_eq!(None, stats.sample().average_latency());
+
+        stats.record_sent(10, 100);
+        stats.record_sent(5, 50);
+        stats.record_completed(&BulkBatchStats {
+            ops: 10,
+            bytes: 100,
+            failed_ops: 2,
+            rejected_ops: 1,
+            failed: false,
+            latency: Duration::from_millis(20),
+        });
+
+        let sample = stats.clone().sample();
+
+        assert_eq!(15, sample.ops());
+        assert_eq!(150, sample.bytes());
+        assert_eq!(2, sample.batches());
+        assert_eq!(1, sample.completed_batches());
+        assert_eq!(1, sample.in_flight_batches());
+        assert_eq!(2, sample.failed_ops());
+        assert_eq!(1, sample.rejected_ops());
+        assert_eq!(Some(Duration::from_millis(20)), sample.average_latency());
+
+        stats.record_completed(&BulkBatchStats {
+            ops: 5,
+            bytes: 50,
+            failed_ops: 0,
+            rejected_ops: 0,
+            failed: true,
+            latency: Duration::from_millis(40),
+        });
+
+        let sample = stats.sample();
+
+        assert_eq!(1, sample.failed_batches());
+        assert_eq!(0, sample.in_flight_batches());
+        assert_eq!(Some(Duration::from_millis(30)), sample.average_latency());
+    }
+
+    #[test]
+    fn count_rejected_ops() {
+        let response: BulkResponse = serde_json::from_value(json!({
+            "took": 5,
+            "errors": true,
+            "items": [
+                {
+                    "index": {
+                        "_index": "idx", "_type": "_doc", "_id": "1", "status": 429,
+                        "error": { "type": "es_rejected_execution_exception", "reason": "rejected" }
+                    }
+                },
+                {
+                    "create": {
+                        "_index": "idx", "_type": "_doc", "_id": "2", "status": 409,
+                        "error": { "type": "version_conflict_engine_exception", "reason": "conflict" }
+                    }
+                },
+                {
+                    "index": {
+                        "_index": "idx", "_type": "_doc", "_id": "3", "_version": 1,
+                        "result": "created", "status": 201
+                    }
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(2, response.failed_ops());
+        assert_eq!(1, response.rejected_ops());
+    }
+
+    #[test]
+    fn in_flight_batches_never_underflow() {
+        let stats = BulkStats::default();
+
+        stats.record_completed(&BulkBatchStats {
+            ops: 1,
+            bytes: 10,
+            failed_ops: 0,
+            rejected_ops: 0,
+            failed: false,
+            latency: Duration::from_millis(1),
+        });
+
+        assert_eq!(0, stats.sample().in_flight_batches());
+    }
+}
@@ -12,6 +12,7 @@ use std::{
 
 use bytes::{
     BufMut,
+    Bytes,
     BytesMut,
 };
 use channel::{
@@ -35,9 +36,13 @@ use serde::{
 use tokio::timer::Delay;
 
 use super::{
+    BulkBatchStats,
     BulkOperation,
     BulkRequestBuilder,
     BulkRequestInner,
+    BulkResponseErrors,
+    BulkStats,
+    OnBatch,
     Pending,
     WrappedBody,
 };
@@ -65,6 +70,7 @@ use crate::{
 The sending half of a stream of bulk operations.
 
 The sender accepts individual operations and keeps them in a buffer until a timer has expired or the buffer fills up.
+Buffered operations can also be sent on demand with [`flush`](#method.flush), or before closing the stream with [`shutdown`](#method.shutdown).
 */
 pub struct BulkSender<TDocument, TResponse> {
     tx: BulkSenderInner<TResponse>,
@@ -72,6 +78,8 @@ pub struct BulkSender<TDocument, TResponse> {
     in_flight: BulkSenderInFlight<TResponse>,
     timeout: Timeout,
     body: SenderBody,
+    stats: BulkStats,
+    on_batch: Option<OnBatch>,
     _marker: PhantomData<TDocument>,
 }
 
@@ -80,8 +88,10 @@ impl<TDocument, TResponse> BulkSender<TDocument, TResponse> {
         req_template: SenderRequestTemplate<TResponse>,
         timeout: Timeout,
         body: SenderBody,
+        on_batch: Option<OnBatch>,
     ) -> (Self, BulkReceiver<TResponse>) {
         let (tx, rx) = channel::bounded(1);
+        let stats = BulkStats::default();
 
         let sender = BulkSender {
             tx: BulkSenderInner(Some(tx)),
@@ -89,6 +99,8 @@ impl<TDocument, TResponse> BulkSender<TDocument, TResponse> {
             timeout,
             body,
             in_flight: BulkSenderInFlight::ReadyToSend,
+            stats: stats.clone(),
+            on_batch,
             _marker: PhantomData,
         };
 
@@ -96,9 +108,42 @@ impl<TDocument, TResponse> BulkSender<TDocument, TResponse> {
             sender,
             BulkReceiver {
                 rx: BulkReceiverInner(rx),
+                stats,
             },
         )
     }
+
+    /**
+    Get a handle to the statistics for this stream.
+
+    The same statistics are shared by the `BulkSender` and its `BulkReceiver`.
+    */
+    pub fn stats(&self) -> BulkStats {
+        self.stats.clone()
+    }
+
+    fn complete_batch(
+        &self,
+        sent: &SentBatch,
+        failed_ops: usize,
+        rejected_ops: usize,
+        failed: bool,
+    ) {
+        let batch = BulkBatchStats {
+            ops: sent.ops,
+            bytes: sent.body.len(),
+            failed_ops,
+            rejected_ops,
+            failed,
+            latency: sent.started.elapsed(),
+        };
+
+        self.stats.record_completed(&batch);
+
+        if let Some(ref on_batch) = self.on_batch {
+            on_batch(&batch);
+        }
+    }
 }
 
 pub(super) struct SenderRequestTemplate<TResponse> {
@@ -125,11 +170,11 @@ impl<TResponse> SenderRequestTemplate<TResponse> {
         }
     }
 
-    fn to_request(&self, body: Vec<u8>) -> BulkRequestBuilder<AsyncSender, Vec<u8>, TResponse> {
+    fn to_request(&self, body: Bytes) -> BulkRequestBuilder<AsyncSender, Bytes, TResponse> {
         RequestBuilder::new(
             self.client.clone(),
             FluentBuilder::new().value(self.params.clone()),
-            BulkRequestInner::<Vec<u8>, TResponse> {
+            BulkRequestInner::<Bytes, TResponse> {
                 index: self.index.clone(),
                 ty: self.ty.clone(),
                 body: WrappedBody::new(body),
@@ -172,11 +217,22 @@ The `BulkSender` and `BulkBody` combination means operations can be pushed while
 */
 enum BulkSenderInFlight<TResponse> {
     ReadyToSend,
-    Pending(Pending<TResponse>),
+    Pending(Pending<TResponse>, SentBatch),
     Transmitting(Option<TResponse>),
     Transmitted,
 }
 
+/**
+Details of an in-flight request for collecting statistics.
+
+The body is retained so it can be reported as unsent if the sender is shut down before the request completes.
+*/
+struct SentBatch {
+    ops: usize,
+    body: Bytes,
+    started: Instant,
+}
+
 struct BulkSenderInner<T>(Option<channel::Sender<T>>);
 struct BulkReceiverInner<T>(channel::Receiver<T>);
 
@@ -187,48 +243,97 @@ The receiver emits complete bulk responses.
 */
 pub struct BulkReceiver<TResponse> {
     rx: BulkReceiverInner<TResponse>,
+    stats: BulkStats,
+}
+
+impl<TResponse> BulkReceiver<TResponse> {
+    /**
+    Get a handle to the statistics for this stream.
+
+    The same statistics are shared by the `BulkReceiver` and its `BulkSender`.
+    */
+    pub fn stats(&self) -> BulkStats {
+        self.stats.clone()
+    }
 }
 
 pub(super) struct SenderBody {
     scratch: Vec<u8>,
     body: BytesMut,
+    ops: usize,
     size: usize,
+    max_ops: Option<usize>,
 }
 
 impl SenderBody {
-    pub(super) fn new(size: usize) -> Self {
+    pub(super) fn new(size: usize, max_ops: Option<usize>) -> Self {
         SenderBody {
             scratch: Vec::new(),
             size,
+            ops: 0,
+            max_ops,
             body: BytesMut::with_capacity(size),
         }
     }
 
-    fn take(&mut self) -> BytesMut {
+    /**
+    Take the current body and the number of operations in it.
+    */
+    fn take(&mut self) -> (BytesMut, usize) {
         // Make sure any oversize remaining scratch can be copied to the new buffer
         let size = usize::max(self.scratch.len(), self.size);
         let mut new_body = BytesMut::with_capacity(size);
+        let mut new_ops = 0;
 
         // Copy out any scratch into the new buffer
         // This would probably be a single operation that didn't fit
         if !self.scratch.is_empty() {
             new_body.put_slice(&self.scratch);
             self.scratch.clear();
+            new_ops = 1;
         }
 
-        mem::replace(&mut self.body, new_body)
+        let ops = mem::replace(&mut self.ops, new_ops);
+
+        (mem::replace(&mut self.body, new_body), ops)
     }
 
     fn has_capacity(&self) -> bool {
-        self.scratch.is_empty() && self.body.remaining_mut() > 0
+        self.scratch.is_empty() && self.body.remaining_mut() > 0 && !self.has_max_ops()
+    }
+
+    /**
+    Whether the body has reached its limit on the number of operations.
+
+    A body can always accept at least one operation.
+    */
+    fn has_max_ops(&self) -> bool {
+        self.max_ops
+            .map(|max_ops| self.ops >= usize::max(max_ops, 1))
+            .unwrap_or(false)
     }
 
     fn is_empty(&self) -> bool {
         self.body.len() == 0
     }
 
+    /**
+    Take the current body and any remaining scratch without replacing them.
+    */
+    fn drain(&mut self) -> (Vec<u8>, usize) {
+        let mut body = self.body.take().to_vec();
+        let mut ops = mem::replace(&mut self.ops, 0);
+
+        if !self.scratch.is_empty() {
+            body.append(&mut self.scratch);
+            ops += 1;
+        }
+
+        (body, ops)
+    }
+
     fn is_full(&self) -> bool {
-        !self.scratch.is_empty() || self.body.remaining_mut() == 0
+        !self.scratch.is_empty() || self.body.remaining_mut() == 0 || self.has_max_ops()
     }
 
     fn push<TDocument>(&mut self, op: BulkOperation<TDocument>) -> Result<(), io::Error>
@@ -241,6 +346,7 @@ impl SenderBody {
         if self.scratch.len() <= self.body.remaining_mut() {
             self.body.put_slice(&self.scratch);
             self.scratch.clear();
+            self.ops += 1;
 
             Ok(())
         }
@@ -248,6 +354,7 @@ impl SenderBody {
         else if self.body.is_empty() {
             let scratch = mem::replace(&mut self.scratch, Vec::new());
             self.body = BytesMut::from(scratch);
+            self.ops += 1;
 
             Ok(())
         }
@@ -261,7 +368,7 @@ impl SenderBody {
 impl<TDocument, TResponse> Sink for BulkSender<TDocument, TResponse>
 where
     TDocument: Serialize + Send + 'static,
-    TResponse: DeserializeOwned + IsOk + Send + 'static,
+    TResponse: DeserializeOwned + IsOk + BulkResponseErrors + Send + 'static,
 {
     type SinkItem = BulkOperation<TDocument>;
     type SinkError = Error;
@@ -295,12 +402,133 @@ where
     }
 
     fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.poll_send(false)
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        try_ready!(self.poll_complete());
+        self.tx.close()
+    }
+}
+
+impl<TDocument, TResponse> BulkSender<TDocument, TResponse>
+where
+    TDocument: Serialize + Send + 'static,
+    TResponse: DeserializeOwned + IsOk + BulkResponseErrors + Send + 'static,
+{
+    /**
+    Send any buffered operations without waiting for the timeout or for the buffer to fill.
+
+    The returned future resolves with the sender once every operation pushed so far has been sent and its response has been passed to the `BulkReceiver`.
+    The `BulkReceiver` needs to be polled for the flush to complete.
+
+    # Examples
+
+    Push a batch of operations and wait for them to be acknowledged before committing an offset:
+
+    ```no_run
+    # use futures::{Future, Sink};
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    # let ops: Vec<BulkOperation<Value>> = vec![];
+    # fn commit_offset() {}
+    let (bulk_stream, bulk_responses) = client.bulk_stream().index("bulk_idx").build();
+
+    let flushed = bulk_stream
+        .send_all(futures::stream::iter_ok(ops))
+        .and_then(|(bulk_stream, _)| bulk_stream.flush())
+        .map(|bulk_stream| {
+            commit_offset();
+            bulk_stream
+        });
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn flush(self) -> BulkFlush<TDocument, TResponse> {
+        BulkFlush { sender: Some(self) }
+    }
+
+    /**
+    Send any buffered operations and close the stream, giving up on operations that haven't been sent after the given deadline.
+
+    The returned future resolves with a [`BulkShutdownSummary`][BulkShutdownSummary] that reports any operations that weren't sent or acknowledged before the deadline.
+    The `BulkReceiver` will end once the sender has shut down.
+
+    Operations in a request that was still in-flight when the deadline expired are reported as unsent, but may have already been applied by Elasticsearch.
+
+    # Examples
+
+    ```no_run
+    # use std::time::Duration;
+    # use futures::Future;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let (bulk_stream, bulk_responses) = client.bulk_stream::<Value>().index("bulk_idx").build();
+
+    let shutdown = bulk_stream
+        .shutdown(Duration::from_secs(30))
+        .map(|summary| {
+            if summary.timed_out() {
+                println!("{} operations were not sent", summary.unsent_ops());
+            }
+        });
+    # Ok(())
+    # }
+    ```
+
+    [BulkShutdownSummary]: struct.BulkShutdownSummary.html
+    */
+    pub fn shutdown(self, deadline: Duration) -> BulkShutdown<TDocument, TResponse> {
+        BulkShutdown {
+            sender: Some(self),
+            deadline: Delay::new(Instant::now() + deadline),
+        }
+    }
+
+    /**
+    Give up on any in-flight request and buffered operations.
+    */
+    fn abandon(mut self) -> BulkShutdownSummary {
+        let mut unsent_body = Vec::new();
+        let mut unsent_ops = 0;
+
+        if let BulkSenderInFlight::Pending(_, ref sent) = self.in_flight {
+            self.complete_batch(sent, 0, 0, true);
+
+            unsent_body.extend_from_slice(&sent.body);
+            unsent_ops += sent.ops;
+        }
+
+        let (body, ops) = self.body.drain();
+        unsent_body.extend(body);
+        unsent_ops += ops;
+
+        BulkShutdownSummary {
+            unsent_ops,
+            unsent_body,
+            timed_out: true,
+        }
+    }
+
+    /**
+    Poll the in-flight request, sending the buffered body if it's ready.
+
+    If `flush` is `true` then the body will be sent even if it isn't full and the timeout hasn't expired.
+    */
+    fn poll_send(&mut self, flush: bool) -> Poll<(), Error> {
         let in_flight = match self.in_flight {
             // The `Sender` is ready to send another request
             BulkSenderInFlight::ReadyToSend => {
                 match self.timeout.poll() {
                     // If the timeout hasn't expired and the body isn't full then we're not ready
-                    Ok(Async::NotReady) if !self.body.is_full() && !self.body.is_empty() => {
+                    Ok(Async::NotReady)
+                        if !flush && !self.body.is_full() && !self.body.is_empty() =>
+                    {
                         return Ok(Async::NotReady);
                     }
                     // Continue
@@ -316,18 +544,37 @@ where
 
                 debug!("Elasticsearch Bulk Stream: sending a bulk request");
 
-                let body = self.body.take();
+                let (body, ops) = self.body.take();
+
+                let sent = SentBatch {
+                    ops,
+                    body: body.freeze(),
+                    started: Instant::now(),
+                };
+                self.stats.record_sent(sent.ops, sent.body.len());
 
-                let req = self.req_template.to_request(body.to_vec());
+                let req = self.req_template.to_request(sent.body.clone());
                 let pending = req.send();
 
-                BulkSenderInFlight::Pending(pending)
+                BulkSenderInFlight::Pending(pending, sent)
             }
             // A request is pending
-            BulkSenderInFlight::Pending(ref mut pending) => {
-                let response = try_ready!(pending.poll());
-                BulkSenderInFlight::Transmitting(Some(response))
-            }
+            BulkSenderInFlight::Pending(ref mut pending, ref sent) => match pending.poll() {
+                Ok(Async::Ready(response)) => {
+                    self.complete_batch(
+                        sent,
+                        response.failed_ops(),
+                        response.rejected_ops(),
+                        false,
+                    );
+                    BulkSenderInFlight::Transmitting(Some(response))
+                }
+                Ok(Async::NotReady) => return Ok(Async::NotReady),
+                Err(e) => {
+                    self.complete_batch(sent, 0, 0, true);
+                    return Err(e);
+                }
+            },
             // A response is transmitting
             BulkSenderInFlight::Transmitting(ref mut response) => {
                 if let Some(item) = response.take() {
@@ -353,12 +600,119 @@ where
         };
 
         self.in_flight = in_flight;
-        self.poll_complete()
+        self.poll_send(flush)
     }
+}
 
-    fn close(&mut self) -> Poll<(), Self::SinkError> {
-        try_ready!(self.poll_complete());
-        self.tx.close()
+/**
+A future returned by [`BulkSender.flush`][BulkSender.flush].
+
+The future resolves with the `BulkSender` once all buffered operations have been sent.
+
+[BulkSender.flush]: struct.BulkSender.html#method.flush
+*/
+pub struct BulkFlush<TDocument, TResponse> {
+    sender: Option<BulkSender<TDocument, TResponse>>,
+}
+
+impl<TDocument, TResponse> Future for BulkFlush<TDocument, TResponse>
+where
+    TDocument: Serialize + Send + 'static,
+    TResponse: DeserializeOwned + IsOk + BulkResponseErrors + Send + 'static,
+{
+    type Item = BulkSender<TDocument, TResponse>;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        try_ready!(self
+            .sender
+            .as_mut()
+            .expect("polled `BulkFlush` after completion")
+            .poll_send(true));
+
+        Ok(Async::Ready(self.sender.take().expect("missing sender")))
+    }
+}
+
+/**
+A future returned by [`BulkSender.shutdown`][BulkSender.shutdown].
+
+[BulkSender.shutdown]: struct.BulkSender.html#method.shutdown
+*/
+pub struct BulkShutdown<TDocument, TResponse> {
+    sender: Option<BulkSender<TDocument, TResponse>>,
+    deadline: Delay,
+}
+
+impl<TDocument, TResponse> Future for BulkShutdown<TDocument, TResponse>
+where
+    TDocument: Serialize + Send + 'static,
+    TResponse: DeserializeOwned + IsOk + BulkResponseErrors + Send + 'static,
+{
+    type Item = BulkShutdownSummary;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let flushed = self
+            .sender
+            .as_mut()
+            .expect("polled `BulkShutdown` after completion")
+            .poll_send(true)?;
+
+        if let Async::Ready(()) = flushed {
+            // Dropping the sender will close the channel to the receiver
+            self.sender.take();
+
+            return Ok(Async::Ready(BulkShutdownSummary {
+                unsent_ops: 0,
+                unsent_body: Vec::new(),
+                timed_out: false,
+            }));
+        }
+
+        try_ready!(self.deadline.poll().map_err(error::request));
+
+        debug!("Elasticsearch Bulk Stream: shutdown deadline expired");
+
+        let sender = self.sender.take().expect("missing sender");
+        Ok(Async::Ready(sender.abandon()))
+    }
+}
+
+/**
+The result of shutting down a `BulkSender`.
+
+Any operations that weren't sent or acknowledged before the deadline are available as a newline-delimited bulk body.
+The body can be read back into operations using a [`BulkReader`][BulkReader].
+
+[BulkReader]: struct.BulkReader.html
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulkShutdownSummary {
+    unsent_ops: usize,
+    unsent_body: Vec<u8>,
+    timed_out: bool,
+}
+
+impl BulkShutdownSummary {
+    /** Whether the deadline expired before all operations were sent. */
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /** The number of operations that weren't sent or acknowledged. */
+    pub fn unsent_ops(&self) -> usize {
+        self.unsent_ops
+    }
+
+    /** The bulk body for operations that weren't sent or acknowledged. */
+    pub fn unsent_body(&self) -> &[u8] {
+        &self.unsent_body
+    }
+
+    /** Take the bulk body for operations that weren't sent or acknowledged. */
+    pub fn into_unsent_body(self) -> Vec<u8> {
+        self.unsent_body
     }
 }
 
@@ -439,3 +793,68 @@ impl StdError for Disconnected {
         "disconnected"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn drain_body_includes_scratch() {
+        let mut body = SenderBody::new(32, None);
+
+        body.push(bulk_raw().index(json!({ "a": 1 }))).unwrap();
+        body.push(bulk_raw().index(json!({ "b": 2 }))).unwrap();
+
+        assert!(body.is_full());
+
+        let (drained, ops) = body.drain();
+
+        let expected = concat!(
+            r#"{"index":{}}"#,
+            "\n",
+            r#"{"a":1}"#,
+            "\n",
+            r#"{"index":{}}"#,
+            "\n",
+            r#"{"b":2}"#,
+            "\n",
+        );
+
+        assert_eq!(2, ops);
+        assert_eq!(expected, String::from_utf8(drained).unwrap());
+        assert!(body.is_empty());
+        assert!(!body.is_full());
+    }
+
+    #[test]
+    fn max_ops_fills_body() {
+        let mut body = SenderBody::new(1024, Some(2));
+
+        body.push(bulk_raw().index(json!({ "a": 1 }))).unwrap();
+
+        assert!(body.has_capacity());
+        assert!(!body.is_full());
+
+        body.push(bulk_raw().index(json!({ "b": 2 }))).unwrap();
+
+        assert!(!body.has_capacity());
+        assert!(body.is_full());
+
+        let (_, ops) = body.take();
+
+        assert_eq!(2, ops);
+        assert!(body.has_capacity());
+    }
+
+    #[test]
+    fn zero_max_ops_accepts_one_op() {
+        let mut body = SenderBody::new(1024, Some(0));
+
+        assert!(body.has_capacity());
+
+        body.push(bulk_raw().index(json!({ "a": 1 }))).unwrap();
+
+        assert!(body.is_full());
+    }
+}
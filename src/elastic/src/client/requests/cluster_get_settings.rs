@@ -0,0 +1,241 @@
+/*!
+Builders for [get cluster settings requests][docs-cluster-get-settings].
+
+[docs-cluster-get-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-get-settings.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::ClusterGetSettingsResponse,
+        ClusterClient,
+    },
+    endpoints::ClusterGetSettingsRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+};
+
+/**
+A [get cluster settings request][docs-cluster-get-settings] builder that can be configured before sending.
+
+Call [`Client.cluster.get_settings`][Client.cluster.get_settings] to get a `ClusterGetSettingsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-cluster-get-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-get-settings.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.cluster.get_settings]: ../../struct.ClusterClient.html#get-cluster-settings-request
+*/
+pub type ClusterGetSettingsRequestBuilder<TSender> =
+    RequestBuilder<TSender, ClusterGetSettingsRequestInner>;
+
+#[doc(hidden)]
+pub struct ClusterGetSettingsRequestInner;
+
+/**
+# Get cluster settings request
+*/
+impl<TSender> ClusterClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`ClusterGetSettingsRequestBuilder`][ClusterGetSettingsRequestBuilder] with this `Client` that can be configured before sending.
+
+    Only settings that have been explicitly set are returned unless defaults are requested using the [builder methods][builder-methods].
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the shard allocation setting that's in effect:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster()
+                         .get_settings()
+                         .include_defaults()
+                         .send()?;
+
+    println!("{:?}", response.get(ClusterSetting::AllocationEnable));
+    # Ok(())
+    # }
+    ```
+
+    [ClusterGetSettingsRequestBuilder]: requests/cluster_get_settings/type.ClusterGetSettingsRequestBuilder.html
+    [builder-methods]: requests/cluster_get_settings/type.ClusterGetSettingsRequestBuilder.html#builder-methods
+    [send-sync]: requests/cluster_get_settings/type.ClusterGetSettingsRequestBuilder.html#send-synchronously
+    [send-async]: requests/cluster_get_settings/type.ClusterGetSettingsRequestBuilder.html#send-asynchronously
+    */
+    pub fn get_settings(self) -> ClusterGetSettingsRequestBuilder<TSender> {
+        RequestBuilder::initial(self.inner, ClusterGetSettingsRequestInner)
+    }
+}
+
+impl ClusterGetSettingsRequestInner {
+    fn into_request(self) -> ClusterGetSettingsRequest<'static> {
+        ClusterGetSettingsRequest::new()
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `ClusterGetSettingsRequestBuilder` before sending it.
+*/
+impl<TSender> ClusterGetSettingsRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /** Return settings with flat dotted keys instead of nested objects. */
+    pub fn flat_settings(self) -> Self {
+        self.params_fluent(|params| params.url_param("flat_settings", true))
+    }
+
+    /** Also return the default values of settings that haven't been set. */
+    pub fn include_defaults(self) -> Self {
+        self.params_fluent(|params| params.url_param("include_defaults", true))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl ClusterGetSettingsRequestBuilder<SyncSender> {
+    /**
+    Send a `ClusterGetSettingsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the persistent settings for the cluster:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster().get_settings().flat_settings().send()?;
+
+    for (key, value) in response.persistent().iter() {
+        println!("{}: {}", key, value);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<ClusterGetSettingsResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl ClusterGetSettingsRequestBuilder<AsyncSender> {
+    /**
+    Send a `ClusterGetSettingsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised cluster settings response.
+
+    # Examples
+
+    Get the persistent settings for the cluster:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.cluster().get_settings().flat_settings().send();
+
+    future.and_then(|response| {
+        for (key, value) in response.persistent().iter() {
+            println!("{}: {}", key, value);
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<ClusterGetSettingsResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.cluster().get_settings().inner.into_request();
+
+        assert_eq!("/_cluster/settings", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_params() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .cluster()
+            .get_settings()
+            .flat_settings()
+            .include_defaults()
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("flat_settings=true"));
+        assert!(qry.contains("include_defaults=true"));
+    }
+}
@@ -0,0 +1,412 @@
+/*!
+Builders for [cluster health requests][docs-cluster-health].
+
+[docs-cluster-health]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-health.html
+*/
+
+use futures::Future;
+use std::time::Duration;
+
+use crate::{
+    client::{
+        requests::{
+            common::format_duration,
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::{
+            ClusterHealthResponse,
+            HealthStatus,
+        },
+        ClusterClient,
+    },
+    endpoints::ClusterHealthRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::Index,
+};
+
+/**
+A [cluster health request][docs-cluster-health] builder that can be configured before sending.
+
+Call [`Client.cluster.health`][Client.cluster.health] to get a `ClusterHealthRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-cluster-health]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-health.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.cluster.health]: ../../struct.ClusterClient.html#cluster-health-request
+*/
+pub type ClusterHealthRequestBuilder<TSender> = RequestBuilder<TSender, ClusterHealthRequestInner>;
+
+#[doc(hidden)]
+pub struct ClusterHealthRequestInner {
+    index: Option<Index<'static>>,
+}
+
+/** The level of detail to return cluster health at. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HealthLevel {
+    /** Only return the health of the cluster. This is the default. */
+    Cluster,
+    /** Also return the health of each index. */
+    Indices,
+    /** Also return the health of each index and shard. */
+    Shards,
+}
+
+impl HealthLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            HealthLevel::Cluster => "cluster",
+            HealthLevel::Indices => "indices",
+            HealthLevel::Shards => "shards",
+        }
+    }
+}
+
+/**
+A number of nodes to wait for.
+
+A plain number converts into an exact number of nodes.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NodeCount {
+    /** Wait for at least this many nodes. */
+    AtLeast(u32),
+    /** Wait for at most this many nodes. */
+    AtMost(u32),
+    /** Wait for exactly this many nodes. */
+    Exactly(u32),
+}
+
+impl NodeCount {
+    fn to_param(self) -> String {
+        match self {
+            NodeCount::AtLeast(nodes) => format!(">={}", nodes),
+            NodeCount::AtMost(nodes) => format!("<={}", nodes),
+            NodeCount::Exactly(nodes) => nodes.to_string(),
+        }
+    }
+}
+
+impl From<u32> for NodeCount {
+    fn from(nodes: u32) -> Self {
+        NodeCount::Exactly(nodes)
+    }
+}
+
+/**
+A number of shards that must be active.
+
+A plain number converts into a count of shards.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActiveShards {
+    /** Wait for every shard to be active. */
+    All,
+    /** Wait for this many shards to be active. */
+    Count(u32),
+}
+
+impl ActiveShards {
+    fn to_param(self) -> String {
+        match self {
+            ActiveShards::All => "all".to_owned(),
+            ActiveShards::Count(shards) => shards.to_string(),
+        }
+    }
+}
+
+impl From<u32> for ActiveShards {
+    fn from(shards: u32) -> Self {
+        ActiveShards::Count(shards)
+    }
+}
+
+/**
+# Cluster health request
+*/
+impl<TSender> ClusterClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`ClusterHealthRequestBuilder`][ClusterHealthRequestBuilder] with this `Client` that can be configured before sending.
+
+    The request can wait for conditions like a minimum health status or number of nodes before returning.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Wait up to a minute for the cluster to become at least `yellow`:
+
+    ```no_run
+    # use std::time::Duration;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster()
+                         .health()
+                         .wait_for_status(HealthStatus::Yellow)
+                         .timeout(Duration::from_secs(60))
+                         .send()?;
+
+    assert!(!response.timed_out());
+    # Ok(())
+    # }
+    ```
+
+    [ClusterHealthRequestBuilder]: requests/cluster_health/type.ClusterHealthRequestBuilder.html
+    [builder-methods]: requests/cluster_health/type.ClusterHealthRequestBuilder.html#builder-methods
+    [send-sync]: requests/cluster_health/type.ClusterHealthRequestBuilder.html#send-synchronously
+    [send-async]: requests/cluster_health/type.ClusterHealthRequestBuilder.html#send-asynchronously
+    */
+    pub fn health(self) -> ClusterHealthRequestBuilder<TSender> {
+        RequestBuilder::initial(self.inner, ClusterHealthRequestInner { index: None })
+    }
+}
+
+impl ClusterHealthRequestInner {
+    fn into_request(self) -> ClusterHealthRequest<'static> {
+        match self.index {
+            Some(index) => ClusterHealthRequest::for_index(index),
+            None => ClusterHealthRequest::new(),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `ClusterHealthRequestBuilder` before sending it.
+*/
+impl<TSender> ClusterHealthRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Only get the health of the given index.
+
+    The index can be a comma-separated list of indices or contain wildcards.
+    */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /** Wait until the health is at least the given status. */
+    pub fn wait_for_status(self, status: HealthStatus) -> Self {
+        self.params_fluent(move |params| params.url_param("wait_for_status", status.as_str()))
+    }
+
+    /**
+    Wait until the given number of nodes are available.
+
+    The number can be exact, like `3`, or a bound, like `NodeCount::AtLeast(3)`.
+    */
+    pub fn wait_for_nodes(self, nodes: impl Into<NodeCount>) -> Self {
+        let nodes = nodes.into().to_param();
+
+        self.params_fluent(move |params| params.url_param("wait_for_nodes", nodes.clone()))
+    }
+
+    /**
+    Wait until the given number of shards are active.
+
+    The number can be a count, like `2`, or `ActiveShards::All` to wait for every shard.
+    */
+    pub fn wait_for_active_shards(self, shards: impl Into<ActiveShards>) -> Self {
+        let shards = shards.into().to_param();
+
+        self.params_fluent(move |params| params.url_param("wait_for_active_shards", shards.clone()))
+    }
+
+    /** Wait until there are no relocating shards. */
+    pub fn wait_for_no_relocating_shards(self) -> Self {
+        self.params_fluent(|params| params.url_param("wait_for_no_relocating_shards", true))
+    }
+
+    /** Wait until there are no initializing shards. */
+    pub fn wait_for_no_initializing_shards(self) -> Self {
+        self.params_fluent(|params| params.url_param("wait_for_no_initializing_shards", true))
+    }
+
+    /**
+    Set how long to wait for conditions to be met.
+
+    If the conditions aren't met before the timeout then the response will be returned with `timed_out` set to `true`.
+    */
+    pub fn timeout(self, timeout: Duration) -> Self {
+        let timeout = format_duration(timeout);
+
+        self.params_fluent(move |params| params.url_param("timeout", timeout.clone()))
+    }
+
+    /** Set the level of detail to return health at. */
+    pub fn level(self, level: HealthLevel) -> Self {
+        self.params_fluent(move |params| params.url_param("level", level.as_str()))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl ClusterHealthRequestBuilder<SyncSender> {
+    /**
+    Send a `ClusterHealthRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the health of each index in the cluster:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster()
+                         .health()
+                         .level(HealthLevel::Indices)
+                         .send()?;
+
+    for (index, health) in response.indices() {
+        println!("{}: {}", index, health.status());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<ClusterHealthResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl ClusterHealthRequestBuilder<AsyncSender> {
+    /**
+    Send a `ClusterHealthRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised cluster health response.
+
+    # Examples
+
+    Get the health of each index in the cluster:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.cluster()
+                       .health()
+                       .level(HealthLevel::Indices)
+                       .send();
+
+    future.and_then(|response| {
+        for (index, health) in response.indices() {
+            println!("{}: {}", index, health.status());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<ClusterHealthResponse>;
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.cluster().health().inner.into_request();
+
+        assert_eq!("/_cluster/health", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .cluster()
+            .health()
+            .index("testindex")
+            .inner
+            .into_request();
+
+        assert_eq!("/_cluster/health/testindex", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_wait_conditions() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .cluster()
+            .health()
+            .wait_for_status(HealthStatus::Yellow)
+            .wait_for_nodes(NodeCount::AtLeast(2))
+            .wait_for_active_shards(ActiveShards::All)
+            .timeout(Duration::from_secs(30))
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("wait_for_status=yellow"));
+        assert!(qry.contains("wait_for_nodes=%3E%3D2"));
+        assert!(qry.contains("wait_for_active_shards=all"));
+        assert!(qry.contains("timeout=30s"));
+    }
+}
@@ -0,0 +1,326 @@
+/*!
+Builders for [update cluster settings requests][docs-cluster-update-settings].
+
+[docs-cluster-update-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-update-settings.html
+*/
+
+use futures::Future;
+use serde_json;
+
+use crate::{
+    client::{
+        requests::{
+            cluster_settings::ClusterSettings,
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::ClusterPutSettingsResponse,
+        ClusterClient,
+    },
+    endpoints::ClusterPutSettingsRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+};
+
+/**
+An [update cluster settings request][docs-cluster-update-settings] builder that can be configured before sending.
+
+Call [`Client.cluster.put_settings`][Client.cluster.put_settings] to get a `ClusterPutSettingsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-cluster-update-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-update-settings.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.cluster.put_settings]: ../../struct.ClusterClient.html#update-cluster-settings-request
+*/
+pub type ClusterPutSettingsRequestBuilder<TSender> =
+    RequestBuilder<TSender, ClusterPutSettingsRequestInner>;
+
+#[doc(hidden)]
+pub struct ClusterPutSettingsRequestInner {
+    persistent: ClusterSettings,
+    transient: ClusterSettings,
+}
+
+/**
+# Update cluster settings request
+*/
+impl<TSender> ClusterClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`ClusterPutSettingsRequestBuilder`][ClusterPutSettingsRequestBuilder] with this `Client` that can be configured before sending.
+
+    Settings are added to the request as either persistent or transient using the [builder methods][builder-methods].
+    At least one setting must be added before the request is sent.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Move shards off the `node-1` node before taking it out of the cluster:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster()
+                         .put_settings()
+                         .transient(ClusterSettings::new().exclude_nodes_by_name(vec!["node-1"]))
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [ClusterPutSettingsRequestBuilder]: requests/cluster_put_settings/type.ClusterPutSettingsRequestBuilder.html
+    [builder-methods]: requests/cluster_put_settings/type.ClusterPutSettingsRequestBuilder.html#builder-methods
+    [send-sync]: requests/cluster_put_settings/type.ClusterPutSettingsRequestBuilder.html#send-synchronously
+    [send-async]: requests/cluster_put_settings/type.ClusterPutSettingsRequestBuilder.html#send-asynchronously
+    */
+    pub fn put_settings(self) -> ClusterPutSettingsRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            ClusterPutSettingsRequestInner {
+                persistent: ClusterSettings::new(),
+                transient: ClusterSettings::new(),
+            },
+        )
+    }
+}
+
+impl ClusterPutSettingsRequestInner {
+    fn into_request(self) -> Result<ClusterPutSettingsRequest<'static, Vec<u8>>, Error> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            #[serde(skip_serializing_if = "ClusterSettings::is_empty")]
+            persistent: &'a ClusterSettings,
+            #[serde(skip_serializing_if = "ClusterSettings::is_empty")]
+            transient: &'a ClusterSettings,
+        }
+
+        if self.persistent.is_empty() && self.transient.is_empty() {
+            return Err(error::request(error::message(
+                "at least one persistent or transient setting must be updated",
+            )));
+        }
+
+        let body = serde_json::to_vec(&Body {
+            persistent: &self.persistent,
+            transient: &self.transient,
+        })
+        .map_err(error::request)?;
+
+        Ok(ClusterPutSettingsRequest::new(body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `ClusterPutSettingsRequestBuilder` before sending it.
+*/
+impl<TSender> ClusterPutSettingsRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set settings that survive a full cluster restart.
+
+    Calling this method replaces any persistent settings set previously.
+    */
+    pub fn persistent(mut self, settings: ClusterSettings) -> Self {
+        self.inner.persistent = settings;
+        self
+    }
+
+    /**
+    Set settings that are lost on a full cluster restart.
+
+    Calling this method replaces any transient settings set previously.
+    */
+    pub fn transient(mut self, settings: ClusterSettings) -> Self {
+        self.inner.transient = settings;
+        self
+    }
+
+    /** Return the updated settings with flat dotted keys instead of nested objects. */
+    pub fn flat_settings(self) -> Self {
+        self.params_fluent(|params| params.url_param("flat_settings", true))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl ClusterPutSettingsRequestBuilder<SyncSender> {
+    /**
+    Send a `ClusterPutSettingsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Stop allocating replica shards while restarting a node:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster()
+                         .put_settings()
+                         .persistent(ClusterSettings::new().allocation_enable(AllocationEnable::Primaries))
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<ClusterPutSettingsResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl ClusterPutSettingsRequestBuilder<AsyncSender> {
+    /**
+    Send a `ClusterPutSettingsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised cluster settings response.
+
+    # Examples
+
+    Stop allocating replica shards while restarting a node:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.cluster()
+                       .put_settings()
+                       .persistent(ClusterSettings::new().allocation_enable(AllocationEnable::Primaries))
+                       .send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<ClusterPutSettingsResponse>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .cluster()
+            .put_settings()
+            .transient(ClusterSettings::new().allocation_enable(AllocationEnable::None))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/_cluster/settings", req.url.as_ref());
+        assert_eq!(
+            json!({ "transient": { "cluster.routing.allocation.enable": "none" } }),
+            actual_body
+        );
+    }
+
+    #[test]
+    fn persistent_and_transient() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .cluster()
+            .put_settings()
+            .persistent(ClusterSettings::new().max_shards_per_node(500))
+            .transient(ClusterSettings::new().reset(ClusterSetting::AllocationExcludeName))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        let expected_body = json!({
+            "persistent": { "cluster.max_shards_per_node": 500 },
+            "transient": { "cluster.routing.allocation.exclude._name": null }
+        });
+
+        assert_eq!(expected_body, actual_body);
+    }
+
+    #[test]
+    fn empty_settings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.cluster().put_settings().inner.into_request();
+
+        assert!(req.is_err());
+    }
+}
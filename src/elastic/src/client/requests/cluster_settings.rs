@@ -0,0 +1,331 @@
+/*!
+A typed model for [cluster settings][docs-cluster-settings].
+
+Use [`ClusterSettings`][ClusterSettings] to set persistent or transient settings with a [cluster update settings request][docs-cluster-update-settings].
+
+[docs-cluster-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-cluster.html
+[docs-cluster-update-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-update-settings.html
+[ClusterSettings]: struct.ClusterSettings.html
+*/
+
+use std::fmt;
+
+use serde_json::{
+    Map,
+    Value,
+};
+
+/**
+A set of cluster settings to update.
+
+Settings are keyed by their full dotted name, like `cluster.routing.allocation.enable`.
+Common settings have typed methods, and any other setting can be set using [`setting`](#method.setting).
+Settings can be returned to their default value using [`reset`](#method.reset).
+
+# Examples
+
+Move shards off a node before taking it out of the cluster:
+
+```
+# use elastic::prelude::*;
+let settings = ClusterSettings::new()
+    .exclude_nodes_by_name(vec!["node-1"])
+    .setting(ClusterSetting::ClusterConcurrentRebalance, 4);
+```
+
+Allow shards to be allocated to the node again:
+
+```
+# use elastic::prelude::*;
+let settings = ClusterSettings::new().reset(ClusterSetting::AllocationExcludeName);
+```
+*/
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ClusterSettings(Map<String, Value>);
+
+impl ClusterSettings {
+    /** Create an empty set of cluster settings. */
+    pub fn new() -> Self {
+        ClusterSettings::default()
+    }
+
+    /** Set which kinds of shards can be allocated. */
+    pub fn allocation_enable(self, enable: AllocationEnable) -> Self {
+        self.setting(ClusterSetting::AllocationEnable, enable.as_str())
+    }
+
+    /** Set which kinds of shards can be rebalanced. */
+    pub fn rebalance_enable(self, enable: RebalanceEnable) -> Self {
+        self.setting(ClusterSetting::RebalanceEnable, enable.as_str())
+    }
+
+    /**
+    Don't allocate shards to nodes with any of the given names.
+
+    Shards already allocated to the nodes will be moved off them.
+    */
+    pub fn exclude_nodes_by_name<I>(self, names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.setting(ClusterSetting::AllocationExcludeName, join(names))
+    }
+
+    /**
+    Don't allocate shards to nodes with any of the given ip addresses.
+
+    Shards already allocated to the nodes will be moved off them.
+    */
+    pub fn exclude_nodes_by_ip<I>(self, ips: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.setting(ClusterSetting::AllocationExcludeIp, join(ips))
+    }
+
+    /**
+    Don't allocate shards to nodes with any of the given host names.
+
+    Shards already allocated to the nodes will be moved off them.
+    */
+    pub fn exclude_nodes_by_host<I>(self, hosts: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.setting(ClusterSetting::AllocationExcludeHost, join(hosts))
+    }
+
+    /** Set the maximum number of primary and replica shards for each data node. */
+    pub fn max_shards_per_node(self, max_shards_per_node: u32) -> Self {
+        self.setting(ClusterSetting::MaxShardsPerNode, max_shards_per_node)
+    }
+
+    /** Set whether or not the cluster is read-only. */
+    pub fn read_only(self, read_only: bool) -> Self {
+        self.setting(ClusterSetting::ReadOnly, read_only)
+    }
+
+    /** Set whether or not indices are created automatically when documents are indexed into them. */
+    pub fn auto_create_index(self, auto_create_index: bool) -> Self {
+        self.setting(ClusterSetting::AutoCreateIndex, auto_create_index)
+    }
+
+    /**
+    Set a setting that isn't covered by the other methods.
+
+    The key is the full dotted name of the setting, or a [`ClusterSetting`](enum.ClusterSetting.html).
+    */
+    pub fn setting(mut self, key: impl AsRef<str>, value: impl Into<Value>) -> Self {
+        self.0.insert(key.as_ref().to_owned(), value.into());
+        self
+    }
+
+    /**
+    Return a setting to its default value.
+
+    Keys can use wildcards, so `cluster.routing.allocation.*` will reset all allocation settings.
+    */
+    pub fn reset(self, key: impl AsRef<str>) -> Self {
+        self.setting(key, Value::Null)
+    }
+
+    /** Get the value of a setting. */
+    pub fn get_setting(&self, key: impl AsRef<str>) -> Option<&Value> {
+        self.0.get(key.as_ref())
+    }
+
+    /** Whether or not there are any settings. */
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+fn join<I>(values: I) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    values
+        .into_iter()
+        .map(|value| value.as_ref().to_owned())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/**
+The key for a common cluster setting.
+
+Keys can be used to set values in [`ClusterSettings`](struct.ClusterSettings.html) and to look them up in cluster settings responses.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClusterSetting {
+    /** `cluster.routing.allocation.enable` */
+    AllocationEnable,
+    /** `cluster.routing.rebalance.enable` */
+    RebalanceEnable,
+    /** `cluster.routing.allocation.exclude._name` */
+    AllocationExcludeName,
+    /** `cluster.routing.allocation.exclude._ip` */
+    AllocationExcludeIp,
+    /** `cluster.routing.allocation.exclude._host` */
+    AllocationExcludeHost,
+    /** `cluster.routing.allocation.cluster_concurrent_rebalance` */
+    ClusterConcurrentRebalance,
+    /** `cluster.routing.allocation.node_concurrent_recoveries` */
+    NodeConcurrentRecoveries,
+    /** `cluster.routing.allocation.disk.threshold_enabled` */
+    DiskThresholdEnabled,
+    /** `cluster.routing.allocation.disk.watermark.low` */
+    DiskWatermarkLow,
+    /** `cluster.routing.allocation.disk.watermark.high` */
+    DiskWatermarkHigh,
+    /** `cluster.routing.allocation.disk.watermark.flood_stage` */
+    DiskWatermarkFloodStage,
+    /** `cluster.max_shards_per_node` */
+    MaxShardsPerNode,
+    /** `cluster.blocks.read_only` */
+    ReadOnly,
+    /** `action.auto_create_index` */
+    AutoCreateIndex,
+    /** `indices.recovery.max_bytes_per_sec` */
+    RecoveryMaxBytesPerSec,
+}
+
+impl ClusterSetting {
+    /** The full dotted name of the setting. */
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ClusterSetting::AllocationEnable => "cluster.routing.allocation.enable",
+            ClusterSetting::RebalanceEnable => "cluster.routing.rebalance.enable",
+            ClusterSetting::AllocationExcludeName => "cluster.routing.allocation.exclude._name",
+            ClusterSetting::AllocationExcludeIp => "cluster.routing.allocation.exclude._ip",
+            ClusterSetting::AllocationExcludeHost => "cluster.routing.allocation.exclude._host",
+            ClusterSetting::ClusterConcurrentRebalance => {
+                "cluster.routing.allocation.cluster_concurrent_rebalance"
+            }
+            ClusterSetting::NodeConcurrentRecoveries => {
+                "cluster.routing.allocation.node_concurrent_recoveries"
+            }
+            ClusterSetting::DiskThresholdEnabled => {
+                "cluster.routing.allocation.disk.threshold_enabled"
+            }
+            ClusterSetting::DiskWatermarkLow => "cluster.routing.allocation.disk.watermark.low",
+            ClusterSetting::DiskWatermarkHigh => "cluster.routing.allocation.disk.watermark.high",
+            ClusterSetting::DiskWatermarkFloodStage => {
+                "cluster.routing.allocation.disk.watermark.flood_stage"
+            }
+            ClusterSetting::MaxShardsPerNode => "cluster.max_shards_per_node",
+            ClusterSetting::ReadOnly => "cluster.blocks.read_only",
+            ClusterSetting::AutoCreateIndex => "action.auto_create_index",
+            ClusterSetting::RecoveryMaxBytesPerSec => "indices.recovery.max_bytes_per_sec",
+        }
+    }
+}
+
+impl AsRef<str> for ClusterSetting {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ClusterSetting {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/** Which kinds of shards can be allocated. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AllocationEnable {
+    /** Allocate all kinds of shards. This is the default. */
+    All,
+    /** Only allocate primary shards. */
+    Primaries,
+    /** Only allocate primary shards for new indices. */
+    NewPrimaries,
+    /** Don't allocate any shards. */
+    None,
+}
+
+impl AllocationEnable {
+    fn as_str(self) -> &'static str {
+        match self {
+            AllocationEnable::All => "all",
+            AllocationEnable::Primaries => "primaries",
+            AllocationEnable::NewPrimaries => "new_primaries",
+            AllocationEnable::None => "none",
+        }
+    }
+}
+
+/** Which kinds of shards can be rebalanced. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RebalanceEnable {
+    /** Rebalance all kinds of shards. This is the default. */
+    All,
+    /** Only rebalance primary shards. */
+    Primaries,
+    /** Only rebalance replica shards. */
+    Replicas,
+    /** Don't rebalance any shards. */
+    None,
+}
+
+impl RebalanceEnable {
+    fn as_str(self) -> &'static str {
+        match self {
+            RebalanceEnable::All => "all",
+            RebalanceEnable::Primaries => "primaries",
+            RebalanceEnable::Replicas => "replicas",
+            RebalanceEnable::None => "none",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    #[test]
+    fn serialize_settings() {
+        let settings = ClusterSettings::new()
+            .allocation_enable(AllocationEnable::Primaries)
+            .exclude_nodes_by_name(vec!["node-1", "node-2"])
+            .max_shards_per_node(500)
+            .reset(ClusterSetting::RebalanceEnable)
+            .setting("cluster.info.update.interval", "1m");
+
+        let ser = serde_json::to_value(&settings).unwrap();
+
+        let expected = json!({
+            "cluster.routing.allocation.enable": "primaries",
+            "cluster.routing.allocation.exclude._name": "node-1,node-2",
+            "cluster.max_shards_per_node": 500,
+            "cluster.routing.rebalance.enable": null,
+            "cluster.info.update.interval": "1m"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn get_setting() {
+        let settings = ClusterSettings::new().read_only(true);
+
+        assert_eq!(
+            Some(&Value::Bool(true)),
+            settings.get_setting(ClusterSetting::ReadOnly)
+        );
+        assert_eq!(
+            Some(&Value::Bool(true)),
+            settings.get_setting("cluster.blocks.read_only")
+        );
+        assert!(!settings.is_empty());
+        assert!(ClusterSettings::new().is_empty());
+    }
+}
@@ -0,0 +1,358 @@
+/*!
+Builders for [cluster state requests][docs-cluster-state].
+
+[docs-cluster-state]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-state.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::ClusterStateResponse,
+        ClusterClient,
+    },
+    endpoints::ClusterStateRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::Index,
+};
+
+/**
+A [cluster state request][docs-cluster-state] builder that can be configured before sending.
+
+Call [`Client.cluster.state`][Client.cluster.state] to get a `ClusterStateRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-cluster-state]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-state.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.cluster.state]: ../../struct.ClusterClient.html#cluster-state-request
+*/
+pub type ClusterStateRequestBuilder<TSender> = RequestBuilder<TSender, ClusterStateRequestInner>;
+
+#[doc(hidden)]
+pub struct ClusterStateRequestInner {
+    index: Option<Index<'static>>,
+    metrics: Vec<ClusterStateMetric>,
+}
+
+/** A part of the cluster state that can be requested. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClusterStateMetric {
+    /** The version of the cluster state. */
+    Version,
+    /** The elected master node. */
+    MasterNode,
+    /** The nodes in the cluster. */
+    Nodes,
+    /** Where each shard is allocated. */
+    RoutingTable,
+    /** The shards allocated to each node. */
+    RoutingNodes,
+    /** Metadata for the cluster and its indices, like settings and mappings. */
+    Metadata,
+    /** Blocks that prevent operations on the cluster or its indices. */
+    Blocks,
+}
+
+impl ClusterStateMetric {
+    fn as_str(self) -> &'static str {
+        match self {
+            ClusterStateMetric::Version => "version",
+            ClusterStateMetric::MasterNode => "master_node",
+            ClusterStateMetric::Nodes => "nodes",
+            ClusterStateMetric::RoutingTable => "routing_table",
+            ClusterStateMetric::RoutingNodes => "routing_nodes",
+            ClusterStateMetric::Metadata => "metadata",
+            ClusterStateMetric::Blocks => "blocks",
+        }
+    }
+}
+
+/**
+# Cluster state request
+*/
+impl<TSender> ClusterClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`ClusterStateRequestBuilder`][ClusterStateRequestBuilder] with this `Client` that can be configured before sending.
+
+    The full cluster state can be large, so it's a good idea to only request the metrics that are needed using the [builder methods][builder-methods].
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the elected master node and the nodes in the cluster:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster()
+                         .state()
+                         .metric(ClusterStateMetric::MasterNode)
+                         .metric(ClusterStateMetric::Nodes)
+                         .send()?;
+
+    for (id, node) in response.nodes() {
+        let is_master = response.master_node() == Some(id);
+
+        println!("{} ({}): master {}", node.name(), id, is_master);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [ClusterStateRequestBuilder]: requests/cluster_state/type.ClusterStateRequestBuilder.html
+    [builder-methods]: requests/cluster_state/type.ClusterStateRequestBuilder.html#builder-methods
+    [send-sync]: requests/cluster_state/type.ClusterStateRequestBuilder.html#send-synchronously
+    [send-async]: requests/cluster_state/type.ClusterStateRequestBuilder.html#send-asynchronously
+    */
+    pub fn state(self) -> ClusterStateRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            ClusterStateRequestInner {
+                index: None,
+                metrics: Vec::new(),
+            },
+        )
+    }
+}
+
+impl ClusterStateRequestInner {
+    fn into_request(self) -> ClusterStateRequest<'static> {
+        let metrics = if self.metrics.is_empty() {
+            None
+        } else {
+            let metrics = self
+                .metrics
+                .iter()
+                .map(|metric| metric.as_str())
+                .collect::<Vec<_>>()
+                .join(",");
+
+            Some(metrics)
+        };
+
+        match (metrics, self.index) {
+            (Some(metrics), Some(index)) => ClusterStateRequest::for_metric_index(metrics, index),
+            (None, Some(index)) => ClusterStateRequest::for_metric_index("_all", index),
+            (Some(metrics), None) => ClusterStateRequest::for_metric(metrics),
+            (None, None) => ClusterStateRequest::new(),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `ClusterStateRequestBuilder` before sending it.
+*/
+impl<TSender> ClusterStateRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Only return the given part of the cluster state.
+
+    This method can be called multiple times to return multiple parts.
+    */
+    pub fn metric(mut self, metric: ClusterStateMetric) -> Self {
+        if !self.inner.metrics.contains(&metric) {
+            self.inner.metrics.push(metric);
+        }
+
+        self
+    }
+
+    /**
+    Only return metadata and routing for the given index.
+
+    The index can be a comma-separated list of indices or contain wildcards.
+    */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /** Return settings with flat dotted keys instead of nested objects. */
+    pub fn flat_settings(self) -> Self {
+        self.params_fluent(|params| params.url_param("flat_settings", true))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl ClusterStateRequestBuilder<SyncSender> {
+    /**
+    Send a `ClusterStateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the state of the `myindex` index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster()
+                         .state()
+                         .metric(ClusterStateMetric::Metadata)
+                         .index("myindex")
+                         .send()?;
+
+    if let Some(index) = response.metadata().and_then(|metadata| metadata.index("myindex")) {
+        println!("{}", index.state());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<ClusterStateResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl ClusterStateRequestBuilder<AsyncSender> {
+    /**
+    Send a `ClusterStateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised cluster state response.
+
+    # Examples
+
+    Get the state of the `myindex` index:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.cluster()
+                       .state()
+                       .metric(ClusterStateMetric::Metadata)
+                       .index("myindex")
+                       .send();
+
+    future.and_then(|response| {
+        if let Some(index) = response.metadata().and_then(|metadata| metadata.index("myindex")) {
+            println!("{}", index.state());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<ClusterStateResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.cluster().state().inner.into_request();
+
+        assert_eq!("/_cluster/state", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_metrics() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .cluster()
+            .state()
+            .metric(ClusterStateMetric::Nodes)
+            .metric(ClusterStateMetric::MasterNode)
+            .metric(ClusterStateMetric::Nodes)
+            .inner
+            .into_request();
+
+        assert_eq!("/_cluster/state/nodes,master_node", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .cluster()
+            .state()
+            .index("testindex")
+            .inner
+            .into_request();
+
+        assert_eq!("/_cluster/state/_all/testindex", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_metrics_and_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .cluster()
+            .state()
+            .metric(ClusterStateMetric::Metadata)
+            .index("testindex")
+            .inner
+            .into_request();
+
+        assert_eq!("/_cluster/state/metadata/testindex", req.url.as_ref());
+    }
+}
@@ -2,7 +2,11 @@
 Types that are common between requests.
 */
 
-use std::ops::Not;
+use std::{
+    fmt,
+    ops::Not,
+    time::Duration,
+};
 
 use serde::ser::{
     Serialize,
@@ -199,3 +203,190 @@ impl<'a> From<&'a str> for ScriptBuilder<DefaultParams> {
         ScriptBuilder::new(source)
     }
 }
+
+/**
+How an externally maintained version is compared to the version of an indexed document.
+
+See [optimistic concurrency control][docs-occ] for more details.
+
+[docs-occ]: https://www.elastic.co/guide/en/elasticsearch/reference/current/optimistic-concurrency-control.html
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VersionType {
+    /** Only apply the operation if the given version is greater than the current version. */
+    External,
+    /** Only apply the operation if the given version is greater than or equal to the current version. */
+    ExternalGte,
+}
+
+impl VersionType {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            VersionType::External => "external",
+            VersionType::ExternalGte => "external_gte",
+        }
+    }
+}
+
+/** What to do when a by-query request hits a version conflict. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Conflicts {
+    /** Stop the request at the first version conflict. This is the default. */
+    Abort,
+    /** Count version conflicts and carry on with the rest of the documents. */
+    Proceed,
+}
+
+impl Conflicts {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Conflicts::Abort => "abort",
+            Conflicts::Proceed => "proceed",
+        }
+    }
+}
+
+/**
+The number of slices to split a by-query request into.
+
+Each slice is processed in parallel as a sub-task.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Slices {
+    /** Let Elasticsearch pick a number of slices, usually one per shard. */
+    Auto,
+    /** Use a fixed number of slices. */
+    Count(u32),
+}
+
+impl From<u32> for Slices {
+    fn from(count: u32) -> Self {
+        Slices::Count(count)
+    }
+}
+
+impl fmt::Display for Slices {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Slices::Auto => f.write_str("auto"),
+            Slices::Count(count) => fmt::Display::fmt(&count, f),
+        }
+    }
+}
+
+/** A limit on the number of documents a by-query request can process each second. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RequestsPerSecond {
+    /** Don't throttle the request. */
+    Unlimited,
+    /** Throttle the request to the given number of documents per second. */
+    Limit(f32),
+}
+
+impl From<f32> for RequestsPerSecond {
+    fn from(limit: f32) -> Self {
+        RequestsPerSecond::Limit(limit)
+    }
+}
+
+impl fmt::Display for RequestsPerSecond {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RequestsPerSecond::Unlimited => f.write_str("-1"),
+            RequestsPerSecond::Limit(limit) => fmt::Display::fmt(&limit, f),
+        }
+    }
+}
+
+/** Join a set of field names or patterns into a comma-separated url parameter. */
+pub(crate) fn field_list<I>(fields: I) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    fields
+        .into_iter()
+        .map(|field| field.as_ref().to_owned())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/** Options for the term vectors returned by single and multi term vectors requests. */
+#[derive(Serialize, Default)]
+pub(crate) struct TermVectorsOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) fields: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) offsets: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) positions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) payloads: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) term_statistics: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) field_statistics: Option<bool>,
+}
+
+impl TermVectorsOptions {
+    pub(crate) fn fields<I>(&mut self, fields: I)
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.fields = Some(
+            fields
+                .into_iter()
+                .map(|field| field.as_ref().to_owned())
+                .collect(),
+        );
+    }
+}
+
+/**
+Format a duration using the largest time unit that represents it exactly.
+
+Elasticsearch doesn't accept time units smaller than a millisecond in most places, so any remainder is rounded up to the next millisecond.
+*/
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let millis =
+        duration.as_secs() * 1000 + u64::from(duration.subsec_nanos() + 999_999) / 1_000_000;
+
+    let units = [
+        ("d", 24 * 60 * 60 * 1000),
+        ("h", 60 * 60 * 1000),
+        ("m", 60 * 1000),
+        ("s", 1000),
+    ];
+
+    for &(unit, size) in &units {
+        if millis >= size && millis % size == 0 {
+            return format!("{}{}", millis / size, unit);
+        }
+    }
+
+    format!("{}ms", millis)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::format_duration;
+
+    #[test]
+    fn format_durations() {
+        assert_eq!("1d", format_duration(Duration::from_secs(24 * 60 * 60)));
+        assert_eq!("90m", format_duration(Duration::from_secs(90 * 60)));
+        assert_eq!("30s", format_duration(Duration::from_secs(30)));
+        assert_eq!("1500ms", format_duration(Duration::from_millis(1500)));
+        assert_eq!("0ms", format_duration(Duration::from_secs(0)));
+    }
+
+    #[test]
+    fn format_sub_millisecond_durations() {
+        assert_eq!("1ms", format_duration(Duration::from_micros(1)));
+        assert_eq!("2ms", format_duration(Duration::from_micros(1500)));
+        assert_eq!("1s", format_duration(Duration::from_nanos(999_999_999)));
+    }
+}
@@ -0,0 +1,531 @@
+/*!
+Builders for checking a document mapping against a [live index mapping][docs-get-index].
+
+[docs-get-index]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-index.html
+*/
+
+use futures::Future;
+use serde::de::{
+    Deserialize,
+    Deserializer,
+    Error as DeError,
+};
+use serde_json::{
+    self,
+    Value,
+};
+use std::marker::PhantomData;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::{
+            IndexMapping,
+            IndicesGetAliasResponse,
+            IndicesGetMappingResponse,
+            MappingDiff,
+        },
+        DocumentClient,
+    },
+    endpoints::IndicesGetRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::{
+        receiver::IsOkOnSuccess,
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+    },
+    params::Index,
+    types::document::{
+        DocumentType,
+        StaticIndex,
+    },
+};
+
+/**
+A check mapping request builder that can be configured before sending.
+
+Call [`Client.document.check_mapping`][Client.document.check_mapping] to get a `CheckMappingRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document.check_mapping]: ../../struct.DocumentClient.html#check-mapping-request
+*/
+pub type CheckMappingRequestBuilder<TSender, TDocument> =
+    RequestBuilder<TSender, CheckMappingRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct CheckMappingRequestInner<TDocument> {
+    index: Index<'static>,
+    _marker: PhantomData<TDocument>,
+}
+
+/**
+The mappings and aliases for the indices matched by a get index request.
+
+The same response body is read as both a get mapping and a get alias response, so an index that's an alias can be resolved without a second request.
+*/
+struct IndexMetadataResponse {
+    mappings: IndicesGetMappingResponse,
+    aliases: IndicesGetAliasResponse,
+}
+
+impl<'de> Deserialize<'de> for IndexMetadataResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        let mappings = serde_json::from_value(value.clone()).map_err(D::Error::custom)?;
+        let aliases = serde_json::from_value(value).map_err(D::Error::custom)?;
+
+        Ok(IndexMetadataResponse { mappings, aliases })
+    }
+}
+
+impl IsOkOnSuccess for IndexMetadataResponse {}
+
+/**
+# Check mapping request
+*/
+impl<TSender, TDocument> DocumentClient<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`CheckMappingRequestBuilder`][CheckMappingRequestBuilder] with this `Client` that can be configured before sending.
+
+    The request gets the live mapping and aliases for the document's index and compares the mapping with the one for the [`DocumentType`][documents-mod].
+    If the index is an alias that points to several indices then the mapping of its write index is checked.
+    The result is a [`MappingDiff`][MappingDiff] that reports added, removed and conflicting fields.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check whether the mapping for a [`DocumentType`][documents-mod] called `MyType` can be put on its index:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = SyncClientBuilder::new().build()?;
+    let diff = client.document::<MyType>()
+                     .check_mapping()
+                     .send()?;
+
+    if diff.is_compatible() {
+        client.document::<MyType>().put_mapping().send()?;
+    }
+    # Ok(())
+    # }
+    ```
+
+    [CheckMappingRequestBuilder]: requests/document_check_mapping/type.CheckMappingRequestBuilder.html
+    [builder-methods]: requests/document_check_mapping/type.CheckMappingRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_check_mapping/type.CheckMappingRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_check_mapping/type.CheckMappingRequestBuilder.html#send-asynchronously
+    [MappingDiff]: responses/struct.MappingDiff.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn check_mapping(self) -> CheckMappingRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DocumentType + StaticIndex,
+    {
+        RequestBuilder::initial(
+            self.inner,
+            CheckMappingRequestInner {
+                index: TDocument::static_index(),
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<TDocument> CheckMappingRequestInner<TDocument>
+where
+    TDocument: DocumentType,
+{
+    fn to_request(&self) -> IndicesGetRequest<'static> {
+        IndicesGetRequest::for_index(self.index.clone())
+    }
+
+    fn into_diff(self, res: IndexMetadataResponse) -> Result<MappingDiff, Error> {
+        let expected = IndexMapping::from_document::<TDocument>().map_err(error::request)?;
+        let IndexMetadataResponse { mappings, aliases } = res;
+
+        let live = match mappings.index(&self.index) {
+            Some(live) => Some(live),
+            None => {
+                let mut indices = mappings.iter();
+
+                match (indices.next(), indices.next()) {
+                    // The index is an alias or wildcard that matched a single index
+                    (Some((_, live)), None) => Some(live),
+                    // The index is an alias over several indices so use its write index
+                    (Some(_), Some(_)) => self
+                        .write_index(&aliases)
+                        .and_then(|index| mappings.index(index)),
+                    _ => None,
+                }
+            }
+        };
+
+        let live = live.ok_or_else(|| {
+            error::request(error::message(format!(
+                "the mapping for index `{}` couldn't be resolved to a single index",
+                &*self.index
+            )))
+        })?;
+
+        Ok(live.diff(&expected))
+    }
+
+    fn write_index<'a>(&'a self, aliases: &'a IndicesGetAliasResponse) -> Option<&'a str> {
+        let mut indices = aliases.indices_for_alias(&self.index);
+
+        match (indices.next(), indices.next()) {
+            (Some(index), None) => Some(index),
+            _ => aliases
+                .iter()
+                .filter(|(_, index_aliases)| {
+                    index_aliases
+                        .get(&self.index)
+                        .and_then(|alias| alias.is_write_index())
+                        .unwrap_or(false)
+                })
+                .map(|(index, _)| index)
+                .next(),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `CheckMappingRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> CheckMappingRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /** Set the index to check the mapping for. */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = index.into();
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> CheckMappingRequestBuilder<SyncSender, TDocument>
+where
+    TDocument: DocumentType,
+{
+    /**
+    Send a `CheckMappingRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is compared with the document mapping.
+
+    # Examples
+
+    Refuse to start if the mapping for a document type called `MyType` conflicts with its index:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = SyncClientBuilder::new().build()?;
+    let diff = client.document::<MyType>()
+                     .check_mapping()
+                     .send()?;
+
+    assert!(!diff.requires_reindex());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<MappingDiff, Error> {
+        let req = self.inner.to_request();
+
+        let res = RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()?;
+
+        self.inner.into_diff(res)
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> CheckMappingRequestBuilder<AsyncSender, TDocument>
+where
+    TDocument: DocumentType + Send + 'static,
+{
+    /**
+    Send a `CheckMappingRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the differences between the live and document mappings.
+
+    # Examples
+
+    Refuse to start if the mapping for a document type called `MyType` conflicts with its index:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.document::<MyType>()
+                       .check_mapping()
+                       .send();
+
+    future.and_then(|diff| {
+        assert!(!diff.requires_reindex());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req = inner.to_request();
+
+        let res_future = RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+            .send()
+            .and_then(|res| res.into_response::<IndexMetadataResponse>())
+            .and_then(move |res| inner.into_diff(res));
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<MappingDiff>;
+
+#[cfg(test)]
+mod tests {
+    use super::IndexMetadataResponse;
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+    use serde_json;
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[derive(ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    #[allow(dead_code)]
+    struct TestDoc {
+        id: Keyword<DefaultKeywordMapping>,
+        title: Text<DefaultTextMapping>,
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .check_mapping()
+            .inner
+            .to_request();
+
+        assert_eq!("/testdoc", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .check_mapping()
+            .index("new-idx")
+            .inner
+            .to_request();
+
+        assert_eq!("/new-idx", req.url.as_ref());
+    }
+
+    #[test]
+    fn diff_live_mapping() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let res: IndexMetadataResponse = serde_json::from_value(json!({
+            "testdoc": {
+                "aliases": { },
+                "mappings": {
+                    "properties": {
+                        "id": { "type": "long" },
+                        "old": { "type": "keyword" }
+                    }
+                },
+                "settings": { }
+            }
+        }))
+        .unwrap();
+
+        let diff = client
+            .document::<TestDoc>()
+            .check_mapping()
+            .inner
+            .into_diff(res)
+            .unwrap();
+
+        let changes = diff
+            .iter()
+            .map(|change| change.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                "conflicting field `id`: expected type `keyword` but found `long`",
+                "removed field `old` of type `keyword`",
+                "added field `title` of type `text`",
+            ],
+            changes
+        );
+        assert!(diff.requires_reindex());
+    }
+
+    #[test]
+    fn diff_missing_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let res: IndexMetadataResponse = serde_json::from_value(json!({})).unwrap();
+
+        let diff = client
+            .document::<TestDoc>()
+            .check_mapping()
+            .inner
+            .into_diff(res);
+
+        assert!(diff.is_err());
+    }
+
+    #[test]
+    fn diff_aliased_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let res: IndexMetadataResponse = serde_json::from_value(json!({
+            "testdoc-v2": {
+                "aliases": {
+                    "testdoc": { }
+                },
+                "mappings": {
+                    "properties": {
+                        "id": { "type": "keyword" },
+                        "title": { "type": "text" }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let diff = client
+            .document::<TestDoc>()
+            .check_mapping()
+            .inner
+            .into_diff(res)
+            .unwrap();
+
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn diff_aliased_write_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let res: IndexMetadataResponse = serde_json::from_value(json!({
+            "testdoc-v1": {
+                "aliases": {
+                    "testdoc": { }
+                },
+                "mappings": {
+                    "properties": {
+                        "id": { "type": "long" }
+                    }
+                }
+            },
+            "testdoc-v2": {
+                "aliases": {
+                    "testdoc": { "is_write_index": true }
+                },
+                "mappings": {
+                    "properties": {
+                        "id": { "type": "keyword" },
+                        "title": { "type": "text" }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let diff = client
+            .document::<TestDoc>()
+            .check_mapping()
+            .inner
+            .into_diff(res)
+            .unwrap();
+
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn diff_ambiguous_alias() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let res: IndexMetadataResponse = serde_json::from_value(json!({
+            "testdoc-v1": { "aliases": { "testdoc": { } }, "mappings": { } },
+            "testdoc-v2": { "aliases": { "testdoc": { } }, "mappings": { } }
+        }))
+        .unwrap();
+
+        let diff = client
+            .document::<TestDoc>()
+            .check_mapping()
+            .inner
+            .into_diff(res);
+
+        assert!(diff.is_err());
+    }
+}
@@ -0,0 +1,444 @@
+/*!
+Builders for [create document requests][docs-create].
+
+[docs-create]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-index_.html#operation-type
+*/
+
+use futures::Future;
+use serde::Serialize;
+use serde_json;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndexResponse,
+        DocumentClient,
+    },
+    endpoints::CreateRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Id,
+        Index,
+        Routing,
+        Type,
+    },
+    types::document::{
+        DocumentType,
+        DEFAULT_DOC_TYPE,
+    },
+};
+
+/**
+A [create document request][docs-create] builder that can be configured before sending.
+
+Call [`Client.document.create`][Client.document.create] to get a `CreateRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-create]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-index_.html#operation-type
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document.create]: ../../struct.DocumentClient.html#create-document-request
+*/
+pub type CreateRequestBuilder<TSender, TDocument> =
+    RequestBuilder<TSender, CreateRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct CreateRequestInner<TDocument> {
+    index: Index<'static>,
+    ty: Type<'static>,
+    id: Option<Id<'static>>,
+    doc: TDocument,
+}
+
+/**
+# Create document request
+*/
+impl<TSender, TDocument> DocumentClient<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`CreateRequestBuilder`][CreateRequestBuilder] with this `Client` that can be configured before sending.
+
+    Unlike an [index request][Client.document.index], a create request won't overwrite a document that already exists.
+    If there's already a document with the same id then the request will fail with an [`ApiError::DocumentAlreadyExists`][ApiError::DocumentAlreadyExists].
+    The document must have an id, either from its [`DocumentType`][documents-mod] or set using the [builder methods][builder-methods].
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Create a [`DocumentType`][documents-mod] called `MyType` with an id of `1` if it doesn't already exist:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::{prelude::*, Error, error::ApiError};
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    #[derive(Serialize, Deserialize, ElasticType)]
+    struct MyType {
+        #[elastic(id)]
+        pub id: String,
+        pub title: String,
+    }
+
+    let doc = MyType {
+        id: "1".to_owned(),
+        title: "A title".to_owned(),
+    };
+
+    match client.document().create(doc).send() {
+        Ok(response) => assert!(response.created()),
+        Err(Error::Api(ApiError::DocumentAlreadyExists { .. })) => println!("already created"),
+        Err(e) => Err(e)?,
+    }
+    # Ok(())
+    # }
+    ```
+
+    [CreateRequestBuilder]: requests/document_create/type.CreateRequestBuilder.html
+    [builder-methods]: requests/document_create/type.CreateRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_create/type.CreateRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_create/type.CreateRequestBuilder.html#send-asynchronously
+    [Client.document.index]: #index-document-request
+    [ApiError::DocumentAlreadyExists]: ../error/enum.ApiError.html#variant.DocumentAlreadyExists
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn create(self, doc: TDocument) -> CreateRequestBuilder<TSender, TDocument>
+    where
+        TDocument: Serialize + DocumentType,
+    {
+        let routing = doc.partial_routing().map(|routing| routing.into_owned());
+
+        let builder = RequestBuilder::initial(
+            self.inner,
+            CreateRequestInner {
+                index: doc.index().to_owned(),
+                ty: doc.ty().to_owned(),
+                id: doc.partial_id().map(|id| id.to_owned()),
+                doc,
+            },
+        );
+
+        match routing {
+            Some(routing) => builder.routing(routing),
+            None => builder,
+        }
+    }
+
+    /**
+    Create a [`CreateRequestBuilder`][CreateRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Create a document as a `serde_json::Value` with an id of `123` if it doesn't already exist:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let doc = json!({
+        "title": "A document"
+    });
+
+    let response = client.document()
+                         .create_raw("myindex", 123, doc)
+                         .send()?;
+
+    assert!(response.created());
+    # Ok(())
+    # }
+    ```
+
+    [CreateRequestBuilder]: requests/document_create/type.CreateRequestBuilder.html
+    [builder-methods]: requests/document_create/type.CreateRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_create/type.CreateRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_create/type.CreateRequestBuilder.html#send-asynchronously
+    */
+    pub fn create_raw(
+        self,
+        index: impl Into<Index<'static>>,
+        id: impl Into<Id<'static>>,
+        doc: TDocument,
+    ) -> CreateRequestBuilder<TSender, TDocument>
+    where
+        TDocument: Serialize,
+    {
+        RequestBuilder::initial(
+            self.inner,
+            CreateRequestInner {
+                index: index.into(),
+                ty: DEFAULT_DOC_TYPE.into(),
+                id: Some(id.into()),
+                doc,
+            },
+        )
+    }
+}
+
+impl<TDocument> CreateRequestInner<TDocument>
+where
+    TDocument: Serialize,
+{
+    fn into_request(self) -> Result<CreateRequest<'static, Vec<u8>>, Error> {
+        let id = self.id.ok_or_else(|| {
+            error::request(error::message("a document must have an id to be created"))
+        })?;
+
+        let body = serde_json::to_vec(&self.doc).map_err(error::request)?;
+
+        Ok(CreateRequest::for_index_ty_id(
+            self.index, self.ty, id, body,
+        ))
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `CreateRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> CreateRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /** Set the index for the create request. */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = index.into();
+        self
+    }
+
+    /** Set the type for the create request. */
+    pub fn ty(mut self, ty: impl Into<Type<'static>>) -> Self {
+        self.inner.ty = ty.into();
+        self
+    }
+
+    /**
+    Set the routing value for the create request.
+
+    Documents that declare a routing value using `#[elastic(routing)]` will use it by default.
+    */
+    pub fn routing(self, routing: impl Into<Routing<'static>>) -> Self {
+        let routing = routing.into().to_string();
+
+        self.params_fluent(move |params| params.url_param("routing", &routing))
+    }
+
+    /** Set the id for the create request. */
+    pub fn id(mut self, id: impl Into<Id<'static>>) -> Self {
+        self.inner.id = Some(id.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> CreateRequestBuilder<SyncSender, TDocument>
+where
+    TDocument: Serialize,
+{
+    /**
+    Send a `CreateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Create a document with an id of `1`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     #[elastic(id)]
+    #     pub id: String,
+    #     pub title: String,
+    # }
+    # let client = SyncClientBuilder::new().build()?;
+    let doc = MyType {
+        id: "1".to_owned(),
+        title: String::from("A title"),
+    };
+
+    let response = client.document()
+                         .create(doc)
+                         .send()?;
+
+    assert!(response.created());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndexResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> CreateRequestBuilder<AsyncSender, TDocument>
+where
+    TDocument: Serialize + Send + 'static,
+{
+    /**
+    Send a `CreateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised index response.
+
+    # Examples
+
+    Create a document with an id of `1`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     #[elastic(id)]
+    #     pub id: String,
+    #     pub title: String,
+    # }
+    # let client = AsyncClientBuilder::new().build()?;
+    let doc = MyType {
+        id: "1".to_owned(),
+        title: String::from("A title"),
+    };
+
+    let future = client.document()
+                       .create(doc)
+                       .send();
+
+    future.and_then(|response| {
+        assert!(response.created());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndexResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    struct TestDoc {
+        #[elastic(id)]
+        id: String,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    struct NoIdDoc {}
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .create(TestDoc { id: "1".to_owned() })
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/testdoc/_doc/1/_create", req.url.as_ref());
+        assert_eq!(br#"{"id":"1"}"#.to_vec(), req.body);
+    }
+
+    #[test]
+    fn specify_id() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<NoIdDoc>()
+            .create(NoIdDoc {})
+            .id(1)
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/noiddoc/_doc/1/_create", req.url.as_ref());
+    }
+
+    #[test]
+    fn missing_id() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<NoIdDoc>()
+            .create(NoIdDoc {})
+            .inner
+            .into_request();
+
+        assert!(req.is_err());
+    }
+}
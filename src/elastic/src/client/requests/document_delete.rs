@@ -10,6 +10,7 @@ use std::marker::PhantomData;
 use crate::{
     client::{
         requests::{
+            common::VersionType,
             raw::RawRequestInner,
             Pending as BasePending,
             RequestBuilder,
@@ -27,6 +28,7 @@ use crate::{
     params::{
         Id,
         Index,
+        Routing,
         Type,
     },
     types::document::{
@@ -206,6 +208,46 @@ where
         self.inner.ty = ty.into();
         self
     }
+
+    /**
+    Set the routing value for the delete request.
+
+    This must be the same value the document was indexed with.
+    */
+    pub fn routing(self, routing: impl Into<Routing<'static>>) -> Self {
+        let routing = routing.into().to_string();
+
+        self.params_fluent(move |params| params.url_param("routing", &routing))
+    }
+
+    /**
+    Only delete the document if its sequence number and primary term match the given ones.
+
+    This prevents deleting a document that was changed by another operation after it was read.
+    A mismatch fails the request with an [`ApiError::VersionConflict`][ApiError::VersionConflict].
+
+    [ApiError::VersionConflict]: ../../../error/enum.ApiError.html#variant.VersionConflict
+    */
+    pub fn if_seq_no_primary_term(self, seq_no: u64, primary_term: u64) -> Self {
+        self.params_fluent(move |params| {
+            params
+                .url_param("if_seq_no", seq_no)
+                .url_param("if_primary_term", primary_term)
+        })
+    }
+
+    /**
+    Only delete the document if the given externally maintained version is newer than the current one.
+
+    The version of the delete is kept for a short time, so an index request with an older version that arrives afterwards will also fail.
+    */
+    pub fn version(self, version: u64, version_type: VersionType) -> Self {
+        self.params_fluent(move |params| {
+            params
+                .url_param("version", version)
+                .url_param("version_type", version_type.as_str())
+        })
+    }
 }
 
 /**
@@ -366,4 +408,22 @@ mod tests {
 
         assert_eq!("/testdoc/new-ty/1", req.url.as_ref());
     }
+
+    #[test]
+    fn specify_external_version() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .document::<TestDoc>()
+            .delete("1")
+            .version(3, VersionType::External)
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("version=3"));
+        assert!(qry.contains("version_type=external"));
+    }
 }
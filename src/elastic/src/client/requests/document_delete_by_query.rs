@@ -0,0 +1,498 @@
+/*!
+Builders for [delete by query requests][docs-delete-by-query].
+
+[docs-delete-by-query]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html
+*/
+
+use futures::Future;
+use serde::de::DeserializeOwned;
+use serde_json::{
+    self,
+    Value,
+};
+use std::marker::PhantomData;
+
+use crate::{
+    client::{
+        requests::{
+            common::{
+                Conflicts,
+                RequestsPerSecond,
+                Slices,
+            },
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::{
+            BulkByScrollResponse,
+            TaskResponse,
+        },
+        DocumentClient,
+    },
+    endpoints::DeleteByQueryRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::{
+        receiver::IsOk,
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+    },
+    params::{
+        Index,
+        Routing,
+    },
+    types::document::{
+        DocumentType,
+        StaticIndex,
+    },
+};
+
+/**
+A [delete by query request][docs-delete-by-query] builder that can be configured before sending.
+
+Call [`Client.document.delete_by_query`][Client.document.delete_by_query] to get a `DeleteByQueryRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+By default, sending the request waits for all matching documents to be deleted and returns a [`BulkByScrollResponse`][BulkByScrollResponse].
+Call [`as_task`](#method.as_task) to return a handle to a background task instead.
+
+[docs-delete-by-query]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document.delete_by_query]: ../../struct.DocumentClient.html#delete-by-query-request
+[BulkByScrollResponse]: ../../responses/struct.BulkByScrollResponse.html
+*/
+pub type DeleteByQueryRequestBuilder<TSender, TResponse = BulkByScrollResponse> =
+    RequestBuilder<TSender, DeleteByQueryRequestInner<TResponse>>;
+
+#[doc(hidden)]
+pub struct DeleteByQueryRequestInner<TResponse> {
+    index: Index<'static>,
+    query: Value,
+    _marker: PhantomData<TResponse>,
+}
+
+/**
+# Delete by query request
+*/
+impl<TSender, TDocument> DocumentClient<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`DeleteByQueryRequestBuilder`][DeleteByQueryRequestBuilder] with this `Client` that can be configured before sending.
+
+    Every document in the index that matches the query will be deleted.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Delete all [`DocumentType`][documents-mod]s called `MyType` that have a `status` of `archived`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # #[macro_use] extern crate serde_json;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<MyType>()
+                         .delete_by_query(json!({
+                             "term": {
+                                 "status": "archived"
+                             }
+                         }))
+                         .conflicts(Conflicts::Proceed)
+                         .send()?;
+
+    println!("deleted {} documents", response.deleted());
+    # Ok(())
+    # }
+    ```
+
+    [DeleteByQueryRequestBuilder]: requests/document_delete_by_query/type.DeleteByQueryRequestBuilder.html
+    [builder-methods]: requests/document_delete_by_query/type.DeleteByQueryRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_delete_by_query/type.DeleteByQueryRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_delete_by_query/type.DeleteByQueryRequestBuilder.html#send-asynchronously
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn delete_by_query(self, query: impl Into<Value>) -> DeleteByQueryRequestBuilder<TSender>
+    where
+        TDocument: DocumentType + StaticIndex,
+    {
+        RequestBuilder::initial(
+            self.inner,
+            DeleteByQueryRequestInner::new(TDocument::static_index(), query.into()),
+        )
+    }
+
+    /**
+    Create a [`DeleteByQueryRequestBuilder`][DeleteByQueryRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Delete every document in the `myindex` index that hasn't been seen for a year:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<Value>()
+                         .delete_by_query_raw("myindex", json!({
+                             "range": {
+                                 "last_seen": {
+                                     "lt": "now-1y"
+                                 }
+                             }
+                         }))
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [DeleteByQueryRequestBuilder]: requests/document_delete_by_query/type.DeleteByQueryRequestBuilder.html
+    [builder-methods]: requests/document_delete_by_query/type.DeleteByQueryRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_delete_by_query/type.DeleteByQueryRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_delete_by_query/type.DeleteByQueryRequestBuilder.html#send-asynchronously
+    */
+    pub fn delete_by_query_raw(
+        self,
+        index: impl Into<Index<'static>>,
+        query: impl Into<Value>,
+    ) -> DeleteByQueryRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            DeleteByQueryRequestInner::new(index.into(), query.into()),
+        )
+    }
+}
+
+impl DeleteByQueryRequestInner<BulkByScrollResponse> {
+    fn new(index: Index<'static>, query: Value) -> Self {
+        DeleteByQueryRequestInner {
+            index,
+            query,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<TResponse> DeleteByQueryRequestInner<TResponse> {
+    fn into_request(self) -> Result<DeleteByQueryRequest<'static, Vec<u8>>, Error> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            query: &'a Value,
+        }
+
+        let body = serde_json::to_vec(&Body { query: &self.query }).map_err(error::request)?;
+
+        Ok(DeleteByQueryRequest::for_index(self.index, body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `DeleteByQueryRequestBuilder` before sending it.
+*/
+impl<TSender, TResponse> DeleteByQueryRequestBuilder<TSender, TResponse>
+where
+    TSender: Sender,
+{
+    /**
+    Set the index for the delete by query request.
+
+    The index can be a comma-separated list of indices or contain wildcards.
+    */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = index.into();
+        self
+    }
+
+    /** Only delete matching documents on the shards for the given routing value. */
+    pub fn routing(self, routing: impl Into<Routing<'static>>) -> Self {
+        let routing = routing.into().to_string();
+
+        self.params_fluent(move |params| params.url_param("routing", &routing))
+    }
+
+    /**
+    Set what to do when a matching document changes while the request is running.
+
+    By default the request is aborted at the first version conflict.
+    */
+    pub fn conflicts(self, conflicts: Conflicts) -> Self {
+        self.params_fluent(move |params| params.url_param("conflicts", conflicts.as_str()))
+    }
+
+    /** Split the request into slices that are processed in parallel. */
+    pub fn slices(self, slices: impl Into<Slices>) -> Self {
+        let slices = slices.into();
+
+        self.params_fluent(move |params| params.url_param("slices", slices))
+    }
+
+    /** Throttle the request so that it doesn't overwhelm the cluster. */
+    pub fn requests_per_second(self, requests_per_second: impl Into<RequestsPerSecond>) -> Self {
+        let requests_per_second = requests_per_second.into();
+
+        self.params_fluent(move |params| {
+            params.url_param("requests_per_second", requests_per_second)
+        })
+    }
+
+    /** Refresh the affected shards once the request has finished so the deletes are visible to search. */
+    pub fn refresh(self) -> Self {
+        self.params_fluent(|params| params.url_param("refresh", true))
+    }
+
+    /**
+    Run the request as a background task instead of waiting for it to finish.
+
+    Sending the request will return a [`TaskResponse`][TaskResponse] as soon as the task has started.
+    The task id can then be used to track the progress of the request using the tasks API.
+
+    # Examples
+
+    Start deleting all documents in the `myindex` index:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<Value>()
+                         .delete_by_query_raw("myindex", json!({ "match_all": {} }))
+                         .slices(Slices::Auto)
+                         .as_task()
+                         .send()?;
+
+    println!("started task {}", response.task());
+    # Ok(())
+    # }
+    ```
+
+    [TaskResponse]: ../../responses/struct.TaskResponse.html
+    */
+    pub fn as_task(self) -> DeleteByQueryRequestBuilder<TSender, TaskResponse> {
+        let builder = RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            DeleteByQueryRequestInner {
+                index: self.inner.index,
+                query: self.inner.query,
+                _marker: PhantomData,
+            },
+        );
+
+        builder.params_fluent(|params| params.url_param("wait_for_completion", false))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TResponse> DeleteByQueryRequestBuilder<SyncSender, TResponse>
+where
+    TResponse: IsOk + DeserializeOwned,
+{
+    /**
+    Send a `DeleteByQueryRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Delete all documents in the `myindex` index that belong to a user:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<Value>()
+                         .delete_by_query_raw("myindex", json!({
+                             "term": {
+                                 "user": "kimchy"
+                             }
+                         }))
+                         .send()?;
+
+    for failure in response.failures() {
+        println!("{:?}", failure.reason());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<TResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TResponse> DeleteByQueryRequestBuilder<AsyncSender, TResponse>
+where
+    TResponse: IsOk + DeserializeOwned + Send + 'static,
+{
+    /**
+    Send a `DeleteByQueryRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised delete by query response.
+
+    # Examples
+
+    Delete all documents in the `myindex` index that belong to a user:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.document::<Value>()
+                       .delete_by_query_raw("myindex", json!({
+                           "term": {
+                               "user": "kimchy"
+                           }
+                       }))
+                       .send();
+
+    future.and_then(|response| {
+        println!("deleted {} documents", response.deleted());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<TResponse> {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending<TResponse = BulkByScrollResponse> = BasePending<TResponse>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+        assert_send::<super::Pending<TaskResponse>>();
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    struct TestDoc {}
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .delete_by_query(json!({ "match_all": {} }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/testdoc/_delete_by_query", req.url.as_ref());
+        assert_eq!(json!({ "query": { "match_all": {} } }), actual_body);
+    }
+
+    #[test]
+    fn specify_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<Value>()
+            .delete_by_query_raw("idx-1", json!({ "match_all": {} }))
+            .index("idx-2")
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/idx-2/_delete_by_query", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_params() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .document::<TestDoc>()
+            .delete_by_query(json!({ "match_all": {} }))
+            .conflicts(Conflicts::Proceed)
+            .slices(Slices::Auto)
+            .requests_per_second(500.0)
+            .as_task()
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("conflicts=proceed"));
+        assert!(qry.contains("slices=auto"));
+        assert!(qry.contains("requests_per_second=500"));
+        assert!(qry.contains("wait_for_completion=false"));
+    }
+}
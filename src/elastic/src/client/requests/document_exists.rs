@@ -0,0 +1,399 @@
+/*!
+Builders for [document exists requests][docs-exists].
+
+[docs-exists]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html
+*/
+
+use futures::Future;
+use std::marker::PhantomData;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        DocumentClient,
+    },
+    endpoints::{
+        Endpoint,
+        ExistsRequest,
+        ExistsSourceRequest,
+    },
+    error::Error,
+    http::{
+        receiver::{
+            HttpResponseHead,
+            IsOk,
+            MaybeOkResponse,
+            ParseError,
+            ResponseBody,
+            Unbuffered,
+        },
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+        DefaultBody,
+        StatusCode,
+    },
+    params::{
+        Id,
+        Index,
+        Routing,
+        Type,
+    },
+    types::document::{
+        DocumentType,
+        StaticIndex,
+        StaticType,
+        DEFAULT_DOC_TYPE,
+    },
+};
+
+/**
+A [document exists request][docs-exists] builder that can be configured before sending.
+
+Call [`Client.document.exists`][Client.document.exists] to get an `ExistsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-exists]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document.exists]: ../../struct.DocumentClient.html#document-exists-request
+*/
+pub type ExistsRequestBuilder<TSender, TDocument> =
+    RequestBuilder<TSender, ExistsRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct ExistsRequestInner<TDocument> {
+    index: Index<'static>,
+    ty: Type<'static>,
+    id: Id<'static>,
+    source: bool,
+    _marker: PhantomData<TDocument>,
+}
+
+/**
+# Document exists request
+*/
+impl<TSender, TDocument> DocumentClient<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`ExistsRequestBuilder`][ExistsRequestBuilder] with this `Client` that can be configured before sending.
+
+    The document itself isn't returned, so this is cheaper than a [get request][Client.document.get] when only its presence matters.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check whether a [`DocumentType`][documents-mod] called `MyType` with an id of `1` exists:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let exists = client.document::<MyType>()
+                       .exists(1)
+                       .send()?;
+
+    if !exists {
+        println!("document 1 is missing");
+    }
+    # Ok(())
+    # }
+    ```
+
+    [ExistsRequestBuilder]: requests/document_exists/type.ExistsRequestBuilder.html
+    [builder-methods]: requests/document_exists/type.ExistsRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_exists/type.ExistsRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_exists/type.ExistsRequestBuilder.html#send-asynchronously
+    [Client.document.get]: #get-document-request
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn exists(self, id: impl Into<Id<'static>>) -> ExistsRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DocumentType + StaticIndex + StaticType,
+    {
+        RequestBuilder::initial(
+            self.inner,
+            ExistsRequestInner {
+                index: TDocument::static_index(),
+                ty: TDocument::static_ty(),
+                id: id.into(),
+                source: false,
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    /**
+    Create an [`ExistsRequestBuilder`][ExistsRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check whether a document with an id of `1` exists in the `myindex` index:
+
+    ```no_run
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let exists = client.document::<Value>()
+                       .exists_raw("myindex", 1)
+                       .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [ExistsRequestBuilder]: requests/document_exists/type.ExistsRequestBuilder.html
+    [builder-methods]: requests/document_exists/type.ExistsRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_exists/type.ExistsRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_exists/type.ExistsRequestBuilder.html#send-asynchronously
+    */
+    pub fn exists_raw(
+        self,
+        index: impl Into<Index<'static>>,
+        id: impl Into<Id<'static>>,
+    ) -> ExistsRequestBuilder<TSender, TDocument> {
+        RequestBuilder::initial(
+            self.inner,
+            ExistsRequestInner {
+                index: index.into(),
+                ty: DEFAULT_DOC_TYPE.into(),
+                id: id.into(),
+                source: false,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<TDocument> ExistsRequestInner<TDocument> {
+    fn into_request(self) -> Endpoint<'static, DefaultBody> {
+        if self.source {
+            ExistsSourceRequest::for_index_ty_id(self.index, self.ty, self.id).into()
+        } else {
+            ExistsRequest::for_index_ty_id(self.index, self.ty, self.id).into()
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `ExistsRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> ExistsRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /** Set the index for the exists request. */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = index.into();
+        self
+    }
+
+    /** Set the type for the exists request. */
+    pub fn ty(mut self, ty: impl Into<Type<'static>>) -> Self {
+        self.inner.ty = ty.into();
+        self
+    }
+
+    /** Set the routing value for the exists request. */
+    pub fn routing(self, routing: impl Into<Routing<'static>>) -> Self {
+        let routing = routing.into().to_string();
+
+        self.params_fluent(move |params| params.url_param("routing", &routing))
+    }
+
+    /**
+    Check whether the document's source exists instead of the document itself.
+
+    A document can exist without its source if the `_source` field is disabled in the mapping.
+    */
+    pub fn source(mut self) -> Self {
+        self.inner.source = true;
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> ExistsRequestBuilder<SyncSender, TDocument> {
+    /**
+    Send an `ExistsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives.
+
+    # Examples
+
+    Check whether a [`DocumentType`][documents-mod] called `MyType` with an id of `1` exists:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let exists = client.document::<MyType>()
+                       .exists(1)
+                       .send()?;
+
+    println!("{}", exists);
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn send(self) -> Result<bool, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response::<DocumentExists>()
+            .map(|res| res.0)
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> ExistsRequestBuilder<AsyncSender, TDocument> {
+    /**
+    Send an `ExistsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to whether or not the document exists.
+
+    # Examples
+
+    Check whether a [`DocumentType`][documents-mod] called `MyType` with an id of `1` exists:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.document::<MyType>()
+                       .exists(1)
+                       .send();
+
+    future.and_then(|exists| {
+        println!("{}", exists);
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response::<DocumentExists>())
+                .map(|res| res.0);
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<bool>;
+
+/** The status of a `HEAD` request, which doesn't have a body to deserialise. */
+#[derive(Deserialize)]
+struct DocumentExists(bool);
+
+impl IsOk for DocumentExists {
+    fn is_ok<B: ResponseBody>(
+        head: HttpResponseHead,
+        body: Unbuffered<B>,
+    ) -> Result<MaybeOkResponse<B>, ParseError> {
+        match head.status() {
+            status if status.is_success() => Ok(MaybeOkResponse::ok(json!(true))),
+            StatusCode::NOT_FOUND => Ok(MaybeOkResponse::ok(json!(false))),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        http::Method,
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[derive(ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    struct TestDoc {}
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .exists("1")
+            .inner
+            .into_request();
+
+        assert_eq!("/testdoc/_doc/1", req.url.as_ref());
+        assert_eq!(Method::HEAD, req.method);
+    }
+
+    #[test]
+    fn specify_source() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .exists("1")
+            .source()
+            .inner
+            .into_request();
+
+        assert_eq!("/testdoc/_doc/1/_source", req.url.as_ref());
+        assert_eq!(Method::HEAD, req.method);
+    }
+}
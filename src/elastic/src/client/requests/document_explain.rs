@@ -0,0 +1,409 @@
+/*!
+Builders for [explain requests][docs-explain].
+
+[docs-explain]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html
+*/
+
+use futures::Future;
+use serde_json::{
+    self,
+    Value,
+};
+use std::marker::PhantomData;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::ExplainResponse,
+        DocumentClient,
+    },
+    endpoints::ExplainRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Id,
+        Index,
+        Routing,
+        Type,
+    },
+    types::document::{
+        DocumentType,
+        StaticIndex,
+        StaticType,
+        DEFAULT_DOC_TYPE,
+    },
+};
+
+/**
+An [explain request][docs-explain] builder that can be configured before sending.
+
+Call [`Client.document.explain`][Client.document.explain] to get an `ExplainRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-explain]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document.explain]: ../../struct.DocumentClient.html#explain-request
+*/
+pub type ExplainRequestBuilder<TSender, TDocument> =
+    RequestBuilder<TSender, ExplainRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct ExplainRequestInner<TDocument> {
+    index: Index<'static>,
+    ty: Type<'static>,
+    id: Id<'static>,
+    query: Value,
+    _marker: PhantomData<TDocument>,
+}
+
+/**
+# Explain request
+*/
+impl<TSender, TDocument> DocumentClient<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`ExplainRequestBuilder`][ExplainRequestBuilder] with this `Client` that can be configured before sending.
+
+    The response explains how the score of the document was computed for the query, or why it didn't match.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Explain the score of a [`DocumentType`][documents-mod] called `MyType` with an id of `1`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # #[macro_use] extern crate serde_json;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<MyType>()
+                         .explain(1, json!({
+                             "match": {
+                                 "title": "rust"
+                             }
+                         }))
+                         .send()?;
+
+    if let Some(explanation) = response.explanation() {
+        println!("{}: {}", explanation.value(), explanation.description());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [ExplainRequestBuilder]: requests/document_explain/type.ExplainRequestBuilder.html
+    [builder-methods]: requests/document_explain/type.ExplainRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_explain/type.ExplainRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_explain/type.ExplainRequestBuilder.html#send-asynchronously
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn explain(
+        self,
+        id: impl Into<Id<'static>>,
+        query: impl Into<Value>,
+    ) -> ExplainRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DocumentType + StaticIndex + StaticType,
+    {
+        RequestBuilder::initial(
+            self.inner,
+            ExplainRequestInner {
+                index: TDocument::static_index(),
+                ty: TDocument::static_ty(),
+                id: id.into(),
+                query: query.into(),
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    /**
+    Create an [`ExplainRequestBuilder`][ExplainRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Explain the score of a document with an id of `1` in the `myindex` index:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<Value>()
+                         .explain_raw("myindex", 1, json!({
+                             "match": {
+                                 "title": "rust"
+                             }
+                         }))
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [ExplainRequestBuilder]: requests/document_explain/type.ExplainRequestBuilder.html
+    [builder-methods]: requests/document_explain/type.ExplainRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_explain/type.ExplainRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_explain/type.ExplainRequestBuilder.html#send-asynchronously
+    */
+    pub fn explain_raw(
+        self,
+        index: impl Into<Index<'static>>,
+        id: impl Into<Id<'static>>,
+        query: impl Into<Value>,
+    ) -> ExplainRequestBuilder<TSender, TDocument> {
+        RequestBuilder::initial(
+            self.inner,
+            ExplainRequestInner {
+                index: index.into(),
+                ty: DEFAULT_DOC_TYPE.into(),
+                id: id.into(),
+                query: query.into(),
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<TDocument> ExplainRequestInner<TDocument> {
+    fn into_request(self) -> Result<ExplainRequest<'static, Vec<u8>>, Error> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            query: &'a Value,
+        }
+
+        let body = serde_json::to_vec(&Body { query: &self.query }).map_err(error::request)?;
+
+        Ok(ExplainRequest::for_index_ty_id(
+            self.index, self.ty, self.id, body,
+        ))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `ExplainRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> ExplainRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /** Set the index for the explain request. */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = index.into();
+        self
+    }
+
+    /** Set the type for the explain request. */
+    pub fn ty(mut self, ty: impl Into<Type<'static>>) -> Self {
+        self.inner.ty = ty.into();
+        self
+    }
+
+    /** Set the routing value for the explain request. */
+    pub fn routing(self, routing: impl Into<Routing<'static>>) -> Self {
+        let routing = routing.into().to_string();
+
+        self.params_fluent(move |params| params.url_param("routing", &routing))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> ExplainRequestBuilder<SyncSender, TDocument> {
+    /**
+    Send an `ExplainRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Print each step of the explanation for a [`DocumentType`][documents-mod] called `MyType` with an id of `1`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # #[macro_use] extern crate serde_json;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    fn print(explanation: &Explanation, depth: usize) {
+        println!("{:indent$}{}: {}", "", explanation.value(), explanation.description(), indent = depth * 2);
+
+        for detail in explanation.details() {
+            print(detail, depth + 1);
+        }
+    }
+
+    let response = client.document::<MyType>()
+                         .explain(1, json!({ "match": { "title": "rust" } }))
+                         .send()?;
+
+    if let Some(explanation) = response.explanation() {
+        print(explanation, 0);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn send(self) -> Result<ExplainResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> ExplainRequestBuilder<AsyncSender, TDocument>
+where
+    TDocument: Send + 'static,
+{
+    /**
+    Send an `ExplainRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised explain response.
+
+    # Examples
+
+    Check whether a [`DocumentType`][documents-mod] called `MyType` with an id of `1` matches a query:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # #[macro_use] extern crate serde_json;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.document::<MyType>()
+                       .explain(1, json!({ "match": { "title": "rust" } }))
+                       .send();
+
+    future.and_then(|response| {
+        println!("matched: {}", response.matched());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<ExplainResponse>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[derive(ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    struct TestDoc {}
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .explain("1", json!({ "match_all": {} }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "query": {
+                "match_all": {}
+            }
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/testdoc/_doc/1/_explain", req.url.as_ref());
+        assert_eq!(expected_body.to_string(), actual_body.to_string());
+    }
+
+    #[test]
+    fn specify_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<Value>()
+            .explain_raw("myindex", "1", json!({ "match_all": {} }))
+            .index("new-idx")
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/new-idx/_doc/1/_explain", req.url.as_ref());
+    }
+}
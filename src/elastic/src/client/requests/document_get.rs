@@ -11,6 +11,7 @@ use std::marker::PhantomData;
 use crate::{
     client::{
         requests::{
+            common::field_list,
             raw::RawRequestInner,
             Pending as BasePending,
             RequestBuilder,
@@ -28,6 +29,7 @@ use crate::{
     params::{
         Id,
         Index,
+        Routing,
         Type,
     },
     types::document::{
@@ -205,6 +207,122 @@ where
         self.inner.ty = ty.into();
         self
     }
+
+    /**
+    Set the routing value for the get request.
+
+    Documents that were indexed with a routing value can only be found using the same value.
+    */
+    pub fn routing(self, routing: impl Into<Routing<'static>>) -> Self {
+        let routing = routing.into().to_string();
+
+        self.params_fluent(move |params| params.url_param("routing", &routing))
+    }
+
+    /**
+    Set whether or not to return the `_source` of the document.
+
+    Disabling the source is useful when only stored fields are needed.
+    */
+    pub fn source(self, source: bool) -> Self {
+        self.params_fluent(move |params| params.url_param("_source", source))
+    }
+
+    /**
+    Only return the given fields of the `_source` document.
+
+    Fields can contain wildcards, like `address.*`.
+    */
+    pub fn source_includes<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let fields = field_list(fields);
+
+        self.params_fluent(move |params| params.url_param("_source_includes", &fields))
+    }
+
+    /**
+    Don't return the given fields of the `_source` document.
+
+    Fields can contain wildcards, like `address.*`.
+    */
+    pub fn source_excludes<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let fields = field_list(fields);
+
+        self.params_fluent(move |params| params.url_param("_source_excludes", &fields))
+    }
+
+    /**
+    Return the given stored fields for the document.
+
+    Only fields that are mapped with `store` will be returned.
+    They're available on the response through [`fields`][GetResponse.fields].
+
+    [GetResponse.fields]: ../../responses/struct.GetResponse.html#method.fields
+    */
+    pub fn stored_fields<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let fields = field_list(fields);
+
+        self.params_fluent(move |params| params.url_param("stored_fields", &fields))
+    }
+
+    /**
+    Deserialise the `_source` of the document into a different type.
+
+    This is useful along with [`source_includes`](#method.source_includes) to only fetch part of a large document.
+
+    # Examples
+
+    Only get the title of a `MyType` document:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType { title: String, body: String }
+    #[derive(Debug, Deserialize)]
+    struct Summary {
+        title: String,
+    }
+
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<MyType>()
+                         .get(1)
+                         .source_includes(vec!["title"])
+                         .project::<Summary>()
+                         .send()?;
+
+    if let Some(summary) = response.into_document() {
+        println!("{}", summary.title);
+    }
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn project<TProjection>(self) -> GetRequestBuilder<TSender, TProjection> {
+        RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            GetRequestInner {
+                index: self.inner.index,
+                ty: self.inner.ty,
+                id: self.inner.id,
+                _marker: PhantomData,
+            },
+        )
+    }
 }
 
 /**
@@ -368,4 +486,58 @@ mod tests {
 
         assert_eq!("/testdoc/new-ty/1", req.url.as_ref());
     }
+
+    #[test]
+    fn specify_source_filtering() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .document::<TestDoc>()
+            .get("1")
+            .source_includes(vec!["title", "author.*"])
+            .source_excludes(vec!["author.email"])
+            .stored_fields(vec!["tags"])
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("_source_includes=title%2Cauthor.*"));
+        assert!(qry.contains("_source_excludes=author.email"));
+        assert!(qry.contains("stored_fields=tags"));
+    }
+
+    #[test]
+    fn specify_no_source() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .document::<TestDoc>()
+            .get("1")
+            .source(false)
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+
+        assert!(qry.unwrap().contains("_source=false"));
+    }
+
+    #[test]
+    fn specify_projection() {
+        #[derive(Deserialize)]
+        struct Summary {}
+
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .get("1")
+            .project::<Summary>()
+            .inner
+            .into_request();
+
+        assert_eq!("/testdoc/_doc/1", req.url.as_ref());
+    }
 }
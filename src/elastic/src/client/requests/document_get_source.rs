@@ -0,0 +1,434 @@
+/*!
+Builders for [get source requests][docs-get-source].
+
+[docs-get-source]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html#_source
+*/
+
+use futures::Future;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+use crate::{
+    client::{
+        requests::{
+            common::field_list,
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        DocumentClient,
+    },
+    endpoints::GetSourceRequest,
+    error::Error,
+    http::{
+        receiver::IsOkOnSuccess,
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+    },
+    params::{
+        Id,
+        Index,
+        Routing,
+        Type,
+    },
+    types::document::{
+        DocumentType,
+        StaticIndex,
+        StaticType,
+        DEFAULT_DOC_TYPE,
+    },
+};
+
+/**
+A [get source request][docs-get-source] builder that can be configured before sending.
+
+Call [`Client.document.get_source`][Client.document.get_source] to get a `GetSourceRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-get-source]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html#_source
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document.get_source]: ../../struct.DocumentClient.html#get-source-request
+*/
+pub type GetSourceRequestBuilder<TSender, TDocument> =
+    RequestBuilder<TSender, GetSourceRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct GetSourceRequestInner<TDocument> {
+    index: Index<'static>,
+    ty: Type<'static>,
+    id: Id<'static>,
+    _marker: PhantomData<TDocument>,
+}
+
+/**
+# Get source request
+*/
+impl<TSender, TDocument> DocumentClient<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`GetSourceRequestBuilder`][GetSourceRequestBuilder] with this `Client` that can be configured before sending.
+
+    Only the `_source` of the document is returned, without any metadata like its version.
+    If the document doesn't exist then an error is returned.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the source of a [`DocumentType`][documents-mod] called `MyType` with an id of `1`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let doc = client.document::<MyType>()
+                    .get_source(1)
+                    .send()?;
+
+    println!("{:?}", doc);
+    # Ok(())
+    # }
+    ```
+
+    [GetSourceRequestBuilder]: requests/document_get_source/type.GetSourceRequestBuilder.html
+    [builder-methods]: requests/document_get_source/type.GetSourceRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_get_source/type.GetSourceRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_get_source/type.GetSourceRequestBuilder.html#send-asynchronously
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn get_source(
+        self,
+        id: impl Into<Id<'static>>,
+    ) -> GetSourceRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DeserializeOwned + DocumentType + StaticIndex + StaticType,
+    {
+        RequestBuilder::initial(
+            self.inner,
+            GetSourceRequestInner {
+                index: TDocument::static_index(),
+                ty: TDocument::static_ty(),
+                id: id.into(),
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    /**
+    Create a [`GetSourceRequestBuilder`][GetSourceRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the source of a document as a `serde_json::Value`:
+
+    ```no_run
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let doc = client.document::<Value>()
+                    .get_source_raw("myindex", 1)
+                    .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [GetSourceRequestBuilder]: requests/document_get_source/type.GetSourceRequestBuilder.html
+    [builder-methods]: requests/document_get_source/type.GetSourceRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_get_source/type.GetSourceRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_get_source/type.GetSourceRequestBuilder.html#send-asynchronously
+    */
+    pub fn get_source_raw(
+        self,
+        index: impl Into<Index<'static>>,
+        id: impl Into<Id<'static>>,
+    ) -> GetSourceRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DeserializeOwned,
+    {
+        RequestBuilder::initial(
+            self.inner,
+            GetSourceRequestInner {
+                index: index.into(),
+                ty: DEFAULT_DOC_TYPE.into(),
+                id: id.into(),
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<TDocument> GetSourceRequestInner<TDocument> {
+    fn into_request(self) -> GetSourceRequest<'static> {
+        GetSourceRequest::for_index_ty_id(self.index, self.ty, self.id)
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `GetSourceRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> GetSourceRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /** Set the index for the get source request. */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = index.into();
+        self
+    }
+
+    /** Set the type for the get source request. */
+    pub fn ty(mut self, ty: impl Into<Type<'static>>) -> Self {
+        self.inner.ty = ty.into();
+        self
+    }
+
+    /** Set the routing value for the get source request. */
+    pub fn routing(self, routing: impl Into<Routing<'static>>) -> Self {
+        let routing = routing.into().to_string();
+
+        self.params_fluent(move |params| params.url_param("routing", &routing))
+    }
+
+    /**
+    Only return the given fields of the `_source` document.
+
+    Fields can contain wildcards, like `address.*`.
+    */
+    pub fn source_includes<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let fields = field_list(fields);
+
+        self.params_fluent(move |params| params.url_param("_source_includes", &fields))
+    }
+
+    /**
+    Don't return the given fields of the `_source` document.
+
+    Fields can contain wildcards, like `address.*`.
+    */
+    pub fn source_excludes<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let fields = field_list(fields);
+
+        self.params_fluent(move |params| params.url_param("_source_excludes", &fields))
+    }
+
+    /**
+    Deserialise the `_source` of the document into a different type.
+
+    This is useful along with [`source_includes`](#method.source_includes) to only fetch part of a large document.
+    */
+    pub fn project<TProjection>(self) -> GetSourceRequestBuilder<TSender, TProjection> {
+        RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            GetSourceRequestInner {
+                index: self.inner.index,
+                ty: self.inner.ty,
+                id: self.inner.id,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> GetSourceRequestBuilder<SyncSender, TDocument>
+where
+    TDocument: DeserializeOwned,
+{
+    /**
+    Send a `GetSourceRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the source of a [`DocumentType`][documents-mod] called `MyType` with an id of `1`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let doc = client.document::<MyType>()
+                    .get_source(1)
+                    .send()?;
+
+    println!("{:?}", doc);
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn send(self) -> Result<TDocument, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response::<DocumentSource<TDocument>>()
+            .map(|res| res.0)
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> GetSourceRequestBuilder<AsyncSender, TDocument>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+{
+    /**
+    Send a `GetSourceRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised source document.
+
+    # Examples
+
+    Get the source of a [`DocumentType`][documents-mod] called `MyType` with an id of `1`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.document::<MyType>()
+                       .get_source(1)
+                       .send();
+
+    future.and_then(|doc| {
+        println!("{:?}", doc);
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn send(self) -> Pending<TDocument> {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response::<DocumentSource<TDocument>>())
+                .map(|res| res.0);
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending<TDocument> = BasePending<TDocument>;
+
+/** The bare `_source` of a document, which doesn't have an envelope to deserialise. */
+#[derive(Deserialize)]
+struct DocumentSource<TDocument>(TDocument);
+
+impl<TDocument> IsOkOnSuccess for DocumentSource<TDocument> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending<TestDoc>>();
+    }
+
+    #[derive(Deserialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    struct TestDoc {}
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .get_source("1")
+            .inner
+            .into_request();
+
+        assert_eq!("/testdoc/_doc/1/_source", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .get_source("1")
+            .index("new-idx")
+            .inner
+            .into_request();
+
+        assert_eq!("/new-idx/_doc/1/_source", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_source_filtering() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .document::<TestDoc>()
+            .get_source("1")
+            .source_includes(vec!["title"])
+            .routing("user-1")
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("_source_includes=title"));
+        assert!(qry.contains("routing=user-1"));
+    }
+}
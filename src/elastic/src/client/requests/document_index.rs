@@ -11,6 +11,7 @@ use serde_json;
 use crate::{
     client::{
         requests::{
+            common::VersionType,
             raw::RawRequestInner,
             Pending as BasePending,
             RequestBuilder,
@@ -31,6 +32,7 @@ use crate::{
     params::{
         Id,
         Index,
+        Routing,
         Type,
     },
     types::document::{
@@ -123,7 +125,9 @@ where
     where
         TDocument: Serialize + DocumentType,
     {
-        RequestBuilder::initial(
+        let routing = doc.partial_routing().map(|routing| routing.into_owned());
+
+        let builder = RequestBuilder::initial(
             self.inner,
             IndexRequestInner {
                 index: doc.index().to_owned(),
@@ -131,7 +135,12 @@ where
                 id: doc.partial_id().map(|id| id.to_owned()),
                 doc,
             },
-        )
+        );
+
+        match routing {
+            Some(routing) => builder.routing(routing),
+            None => builder,
+        }
     }
 
     /**
@@ -236,11 +245,54 @@ where
         self
     }
 
+    /**
+    Set the routing value for the index request.
+
+    Documents that declare a routing value using `#[elastic(routing)]` will use it by default.
+    */
+    pub fn routing(self, routing: impl Into<Routing<'static>>) -> Self {
+        let routing = routing.into().to_string();
+
+        self.params_fluent(move |params| params.url_param("routing", &routing))
+    }
+
     /** Set the id for the index request. */
     pub fn id(mut self, id: impl Into<Id<'static>>) -> Self {
         self.inner.id = Some(id.into());
         self
     }
+
+    /**
+    Only index the document if it hasn't changed since it was read.
+
+    The sequence number and primary term are returned when a document is read or written, like [`GetResponse.sequence_number`][GetResponse.sequence_number].
+    If the document has changed since then the request will fail with an [`ApiError::VersionConflict`][ApiError::VersionConflict].
+
+    [GetResponse.sequence_number]: ../../responses/struct.GetResponse.html#method.sequence_number
+    [ApiError::VersionConflict]: ../../../error/enum.ApiError.html#variant.VersionConflict
+    */
+    pub fn if_seq_no_primary_term(self, seq_no: u64, primary_term: u64) -> Self {
+        self.params_fluent(move |params| {
+            params
+                .url_param("if_seq_no", seq_no)
+                .url_param("if_primary_term", primary_term)
+        })
+    }
+
+    /**
+    Only index the document if the given externally maintained version is newer than the current one.
+
+    If the document has a newer version the request will fail with an [`ApiError::VersionConflict`][ApiError::VersionConflict].
+
+    [ApiError::VersionConflict]: ../../../error/enum.ApiError.html#variant.VersionConflict
+    */
+    pub fn version(self, version: u64, version_type: VersionType) -> Self {
+        self.params_fluent(move |params| {
+            params
+                .url_param("version", version)
+                .url_param("version_type", version_type.as_str())
+        })
+    }
 }
 
 /**
@@ -381,6 +433,13 @@ mod tests {
     #[elastic(crate_root = "crate::types")]
     struct TestDoc {}
 
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    struct RoutedDoc {
+        #[elastic(routing)]
+        tenant: String,
+    }
+
     #[test]
     fn default_request() {
         let client = SyncClientBuilder::new().build().unwrap();
@@ -440,4 +499,59 @@ mod tests {
 
         assert_eq!("/testdoc/_doc/1", req.url.as_ref());
     }
+
+    #[test]
+    fn specify_if_seq_no_primary_term() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .document::<TestDoc>()
+            .index(TestDoc {})
+            .id(1)
+            .if_seq_no_primary_term(5, 1)
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("if_seq_no=5"));
+        assert!(qry.contains("if_primary_term=1"));
+    }
+
+    #[test]
+    fn specify_external_version() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .document::<TestDoc>()
+            .index(TestDoc {})
+            .id(1)
+            .version(3, VersionType::ExternalGte)
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("version=3"));
+        assert!(qry.contains("version_type=external_gte"));
+    }
+
+    #[test]
+    fn document_routing() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .document::<RoutedDoc>()
+            .index(RoutedDoc {
+                tenant: "tenant-1".to_owned(),
+            })
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+
+        assert!(qry.unwrap().contains("routing=tenant-1"));
+    }
 }
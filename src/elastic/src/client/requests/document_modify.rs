@@ -0,0 +1,494 @@
+/*!
+Builders for read-modify-write operations on documents using [optimistic concurrency control][docs-occ].
+
+[docs-occ]: https://www.elastic.co/guide/en/elasticsearch/reference/current/optimistic-concurrency-control.html
+*/
+
+use futures::{
+    future::{
+        self,
+        Either,
+        Loop,
+    },
+    Future,
+};
+use serde::{
+    de::DeserializeOwned,
+    ser::Serialize,
+};
+use std::marker::PhantomData;
+
+use crate::{
+    client::{
+        requests::Pending as BasePending,
+        responses::{
+            GetResponse,
+            IndexResponse,
+        },
+        Client,
+        DocumentClient,
+    },
+    error::{
+        self,
+        ApiError,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Id,
+        Index,
+        Routing,
+        Type,
+    },
+    types::document::{
+        DocumentType,
+        StaticIndex,
+        StaticType,
+        DEFAULT_DOC_TYPE,
+    },
+};
+
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/**
+A read-modify-write builder that can be configured before sending.
+
+Call [`Client.document.modify`][Client.document.modify] to get a `ModifyRequestBuilder`.
+The `send` method will either send the requests [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+The document is fetched with a [get request][docs-get], modified with a closure, and then written back with an [index request][docs-index] that only succeeds if the document hasn't changed in the meantime.
+If another operation changed the document first then the whole process is retried.
+
+[docs-get]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html
+[docs-index]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-index_.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document.modify]: ../../struct.DocumentClient.html#method.modify
+*/
+pub struct ModifyRequestBuilder<TSender, TDocument, TModify> {
+    client: Client<TSender>,
+    index: Index<'static>,
+    ty: Type<'static>,
+    id: Id<'static>,
+    routing: Option<Routing<'static>>,
+    max_retries: u32,
+    modify: TModify,
+    _marker: PhantomData<TDocument>,
+}
+
+/**
+# Modify document request
+*/
+impl<TSender, TDocument> DocumentClient<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`ModifyRequestBuilder`][ModifyRequestBuilder] with this `Client` that can be configured before sending.
+
+    The closure may be called more than once if the document is changed by another operation while it's being modified.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Increment the `likes` on a [`DocumentType`][documents-mod] called `MyType` with an id of `1` without losing concurrent updates:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: String,
+    #     pub likes: i64,
+    # }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<MyType>()
+                         .modify(1, |doc: &mut MyType| doc.likes += 1)
+                         .max_retries(5)
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [ModifyRequestBuilder]: requests/document_modify/struct.ModifyRequestBuilder.html
+    [builder-methods]: requests/document_modify/struct.ModifyRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_modify/struct.ModifyRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_modify/struct.ModifyRequestBuilder.html#send-asynchronously
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn modify<TModify>(
+        self,
+        id: impl Into<Id<'static>>,
+        modify: TModify,
+    ) -> ModifyRequestBuilder<TSender, TDocument, TModify>
+    where
+        TDocument: DeserializeOwned + Serialize + DocumentType + StaticIndex + StaticType,
+        TModify: FnMut(&mut TDocument),
+    {
+        ModifyRequestBuilder {
+            client: self.inner,
+            index: TDocument::static_index(),
+            ty: TDocument::static_ty(),
+            id: id.into(),
+            routing: None,
+            max_retries: DEFAULT_MAX_RETRIES,
+            modify,
+            _marker: PhantomData,
+        }
+    }
+
+    /**
+    Create a [`ModifyRequestBuilder`][ModifyRequestBuilder] for a document in the given index.
+
+    # Examples
+
+    Set a property on a document as a `serde_json::Value`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<Value>()
+                         .modify_raw("myindex", 1, |doc: &mut Value| doc["title"] = json!("New Title"))
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [ModifyRequestBuilder]: requests/document_modify/struct.ModifyRequestBuilder.html
+    */
+    pub fn modify_raw<TModify>(
+        self,
+        index: impl Into<Index<'static>>,
+        id: impl Into<Id<'static>>,
+        modify: TModify,
+    ) -> ModifyRequestBuilder<TSender, TDocument, TModify>
+    where
+        TDocument: DeserializeOwned + Serialize,
+        TModify: FnMut(&mut TDocument),
+    {
+        ModifyRequestBuilder {
+            client: self.inner,
+            index: index.into(),
+            ty: DEFAULT_DOC_TYPE.into(),
+            id: id.into(),
+            routing: None,
+            max_retries: DEFAULT_MAX_RETRIES,
+            modify,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `ModifyRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument, TModify> ModifyRequestBuilder<TSender, TDocument, TModify>
+where
+    TSender: Sender,
+{
+    /** Set the index for the document. */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.index = index.into();
+        self
+    }
+
+    /** Set the type for the document. */
+    pub fn ty(mut self, ty: impl Into<Type<'static>>) -> Self {
+        self.ty = ty.into();
+        self
+    }
+
+    /** Set the routing value for the document. */
+    pub fn routing(mut self, routing: impl Into<Routing<'static>>) -> Self {
+        self.routing = Some(routing.into());
+        self
+    }
+
+    /**
+    Set the number of times to retry when the document is changed by another operation.
+
+    The default is `3`.
+    If the document is still changing after the last retry then the [`ApiError::VersionConflict`][ApiError::VersionConflict] is returned.
+
+    [ApiError::VersionConflict]: ../../../error/enum.ApiError.html#variant.VersionConflict
+    */
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument, TModify> ModifyRequestBuilder<SyncSender, TDocument, TModify>
+where
+    TDocument: DeserializeOwned + Serialize,
+    TModify: FnMut(&mut TDocument),
+{
+    /**
+    Send a `ModifyRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until the modified document has been written.
+
+    # Examples
+
+    Increment the `likes` on a document with an id of `1`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: String,
+    #     pub likes: i64,
+    # }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<MyType>()
+                         .modify(1, |doc: &mut MyType| doc.likes += 1)
+                         .send()?;
+
+    println!("{:?}", response.version());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(mut self) -> Result<IndexResponse, Error> {
+        let mut retries = 0;
+
+        loop {
+            let mut req = self
+                .client
+                .document::<TDocument>()
+                .get_raw(self.index.clone(), self.id.clone())
+                .ty(self.ty.clone());
+
+            if let Some(routing) = self.routing.clone() {
+                req = req.routing(routing);
+            }
+
+            let res = req.send()?;
+
+            let (mut doc, seq_no, primary_term) = versioned_document(res, &self.index)?;
+
+            (self.modify)(&mut doc);
+
+            let mut req = self
+                .client
+                .document::<TDocument>()
+                .index_raw(self.index.clone(), doc)
+                .ty(self.ty.clone())
+                .id(self.id.clone())
+                .if_seq_no_primary_term(seq_no, primary_term);
+
+            if let Some(routing) = self.routing.clone() {
+                req = req.routing(routing);
+            }
+
+            let res = req.send();
+
+            match res {
+                Err(Error::Api(ApiError::VersionConflict { .. })) if retries < self.max_retries => {
+                    retries += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument, TModify> ModifyRequestBuilder<AsyncSender, TDocument, TModify>
+where
+    TDocument: DeserializeOwned + Serialize + Send + 'static,
+    TModify: FnMut(&mut TDocument) + Send + 'static,
+{
+    /**
+    Send a `ModifyRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the response for the index request that wrote the modified document.
+
+    # Examples
+
+    Increment the `likes` on a document with an id of `1`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: String,
+    #     pub likes: i64,
+    # }
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.document::<MyType>()
+                       .modify(1, |doc: &mut MyType| doc.likes += 1)
+                       .send();
+
+    future.and_then(|response| {
+        println!("{:?}", response.version());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let res_future = future::loop_fn((self, 0), |(mut builder, retries)| {
+            let mut req = builder
+                .client
+                .document::<TDocument>()
+                .get_raw(builder.index.clone(), builder.id.clone())
+                .ty(builder.ty.clone());
+
+            if let Some(routing) = builder.routing.clone() {
+                req = req.routing(routing);
+            }
+
+            req.send().and_then(move |res| {
+                let (mut doc, seq_no, primary_term) = match versioned_document(res, &builder.index)
+                {
+                    Ok(versioned) => versioned,
+                    Err(e) => return Either::A(future::err(e)),
+                };
+
+                (builder.modify)(&mut doc);
+
+                let mut req = builder
+                    .client
+                    .document::<TDocument>()
+                    .index_raw(builder.index.clone(), doc)
+                    .ty(builder.ty.clone())
+                    .id(builder.id.clone())
+                    .if_seq_no_primary_term(seq_no, primary_term);
+
+                if let Some(routing) = builder.routing.clone() {
+                    req = req.routing(routing);
+                }
+
+                let res_future = req.send();
+
+                Either::B(res_future.then(move |res| match res {
+                    Err(Error::Api(ApiError::VersionConflict { .. }))
+                        if retries < builder.max_retries =>
+                    {
+                        Ok(Loop::Continue((builder, retries + 1)))
+                    }
+                    res => res.map(Loop::Break),
+                }))
+            })
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndexResponse>;
+
+fn versioned_document<TDocument>(
+    res: GetResponse<TDocument>,
+    index: &Index<'static>,
+) -> Result<(TDocument, u64, u64), Error> {
+    let (seq_no, primary_term) = (res.sequence_number(), res.primary_term());
+
+    match (res.into_document(), seq_no, primary_term) {
+        (Some(doc), Some(seq_no), Some(primary_term)) => Ok((doc, seq_no, primary_term)),
+        (None, _, _) => Err(Error::Api(ApiError::DocumentMissing {
+            index: index.to_string(),
+        })),
+        _ => Err(error::request(error::message(
+            "the document doesn't have a sequence number and primary term",
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use super::versioned_document;
+    use crate::{
+        error::{
+            ApiError,
+            Error,
+        },
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn versioned_document_found() {
+        let res: GetResponse<Value> = serde_json::from_value(json!({
+            "_index": "testindex",
+            "_type": "_doc",
+            "_id": "1",
+            "_version": 2,
+            "_seq_no": 5,
+            "_primary_term": 1,
+            "found": true,
+            "_source": { "title": "A title" }
+        }))
+        .unwrap();
+
+        let (doc, seq_no, primary_term) = versioned_document(res, &"testindex".into()).unwrap();
+
+        assert_eq!(json!({ "title": "A title" }), doc);
+        assert_eq!(5, seq_no);
+        assert_eq!(1, primary_term);
+    }
+
+    #[test]
+    fn versioned_document_missing() {
+        let res: GetResponse<Value> = serde_json::from_value(json!({
+            "_index": "testindex",
+            "_type": "_doc",
+            "_id": "1",
+            "found": false
+        }))
+        .unwrap();
+
+        let err = versioned_document(res, &"testindex".into()).unwrap_err();
+
+        match err {
+            Error::Api(ApiError::DocumentMissing { ref index }) if index == "testindex" => (),
+            err => panic!("unexpected error: {:?}", err),
+        }
+    }
+}
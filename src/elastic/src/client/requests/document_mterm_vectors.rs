@@ -0,0 +1,475 @@
+/*!
+Builders for [multi term vectors requests][docs-mtermvectors].
+
+[docs-mtermvectors]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-termvectors.html
+*/
+
+use futures::Future;
+use serde_json;
+use std::marker::PhantomData;
+
+use crate::{
+    client::{
+        requests::{
+            common::TermVectorsOptions,
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::MtermVectorsResponse,
+        DocumentClient,
+    },
+    endpoints::MtermvectorsRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Id,
+        Index,
+        Routing,
+        Type,
+    },
+    types::document::{
+        DocumentType,
+        StaticIndex,
+        StaticType,
+        DEFAULT_DOC_TYPE,
+    },
+};
+
+/**
+A [multi term vectors request][docs-mtermvectors] builder that can be configured before sending.
+
+Call [`Client.document.mterm_vectors`][Client.document.mterm_vectors] to get an `MtermVectorsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-mtermvectors]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-termvectors.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document.mterm_vectors]: ../../struct.DocumentClient.html#multi-term-vectors-request
+*/
+pub type MtermVectorsRequestBuilder<TSender, TDocument> =
+    RequestBuilder<TSender, MtermVectorsRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct MtermVectorsRequestInner<TDocument> {
+    index: Index<'static>,
+    ty: Type<'static>,
+    ids: Vec<Id<'static>>,
+    options: TermVectorsOptions,
+    _marker: PhantomData<TDocument>,
+}
+
+/**
+# Multi term vectors request
+*/
+impl<TSender, TDocument> DocumentClient<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`MtermVectorsRequestBuilder`][MtermVectorsRequestBuilder] with this `Client` that can be configured before sending.
+
+    The same options are used to compute the term vectors of each document.
+    The term vectors are returned in the same order as the ids.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the term vectors for the `title` field of a few documents of a [`DocumentType`][documents-mod] called `MyType`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<MyType>()
+                         .mterm_vectors(vec![1, 2, 3])
+                         .fields(vec!["title"])
+                         .send()?;
+
+    for doc in response.docs() {
+        println!("{:?}: {} fields", doc.id(), doc.fields().count());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [MtermVectorsRequestBuilder]: requests/document_mterm_vectors/type.MtermVectorsRequestBuilder.html
+    [builder-methods]: requests/document_mterm_vectors/type.MtermVectorsRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_mterm_vectors/type.MtermVectorsRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_mterm_vectors/type.MtermVectorsRequestBuilder.html#send-asynchronously
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn mterm_vectors<I>(self, ids: I) -> MtermVectorsRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DocumentType + StaticIndex + StaticType,
+        I: IntoIterator,
+        I::Item: Into<Id<'static>>,
+    {
+        RequestBuilder::initial(
+            self.inner,
+            MtermVectorsRequestInner {
+                index: TDocument::static_index(),
+                ty: TDocument::static_ty(),
+                ids: ids.into_iter().map(Into::into).collect(),
+                options: TermVectorsOptions::default(),
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    /**
+    Create an [`MtermVectorsRequestBuilder`][MtermVectorsRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the term vectors for a few documents in the `myindex` index:
+
+    ```no_run
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<Value>()
+                         .mterm_vectors_raw("myindex", vec![1, 2, 3])
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [MtermVectorsRequestBuilder]: requests/document_mterm_vectors/type.MtermVectorsRequestBuilder.html
+    [builder-methods]: requests/document_mterm_vectors/type.MtermVectorsRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_mterm_vectors/type.MtermVectorsRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_mterm_vectors/type.MtermVectorsRequestBuilder.html#send-asynchronously
+    */
+    pub fn mterm_vectors_raw<I>(
+        self,
+        index: impl Into<Index<'static>>,
+        ids: I,
+    ) -> MtermVectorsRequestBuilder<TSender, TDocument>
+    where
+        I: IntoIterator,
+        I::Item: Into<Id<'static>>,
+    {
+        RequestBuilder::initial(
+            self.inner,
+            MtermVectorsRequestInner {
+                index: index.into(),
+                ty: DEFAULT_DOC_TYPE.into(),
+                ids: ids.into_iter().map(Into::into).collect(),
+                options: TermVectorsOptions::default(),
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<TDocument> MtermVectorsRequestInner<TDocument> {
+    fn into_request(self) -> Result<MtermvectorsRequest<'static, Vec<u8>>, Error> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            ids: Vec<&'a str>,
+            parameters: &'a TermVectorsOptions,
+        }
+
+        let body = serde_json::to_vec(&Body {
+            ids: self.ids.iter().map(|id| id.as_ref()).collect(),
+            parameters: &self.options,
+        })
+        .map_err(error::request)?;
+
+        Ok(MtermvectorsRequest::for_index_ty(self.index, self.ty, body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `MtermVectorsRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> MtermVectorsRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /** Set the index for the multi term vectors request. */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = index.into();
+        self
+    }
+
+    /** Set the type for the multi term vectors request. */
+    pub fn ty(mut self, ty: impl Into<Type<'static>>) -> Self {
+        self.inner.ty = ty.into();
+        self
+    }
+
+    /** Set the routing value for the multi term vectors request. */
+    pub fn routing(self, routing: impl Into<Routing<'static>>) -> Self {
+        let routing = routing.into().to_string();
+
+        self.params_fluent(move |params| params.url_param("routing", &routing))
+    }
+
+    /**
+    Only return term vectors for the given fields.
+
+    Fields can contain wildcards, like `address.*`.
+    By default, term vectors are returned for all fields that store them.
+    */
+    pub fn fields<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.inner.options.fields(fields);
+        self
+    }
+
+    /** Set whether or not to return the start and end offsets of each term. Defaults to `true`. */
+    pub fn offsets(mut self, offsets: bool) -> Self {
+        self.inner.options.offsets = Some(offsets);
+        self
+    }
+
+    /** Set whether or not to return the positions of each term. Defaults to `true`. */
+    pub fn positions(mut self, positions: bool) -> Self {
+        self.inner.options.positions = Some(positions);
+        self
+    }
+
+    /** Set whether or not to return the payloads of each term. Defaults to `true`. */
+    pub fn payloads(mut self, payloads: bool) -> Self {
+        self.inner.options.payloads = Some(payloads);
+        self
+    }
+
+    /**
+    Set whether or not to return the document frequency and total term frequency of each term across the shard. Defaults to `false`.
+
+    Computing term statistics can have a significant performance impact.
+    */
+    pub fn term_statistics(mut self, term_statistics: bool) -> Self {
+        self.inner.options.term_statistics = Some(term_statistics);
+        self
+    }
+
+    /** Set whether or not to return the document count and sums of frequencies of each field across the shard. Defaults to `true`. */
+    pub fn field_statistics(mut self, field_statistics: bool) -> Self {
+        self.inner.options.field_statistics = Some(field_statistics);
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> MtermVectorsRequestBuilder<SyncSender, TDocument> {
+    /**
+    Send an `MtermVectorsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Sum the frequency of a term across a few documents of a [`DocumentType`][documents-mod] called `MyType`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<MyType>()
+                         .mterm_vectors(vec![1, 2, 3])
+                         .fields(vec!["title"])
+                         .send()?;
+
+    let freq: u32 = response
+        .docs()
+        .filter_map(|doc| doc.field("title"))
+        .filter_map(|title| title.term("rust"))
+        .map(|stats| stats.term_freq())
+        .sum();
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn send(self) -> Result<MtermVectorsResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> MtermVectorsRequestBuilder<AsyncSender, TDocument>
+where
+    TDocument: Send + 'static,
+{
+    /**
+    Send an `MtermVectorsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised multi term vectors response.
+
+    # Examples
+
+    Get the term vectors for a few documents of a [`DocumentType`][documents-mod] called `MyType`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.document::<MyType>()
+                       .mterm_vectors(vec![1, 2, 3])
+                       .send();
+
+    future.and_then(|response| {
+        for doc in response.docs().filter(|doc| doc.found()) {
+            println!("{:?}: {} fields", doc.id(), doc.fields().count());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<MtermVectorsResponse>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[derive(ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    struct TestDoc {}
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .mterm_vectors(vec!["1", "2"])
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "ids": ["1", "2"],
+            "parameters": {}
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/testdoc/_doc/_mtermvectors", req.url.as_ref());
+        assert_eq!(expected_body.to_string(), actual_body.to_string());
+    }
+
+    #[test]
+    fn specify_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<Value>()
+            .mterm_vectors_raw("myindex", vec!["1"])
+            .index("new-idx")
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/new-idx/_doc/_mtermvectors", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_options() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .mterm_vectors(vec!["1"])
+            .fields(vec!["title"])
+            .positions(false)
+            .term_statistics(true)
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "ids": ["1"],
+            "parameters": {
+                "fields": ["title"],
+                "positions": false,
+                "term_statistics": true
+            }
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body.to_string(), actual_body.to_string());
+    }
+}
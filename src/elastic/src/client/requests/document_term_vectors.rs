@@ -0,0 +1,568 @@
+/*!
+Builders for [term vectors requests][docs-termvectors].
+
+[docs-termvectors]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-termvectors.html
+*/
+
+use futures::Future;
+use serde::ser::Serialize;
+use serde_json;
+
+use crate::{
+    client::{
+        requests::{
+            common::TermVectorsOptions,
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::TermVectorsResponse,
+        DocumentClient,
+    },
+    endpoints::TermvectorsRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Id,
+        Index,
+        Routing,
+        Type,
+    },
+    types::document::{
+        DocumentType,
+        StaticIndex,
+        StaticType,
+        DEFAULT_DOC_TYPE,
+    },
+};
+
+/**
+A [term vectors request][docs-termvectors] builder that can be configured before sending.
+
+Call [`Client.document.term_vectors`][Client.document.term_vectors] to get a `TermVectorsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-termvectors]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-termvectors.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document.term_vectors]: ../../struct.DocumentClient.html#term-vectors-request
+*/
+pub type TermVectorsRequestBuilder<TSender, TDocument> =
+    RequestBuilder<TSender, TermVectorsRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct TermVectorsRequestInner<TDocument> {
+    index: Index<'static>,
+    ty: Type<'static>,
+    id: Option<Id<'static>>,
+    doc: Option<TDocument>,
+    options: TermVectorsOptions,
+}
+
+/**
+# Term vectors request
+*/
+impl<TSender, TDocument> DocumentClient<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`TermVectorsRequestBuilder`][TermVectorsRequestBuilder] with this `Client` that can be configured before sending.
+
+    The response contains the terms of each field of the document, along with their statistics.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the term vectors for the `title` field of a [`DocumentType`][documents-mod] called `MyType` with an id of `1`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<MyType>()
+                         .term_vectors(1)
+                         .fields(vec!["title"])
+                         .term_statistics(true)
+                         .send()?;
+
+    if let Some(title) = response.field("title") {
+        for (term, stats) in title.terms() {
+            println!("{}: {}", term, stats.term_freq());
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [TermVectorsRequestBuilder]: requests/document_term_vectors/type.TermVectorsRequestBuilder.html
+    [builder-methods]: requests/document_term_vectors/type.TermVectorsRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_term_vectors/type.TermVectorsRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_term_vectors/type.TermVectorsRequestBuilder.html#send-asynchronously
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn term_vectors(
+        self,
+        id: impl Into<Id<'static>>,
+    ) -> TermVectorsRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DocumentType + StaticIndex + StaticType,
+    {
+        RequestBuilder::initial(
+            self.inner,
+            TermVectorsRequestInner {
+                index: TDocument::static_index(),
+                ty: TDocument::static_ty(),
+                id: Some(id.into()),
+                doc: None,
+                options: TermVectorsOptions::default(),
+            },
+        )
+    }
+
+    /**
+    Create a [`TermVectorsRequestBuilder`][TermVectorsRequestBuilder] for an artificial document with this `Client` that can be configured before sending.
+
+    The document isn't indexed.
+    Its term vectors are computed on the fly using the mapping of the index.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the term vectors for a [`DocumentType`][documents-mod] called `MyType` that hasn't been indexed:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { title: String }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let doc = MyType { title: "a title to analyze".to_owned() };
+
+    let response = client.document()
+                         .term_vectors_artificial(doc)
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [TermVectorsRequestBuilder]: requests/document_term_vectors/type.TermVectorsRequestBuilder.html
+    [builder-methods]: requests/document_term_vectors/type.TermVectorsRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_term_vectors/type.TermVectorsRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_term_vectors/type.TermVectorsRequestBuilder.html#send-asynchronously
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn term_vectors_artificial(
+        self,
+        doc: TDocument,
+    ) -> TermVectorsRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DocumentType + StaticIndex + StaticType,
+    {
+        RequestBuilder::initial(
+            self.inner,
+            TermVectorsRequestInner {
+                index: TDocument::static_index(),
+                ty: TDocument::static_ty(),
+                id: None,
+                doc: Some(doc),
+                options: TermVectorsOptions::default(),
+            },
+        )
+    }
+
+    /**
+    Create a [`TermVectorsRequestBuilder`][TermVectorsRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the term vectors for a document with an id of `1` in the `myindex` index:
+
+    ```no_run
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<Value>()
+                         .term_vectors_raw("myindex", 1)
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [TermVectorsRequestBuilder]: requests/document_term_vectors/type.TermVectorsRequestBuilder.html
+    [builder-methods]: requests/document_term_vectors/type.TermVectorsRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_term_vectors/type.TermVectorsRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_term_vectors/type.TermVectorsRequestBuilder.html#send-asynchronously
+    */
+    pub fn term_vectors_raw(
+        self,
+        index: impl Into<Index<'static>>,
+        id: impl Into<Id<'static>>,
+    ) -> TermVectorsRequestBuilder<TSender, TDocument> {
+        RequestBuilder::initial(
+            self.inner,
+            TermVectorsRequestInner {
+                index: index.into(),
+                ty: DEFAULT_DOC_TYPE.into(),
+                id: Some(id.into()),
+                doc: None,
+                options: TermVectorsOptions::default(),
+            },
+        )
+    }
+}
+
+impl<TDocument> TermVectorsRequestInner<TDocument>
+where
+    TDocument: Serialize,
+{
+    fn into_request(self) -> Result<TermvectorsRequest<'static, Vec<u8>>, Error> {
+        #[derive(Serialize)]
+        struct Body<'a, TDocument> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            doc: Option<&'a TDocument>,
+            #[serde(flatten)]
+            options: &'a TermVectorsOptions,
+        }
+
+        let body = serde_json::to_vec(&Body {
+            doc: self.doc.as_ref(),
+            options: &self.options,
+        })
+        .map_err(error::request)?;
+
+        Ok(match self.id {
+            Some(id) => TermvectorsRequest::for_index_ty_id(self.index, self.ty, id, body),
+            None => TermvectorsRequest::for_index_ty(self.index, self.ty, body),
+        })
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `TermVectorsRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> TermVectorsRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /** Set the index for the term vectors request. */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = index.into();
+        self
+    }
+
+    /** Set the type for the term vectors request. */
+    pub fn ty(mut self, ty: impl Into<Type<'static>>) -> Self {
+        self.inner.ty = ty.into();
+        self
+    }
+
+    /** Set the routing value for the term vectors request. */
+    pub fn routing(self, routing: impl Into<Routing<'static>>) -> Self {
+        let routing = routing.into().to_string();
+
+        self.params_fluent(move |params| params.url_param("routing", &routing))
+    }
+
+    /**
+    Only return term vectors for the given fields.
+
+    Fields can contain wildcards, like `address.*`.
+    By default, term vectors are returned for all fields that store them.
+    */
+    pub fn fields<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.inner.options.fields(fields);
+        self
+    }
+
+    /** Set whether or not to return the start and end offsets of each term. Defaults to `true`. */
+    pub fn offsets(mut self, offsets: bool) -> Self {
+        self.inner.options.offsets = Some(offsets);
+        self
+    }
+
+    /** Set whether or not to return the positions of each term. Defaults to `true`. */
+    pub fn positions(mut self, positions: bool) -> Self {
+        self.inner.options.positions = Some(positions);
+        self
+    }
+
+    /** Set whether or not to return the payloads of each term. Defaults to `true`. */
+    pub fn payloads(mut self, payloads: bool) -> Self {
+        self.inner.options.payloads = Some(payloads);
+        self
+    }
+
+    /**
+    Set whether or not to return the document frequency and total term frequency of each term across the shard. Defaults to `false`.
+
+    Computing term statistics can have a significant performance impact.
+    */
+    pub fn term_statistics(mut self, term_statistics: bool) -> Self {
+        self.inner.options.term_statistics = Some(term_statistics);
+        self
+    }
+
+    /** Set whether or not to return the document count and sums of frequencies of each field across the shard. Defaults to `true`. */
+    pub fn field_statistics(mut self, field_statistics: bool) -> Self {
+        self.inner.options.field_statistics = Some(field_statistics);
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> TermVectorsRequestBuilder<SyncSender, TDocument>
+where
+    TDocument: Serialize,
+{
+    /**
+    Send a `TermVectorsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the positions of each term in the `title` field of a [`DocumentType`][documents-mod] called `MyType` with an id of `1`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<MyType>()
+                         .term_vectors(1)
+                         .fields(vec!["title"])
+                         .offsets(false)
+                         .send()?;
+
+    for (field, term_vectors) in response.fields() {
+        for (term, stats) in term_vectors.terms() {
+            let positions: Vec<_> = stats.tokens().iter().filter_map(|token| token.position()).collect();
+
+            println!("{}.{}: {:?}", field, term, positions);
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn send(self) -> Result<TermVectorsResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> TermVectorsRequestBuilder<AsyncSender, TDocument>
+where
+    TDocument: Serialize + Send + 'static,
+{
+    /**
+    Send a `TermVectorsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised term vectors response.
+
+    # Examples
+
+    Get the term vectors for a [`DocumentType`][documents-mod] called `MyType` with an id of `1`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.document::<MyType>()
+                       .term_vectors(1)
+                       .send();
+
+    future.and_then(|response| {
+        for (field, term_vectors) in response.fields() {
+            println!("{}: {} terms", field, term_vectors.terms().count());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<TermVectorsResponse>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    struct TestDoc {
+        title: &'static str,
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .term_vectors("1")
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/testdoc/_doc/1/_termvectors", req.url.as_ref());
+        assert_eq!(b"{}", &*req.body);
+    }
+
+    #[test]
+    fn specify_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<Value>()
+            .term_vectors_raw("myindex", "1")
+            .index("new-idx")
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/new-idx/_doc/1/_termvectors", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_options() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .term_vectors("1")
+            .fields(vec!["title", "tags.*"])
+            .offsets(false)
+            .positions(true)
+            .payloads(false)
+            .term_statistics(true)
+            .field_statistics(false)
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "fields": ["title", "tags.*"],
+            "offsets": false,
+            "positions": true,
+            "payloads": false,
+            "term_statistics": true,
+            "field_statistics": false
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body.to_string(), actual_body.to_string());
+    }
+
+    #[test]
+    fn specify_artificial_doc() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document()
+            .term_vectors_artificial(TestDoc { title: "a title" })
+            .fields(vec!["title"])
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "doc": {
+                "title": "a title"
+            },
+            "fields": ["title"]
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/testdoc/_doc/_termvectors", req.url.as_ref());
+        assert_eq!(expected_body.to_string(), actual_body.to_string());
+    }
+}
@@ -6,12 +6,19 @@ Builders for [update document requests][docs-update].
 
 use futures::Future;
 use serde::ser::Serialize;
-use serde_json;
-use std::marker::PhantomData;
+use serde_json::{
+    self,
+    Value,
+};
+use std::{
+    marker::PhantomData,
+    ops::Not,
+};
 
 use crate::{
     client::{
         requests::{
+            common::field_list,
             raw::RawRequestInner,
             Pending as BasePending,
             RequestBuilder,
@@ -32,6 +39,7 @@ use crate::{
     params::{
         Id,
         Index,
+        Routing,
         Type,
     },
     types::document::{
@@ -60,14 +68,18 @@ The `send` method will either send the request [synchronously][send-sync] or [as
 [send-async]: #send-asynchronously
 [Client.document.update]: ../../struct.DocumentClient.html#update-document-request
 */
-pub type UpdateRequestBuilder<TSender, TBody> = RequestBuilder<TSender, UpdateRequestInner<TBody>>;
+pub type UpdateRequestBuilder<TSender, TBody, TUpsert = Value> =
+    RequestBuilder<TSender, UpdateRequestInner<TBody, TUpsert>>;
 
 #[doc(hidden)]
-pub struct UpdateRequestInner<TBody> {
+pub struct UpdateRequestInner<TBody, TUpsert> {
     index: Index<'static>,
     ty: Type<'static>,
     id: Id<'static>,
     body: TBody,
+    upsert: Option<TUpsert>,
+    scripted_upsert: bool,
+    detect_noop: Option<bool>,
     _marker: PhantomData<TBody>,
 }
 
@@ -213,6 +225,9 @@ where
                 ty,
                 id: id.into(),
                 body: Doc::empty(),
+                upsert: None,
+                scripted_upsert: false,
+                detect_noop: None,
                 _marker: PhantomData,
             },
         )
@@ -267,18 +282,40 @@ where
                 ty: DEFAULT_DOC_TYPE.into(),
                 id: id.into(),
                 body: Doc::empty(),
+                upsert: None,
+                scripted_upsert: false,
+                detect_noop: None,
                 _marker: PhantomData,
             },
         )
     }
 }
 
-impl<TBody> UpdateRequestInner<TBody>
+impl<TBody, TUpsert> UpdateRequestInner<TBody, TUpsert>
 where
     TBody: Serialize,
+    TUpsert: Serialize,
 {
     fn into_request(self) -> Result<UpdateRequest<'static, Vec<u8>>, Error> {
-        let body = serde_json::to_vec(&self.body).map_err(error::request)?;
+        #[derive(Serialize)]
+        struct Body<'a, TBody, TUpsert> {
+            #[serde(flatten)]
+            body: &'a TBody,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            upsert: Option<&'a TUpsert>,
+            #[serde(skip_serializing_if = "Not::not")]
+            scripted_upsert: bool,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            detect_noop: Option<bool>,
+        }
+
+        let body = serde_json::to_vec(&Body {
+            body: &self.body,
+            upsert: self.upsert.as_ref(),
+            scripted_upsert: self.scripted_upsert,
+            detect_noop: self.detect_noop,
+        })
+        .map_err(error::request)?;
 
         Ok(UpdateRequest::for_index_ty_id(
             self.index, self.ty, self.id, body,
@@ -291,7 +328,7 @@ where
 
 Configure an `UpdateRequestBuilder` before sending it.
 */
-impl<TSender, TBody> UpdateRequestBuilder<TSender, TBody>
+impl<TSender, TBody, TUpsert> UpdateRequestBuilder<TSender, TBody, TUpsert>
 where
     TSender: Sender,
 {
@@ -307,6 +344,33 @@ where
         self
     }
 
+    /**
+    Set the routing value for the update request.
+
+    This must be the same value the document was indexed with.
+    */
+    pub fn routing(self, routing: impl Into<Routing<'static>>) -> Self {
+        let routing = routing.into().to_string();
+
+        self.params_fluent(move |params| params.url_param("routing", &routing))
+    }
+
+    /**
+    Only update the document if its sequence number and primary term match the given ones.
+
+    Updates are already applied atomically to the current document, so this is only needed when the update is computed from a copy of the document that was read earlier.
+    A mismatch fails the request with an [`ApiError::VersionConflict`][ApiError::VersionConflict].
+
+    [ApiError::VersionConflict]: ../../../error/enum.ApiError.html#variant.VersionConflict
+    */
+    pub fn if_seq_no_primary_term(self, seq_no: u64, primary_term: u64) -> Self {
+        self.params_fluent(move |params| {
+            params
+                .url_param("if_seq_no", seq_no)
+                .url_param("if_primary_term", primary_term)
+        })
+    }
+
     /**
     Update the source using a document.
 
@@ -363,7 +427,10 @@ where
 
     [documents-mod]: ../../types/document/index.html
     */
-    pub fn doc<TDocument>(self, doc: TDocument) -> UpdateRequestBuilder<TSender, Doc<TDocument>>
+    pub fn doc<TDocument>(
+        self,
+        doc: TDocument,
+    ) -> UpdateRequestBuilder<TSender, Doc<TDocument>, TUpsert>
     where
         TDocument: Serialize,
     {
@@ -375,6 +442,9 @@ where
                 index: self.inner.index,
                 ty: self.inner.ty,
                 id: self.inner.id,
+                upsert: self.inner.upsert,
+                scripted_upsert: false,
+                detect_noop: self.inner.detect_noop,
                 _marker: PhantomData,
             },
         )
@@ -440,7 +510,7 @@ where
     pub fn script<TScript, TParams>(
         self,
         builder: TScript,
-    ) -> UpdateRequestBuilder<TSender, Script<TParams>>
+    ) -> UpdateRequestBuilder<TSender, Script<TParams>, TUpsert>
     where
         TScript: Into<ScriptBuilder<TParams>>,
     {
@@ -452,6 +522,9 @@ where
                 index: self.inner.index,
                 ty: self.inner.ty,
                 id: self.inner.id,
+                upsert: self.inner.upsert,
+                scripted_upsert: self.inner.scripted_upsert,
+                detect_noop: None,
                 _marker: PhantomData,
             },
         )
@@ -531,7 +604,7 @@ where
         self,
         source: TScript,
         builder: impl FnOnce(ScriptBuilder<DefaultParams>) -> ScriptBuilder<TParams>,
-    ) -> UpdateRequestBuilder<TSender, Script<TParams>>
+    ) -> UpdateRequestBuilder<TSender, Script<TParams>, TUpsert>
     where
         TScript: ToString,
     {
@@ -539,9 +612,151 @@ where
 
         self.script(builder)
     }
+
+    /**
+    Index the given document if the document being updated doesn't exist.
+
+    Without an upsert document, updating a missing document fails with an [`ApiError::DocumentMissing`][ApiError::DocumentMissing].
+
+    # Examples
+
+    Increment a counter, or start it at `1` if it doesn't exist yet:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct Counter { id: String, count: i64 }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<Counter>()
+                         .update("page-views")
+                         .script("ctx._source.count += 1")
+                         .upsert(Counter { id: "page-views".to_owned(), count: 1 })
+                         .send()?;
+
+    match response.result() {
+        UpdateResult::Created => println!("started counting"),
+        _ => println!("incremented the count"),
+    }
+    # Ok(())
+    # }
+    ```
+
+    [ApiError::DocumentMissing]: ../../../error/enum.ApiError.html#variant.DocumentMissing
+    */
+    pub fn upsert<TNewUpsert>(
+        self,
+        upsert: TNewUpsert,
+    ) -> UpdateRequestBuilder<TSender, TBody, TNewUpsert>
+    where
+        TNewUpsert: Serialize,
+    {
+        RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            UpdateRequestInner {
+                body: self.inner.body,
+                index: self.inner.index,
+                ty: self.inner.ty,
+                id: self.inner.id,
+                upsert: Some(upsert),
+                scripted_upsert: self.inner.scripted_upsert,
+                detect_noop: self.inner.detect_noop,
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    /**
+    Only return the given fields of the updated `_source` document.
+
+    The fields are returned in the `get` section of the response, and can be read using [`into_document`][UpdateResponse.into_document].
+    Fields can contain wildcards, like `address.*`.
+
+    [UpdateResponse.into_document]: ../../responses/struct.UpdateResponse.html#method.into_document
+    */
+    pub fn source_includes<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let fields = field_list(fields);
+
+        self.params_fluent(move |params| params.url_param("_source_includes", &fields))
+    }
+
+    /**
+    Don't return the given fields of the updated `_source` document.
+
+    Fields can contain wildcards, like `address.*`.
+    */
+    pub fn source_excludes<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let fields = field_list(fields);
+
+        self.params_fluent(move |params| params.url_param("_source_excludes", &fields))
+    }
 }
 
-impl<TSender, TBody> UpdateRequestBuilder<TSender, TBody>
+/**
+# Document update methods
+
+Configure an `UpdateRequestBuilder` that updates the source using a document.
+*/
+impl<TSender, TDocument, TUpsert> UpdateRequestBuilder<TSender, Doc<TDocument>, TUpsert>
+where
+    TSender: Sender,
+{
+    /**
+    Index the partial document as-is if the document being updated doesn't exist.
+
+    This is an alternative to passing the same document to [`upsert`](#method.upsert).
+    */
+    pub fn doc_as_upsert(mut self) -> Self {
+        self.inner.body = self.inner.body.doc_as_upsert();
+        self
+    }
+
+    /**
+    Set whether or not to skip updates that wouldn't change the document.
+
+    Noop detection is enabled by default, and an update that doesn't change anything will have a result of [`UpdateResult::NoOp`][UpdateResult::NoOp].
+    Disabling it will force the document to be reindexed, even if it's unchanged.
+
+    [UpdateResult::NoOp]: ../../responses/enum.UpdateResult.html#variant.NoOp
+    */
+    pub fn detect_noop(mut self, detect_noop: bool) -> Self {
+        self.inner.detect_noop = Some(detect_noop);
+        self
+    }
+}
+
+/**
+# Script update methods
+
+Configure an `UpdateRequestBuilder` that updates the source using a script.
+*/
+impl<TSender, TParams, TUpsert> UpdateRequestBuilder<TSender, Script<TParams>, TUpsert>
+where
+    TSender: Sender,
+{
+    /**
+    Run the script even if the document being updated doesn't exist.
+
+    The script will see the [`upsert`](#method.upsert) document as `ctx._source`, so it can handle creating the document itself.
+    */
+    pub fn scripted_upsert(mut self) -> Self {
+        self.inner.scripted_upsert = true;
+        self
+    }
+}
+
+impl<TSender, TBody, TUpsert> UpdateRequestBuilder<TSender, TBody, TUpsert>
 where
     TSender: Sender,
 {
@@ -583,7 +798,7 @@ where
     [`UpdateResponse`]: ../../responses/struct.UpdateResponse.html
     [`into_document`]: ../../responses/struct.UpdateResponse.html#method.into_document
     */
-    pub fn source(self) -> UpdateRequestBuilder<TSender, TBody> {
+    pub fn source(self) -> UpdateRequestBuilder<TSender, TBody, TUpsert> {
         RequestBuilder::new(
             self.client,
             // TODO: allow passing in `source` parameter add `_source` to body
@@ -596,6 +811,9 @@ where
                 index: self.inner.index,
                 ty: self.inner.ty,
                 id: self.inner.id,
+                upsert: self.inner.upsert,
+                scripted_upsert: self.inner.scripted_upsert,
+                detect_noop: self.inner.detect_noop,
                 _marker: PhantomData,
             },
         )
@@ -605,9 +823,10 @@ where
 /**
 # Send synchronously
 */
-impl<TBody> UpdateRequestBuilder<SyncSender, TBody>
+impl<TBody, TUpsert> UpdateRequestBuilder<SyncSender, TBody, TUpsert>
 where
     TBody: Serialize,
+    TUpsert: Serialize,
 {
     /**
     Send an `UpdateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
@@ -656,9 +875,10 @@ where
 /**
 # Send asynchronously
 */
-impl<TBody> UpdateRequestBuilder<AsyncSender, TBody>
+impl<TBody, TUpsert> UpdateRequestBuilder<AsyncSender, TBody, TUpsert>
 where
     TBody: Serialize + Send + 'static,
+    TUpsert: Serialize + Send + 'static,
 {
     /**
     Send an `UpdateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
@@ -933,4 +1153,106 @@ mod tests {
 
         assert_eq!(expected_body.to_string(), actual_body.to_string());
     }
+
+    #[test]
+    fn specify_doc_as_upsert() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .update("1")
+            .doc(json!({ "a": "string" }))
+            .doc_as_upsert()
+            .detect_noop(false)
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "doc": {
+                "a": "string"
+            },
+            "doc_as_upsert": true,
+            "detect_noop": false
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body.to_string(), actual_body.to_string());
+    }
+
+    #[test]
+    fn specify_upsert() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .update("1")
+            .upsert(json!({ "a": "upserted" }))
+            .doc(json!({ "a": "string" }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "doc": {
+                "a": "string"
+            },
+            "upsert": {
+                "a": "upserted"
+            }
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body.to_string(), actual_body.to_string());
+    }
+
+    #[test]
+    fn specify_scripted_upsert() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .update("1")
+            .script("ctx._source.count += 1")
+            .scripted_upsert()
+            .upsert(json!({ "count": 0 }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "script": {
+                "inline": "ctx._source.count += 1"
+            },
+            "upsert": {
+                "count": 0
+            },
+            "scripted_upsert": true
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body.to_string(), actual_body.to_string());
+    }
+
+    #[test]
+    fn specify_source_filtering() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .document::<TestDoc>()
+            .update("1")
+            .source_includes(vec!["a", "b.*"])
+            .source_excludes(vec!["b.c"])
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("_source_includes=a%2Cb.*"));
+        assert!(qry.contains("_source_excludes=b.c"));
+    }
 }
@@ -0,0 +1,598 @@
+/*!
+Builders for [update by query requests][docs-update-by-query].
+
+[docs-update-by-query]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html
+*/
+
+use futures::Future;
+use serde::{
+    de::DeserializeOwned,
+    ser::Serialize,
+};
+use serde_json::{
+    self,
+    Value,
+};
+use std::marker::PhantomData;
+
+use crate::{
+    client::{
+        requests::{
+            common::{
+                Conflicts,
+                DefaultParams,
+                RequestsPerSecond,
+                Script,
+                ScriptBuilder,
+                Slices,
+            },
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::{
+            BulkByScrollResponse,
+            TaskResponse,
+        },
+        DocumentClient,
+    },
+    endpoints::UpdateByQueryRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::{
+        receiver::IsOk,
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+    },
+    params::{
+        Index,
+        Routing,
+    },
+    types::document::{
+        DocumentType,
+        StaticIndex,
+    },
+};
+
+/**
+An [update by query request][docs-update-by-query] builder that can be configured before sending.
+
+Call [`Client.document.update_by_query`][Client.document.update_by_query] to get an `UpdateByQueryRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+By default, sending the request waits for all matching documents to be updated and returns a [`BulkByScrollResponse`][BulkByScrollResponse].
+Call [`as_task`](#method.as_task) to return a handle to a background task instead.
+
+[docs-update-by-query]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.document.update_by_query]: ../../struct.DocumentClient.html#update-by-query-request
+[BulkByScrollResponse]: ../../responses/struct.BulkByScrollResponse.html
+*/
+pub type UpdateByQueryRequestBuilder<
+    TSender,
+    TParams = DefaultParams,
+    TResponse = BulkByScrollResponse,
+> = RequestBuilder<TSender, UpdateByQueryRequestInner<TParams, TResponse>>;
+
+#[doc(hidden)]
+pub struct UpdateByQueryRequestInner<TParams, TResponse> {
+    index: Index<'static>,
+    query: Value,
+    script: Option<Script<TParams>>,
+    _marker: PhantomData<TResponse>,
+}
+
+/**
+# Update by query request
+*/
+impl<TSender, TDocument> DocumentClient<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`UpdateByQueryRequestBuilder`][UpdateByQueryRequestBuilder] with this `Client` that can be configured before sending.
+
+    Every document in the index that matches the query will be updated.
+    Without a script, documents are reindexed in place, which is useful for picking up changes to the mapping.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Increment the `likes` of all [`DocumentType`][documents-mod]s called `MyType` that belong to a user:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # #[macro_use] extern crate serde_json;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<MyType>()
+                         .update_by_query(json!({
+                             "term": {
+                                 "user": "kimchy"
+                             }
+                         }))
+                         .script("ctx._source.likes++")
+                         .conflicts(Conflicts::Proceed)
+                         .send()?;
+
+    println!("updated {} documents", response.updated());
+    # Ok(())
+    # }
+    ```
+
+    [UpdateByQueryRequestBuilder]: requests/document_update_by_query/type.UpdateByQueryRequestBuilder.html
+    [builder-methods]: requests/document_update_by_query/type.UpdateByQueryRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_update_by_query/type.UpdateByQueryRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_update_by_query/type.UpdateByQueryRequestBuilder.html#send-asynchronously
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn update_by_query(self, query: impl Into<Value>) -> UpdateByQueryRequestBuilder<TSender>
+    where
+        TDocument: DocumentType + StaticIndex,
+    {
+        RequestBuilder::initial(
+            self.inner,
+            UpdateByQueryRequestInner::new(TDocument::static_index(), query.into()),
+        )
+    }
+
+    /**
+    Create an [`UpdateByQueryRequestBuilder`][UpdateByQueryRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Reindex every document in the `myindex` index in place:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<Value>()
+                         .update_by_query_raw("myindex", json!({ "match_all": {} }))
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [UpdateByQueryRequestBuilder]: requests/document_update_by_query/type.UpdateByQueryRequestBuilder.html
+    [builder-methods]: requests/document_update_by_query/type.UpdateByQueryRequestBuilder.html#builder-methods
+    [send-sync]: requests/document_update_by_query/type.UpdateByQueryRequestBuilder.html#send-synchronously
+    [send-async]: requests/document_update_by_query/type.UpdateByQueryRequestBuilder.html#send-asynchronously
+    */
+    pub fn update_by_query_raw(
+        self,
+        index: impl Into<Index<'static>>,
+        query: impl Into<Value>,
+    ) -> UpdateByQueryRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            UpdateByQueryRequestInner::new(index.into(), query.into()),
+        )
+    }
+}
+
+impl UpdateByQueryRequestInner<DefaultParams, BulkByScrollResponse> {
+    fn new(index: Index<'static>, query: Value) -> Self {
+        UpdateByQueryRequestInner {
+            index,
+            query,
+            script: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<TParams, TResponse> UpdateByQueryRequestInner<TParams, TResponse>
+where
+    TParams: Serialize,
+{
+    fn into_request(self) -> Result<UpdateByQueryRequest<'static, Vec<u8>>, Error> {
+        #[derive(Serialize)]
+        struct Body<'a, TParams> {
+            query: &'a Value,
+            #[serde(flatten)]
+            script: Option<&'a Script<TParams>>,
+        }
+
+        let body = serde_json::to_vec(&Body {
+            query: &self.query,
+            script: self.script.as_ref(),
+        })
+        .map_err(error::request)?;
+
+        Ok(UpdateByQueryRequest::for_index(self.index, body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `UpdateByQueryRequestBuilder` before sending it.
+*/
+impl<TSender, TParams, TResponse> UpdateByQueryRequestBuilder<TSender, TParams, TResponse>
+where
+    TSender: Sender,
+{
+    /**
+    Set the index for the update by query request.
+
+    The index can be a comma-separated list of indices or contain wildcards.
+    */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = index.into();
+        self
+    }
+
+    /** Only update matching documents on the shards for the given routing value. */
+    pub fn routing(self, routing: impl Into<Routing<'static>>) -> Self {
+        let routing = routing.into().to_string();
+
+        self.params_fluent(move |params| params.url_param("routing", &routing))
+    }
+
+    /**
+    Update each matching document using [a script][painless-lang].
+
+    The script can set `ctx.op` to `noop` to skip a document or `delete` to delete it.
+
+    # Examples
+
+    Set the `status` of all matching documents using a parameterised script:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use elastic::client::requests::document_update::ScriptBuilder;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let script = ScriptBuilder::new("ctx._source.status = params.status")
+        .param("status", "archived");
+
+    let response = client.document::<Value>()
+                         .update_by_query_raw("myindex", json!({
+                             "range": {
+                                 "last_seen": {
+                                     "lt": "now-1y"
+                                 }
+                             }
+                         }))
+                         .script(script)
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [painless-lang]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-painless.html
+    */
+    pub fn script<TScript, TNewParams>(
+        self,
+        builder: TScript,
+    ) -> UpdateByQueryRequestBuilder<TSender, TNewParams, TResponse>
+    where
+        TScript: Into<ScriptBuilder<TNewParams>>,
+    {
+        RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            UpdateByQueryRequestInner {
+                index: self.inner.index,
+                query: self.inner.query,
+                script: Some(builder.into().build()),
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    /**
+    Update each matching document using [a script][painless-lang] configured by a fluent closure API.
+
+    # Examples
+
+    Set the `status` of all matching documents using a parameterised script:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<Value>()
+                         .update_by_query_raw("myindex", json!({ "match_all": {} }))
+                         .script_fluent("ctx._source.status = params.status", |script| script
+                            .param("status", "archived"))
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [painless-lang]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-painless.html
+    */
+    pub fn script_fluent<TScript, TNewParams>(
+        self,
+        source: TScript,
+        builder: impl FnOnce(ScriptBuilder<DefaultParams>) -> ScriptBuilder<TNewParams>,
+    ) -> UpdateByQueryRequestBuilder<TSender, TNewParams, TResponse>
+    where
+        TScript: ToString,
+    {
+        let builder = builder(ScriptBuilder::new(source));
+
+        self.script(builder)
+    }
+
+    /**
+    Set what to do when a matching document changes while the request is running.
+
+    By default the request is aborted at the first version conflict.
+    */
+    pub fn conflicts(self, conflicts: Conflicts) -> Self {
+        self.params_fluent(move |params| params.url_param("conflicts", conflicts.as_str()))
+    }
+
+    /** Split the request into slices that are processed in parallel. */
+    pub fn slices(self, slices: impl Into<Slices>) -> Self {
+        let slices = slices.into();
+
+        self.params_fluent(move |params| params.url_param("slices", slices))
+    }
+
+    /** Throttle the request so that it doesn't overwhelm the cluster. */
+    pub fn requests_per_second(self, requests_per_second: impl Into<RequestsPerSecond>) -> Self {
+        let requests_per_second = requests_per_second.into();
+
+        self.params_fluent(move |params| {
+            params.url_param("requests_per_second", requests_per_second)
+        })
+    }
+
+    /** Refresh the affected shards once the request has finished so the updates are visible to search. */
+    pub fn refresh(self) -> Self {
+        self.params_fluent(|params| params.url_param("refresh", true))
+    }
+
+    /**
+    Run the request as a background task instead of waiting for it to finish.
+
+    Sending the request will return a [`TaskResponse`][TaskResponse] as soon as the task has started.
+
+    [TaskResponse]: ../../responses/struct.TaskResponse.html
+    */
+    pub fn as_task(self) -> UpdateByQueryRequestBuilder<TSender, TParams, TaskResponse> {
+        let builder = RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            UpdateByQueryRequestInner {
+                index: self.inner.index,
+                query: self.inner.query,
+                script: self.inner.script,
+                _marker: PhantomData,
+            },
+        );
+
+        builder.params_fluent(|params| params.url_param("wait_for_completion", false))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TParams, TResponse> UpdateByQueryRequestBuilder<SyncSender, TParams, TResponse>
+where
+    TParams: Serialize,
+    TResponse: IsOk + DeserializeOwned,
+{
+    /**
+    Send an `UpdateByQueryRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Increment the `likes` of all documents in the `myindex` index that belong to a user:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<Value>()
+                         .update_by_query_raw("myindex", json!({
+                             "term": {
+                                 "user": "kimchy"
+                             }
+                         }))
+                         .script("ctx._source.likes++")
+                         .send()?;
+
+    println!("{} conflicts", response.version_conflicts());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<TResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TParams, TResponse> UpdateByQueryRequestBuilder<AsyncSender, TParams, TResponse>
+where
+    TParams: Serialize + Send + 'static,
+    TResponse: IsOk + DeserializeOwned + Send + 'static,
+{
+    /**
+    Send an `UpdateByQueryRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised update by query response.
+
+    # Examples
+
+    Increment the `likes` of all documents in the `myindex` index that belong to a user:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.document::<Value>()
+                       .update_by_query_raw("myindex", json!({
+                           "term": {
+                               "user": "kimchy"
+                           }
+                       }))
+                       .script("ctx._source.likes++")
+                       .send();
+
+    future.and_then(|response| {
+        println!("updated {} documents", response.updated());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<TResponse> {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending<TResponse = BulkByScrollResponse> = BasePending<TResponse>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+        assert_send::<super::Pending<TaskResponse>>();
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    struct TestDoc {}
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .update_by_query(json!({ "match_all": {} }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/testdoc/_update_by_query", req.url.as_ref());
+        assert_eq!(json!({ "query": { "match_all": {} } }), actual_body);
+    }
+
+    #[test]
+    fn specify_script() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .update_by_query(json!({ "match_all": {} }))
+            .script_fluent("ctx._source.likes += params.n", |script| {
+                script.param("n", 1)
+            })
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        let expected_body = json!({
+            "query": { "match_all": {} },
+            "script": {
+                "inline": "ctx._source.likes += params.n",
+                "params": { "n": 1 }
+            }
+        });
+
+        assert_eq!(expected_body, actual_body);
+    }
+
+    #[test]
+    fn specify_params() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .document::<TestDoc>()
+            .update_by_query(json!({ "match_all": {} }))
+            .conflicts(Conflicts::Proceed)
+            .slices(4)
+            .requests_per_second(RequestsPerSecond::Unlimited)
+            .as_task()
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("conflicts=proceed"));
+        assert!(qry.contains("slices=4"));
+        assert!(qry.contains("requests_per_second=-1"));
+        assert!(qry.contains("wait_for_completion=false"));
+    }
+}
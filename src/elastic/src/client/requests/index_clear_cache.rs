@@ -0,0 +1,267 @@
+/*!
+Builders for [clear index cache requests][docs-clear-cache].
+
+[docs-clear-cache]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-clearcache.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesShardsResponse,
+        IndexClient,
+    },
+    endpoints::IndicesClearCacheRequest,
+    error::Error,
+    http::{
+        empty_body,
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+        DefaultBody,
+    },
+    params::Index,
+};
+
+/**
+A [clear index cache request][docs-clear-cache] builder that can be configured before sending.
+
+Call [`Client.index.clear_cache`][Client.index.clear_cache] to get an `IndexClearCacheRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-clear-cache]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-clearcache.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.clear_cache]: ../../struct.IndexClient.html#clear-index-cache-request
+*/
+pub type IndexClearCacheRequestBuilder<TSender> =
+    RequestBuilder<TSender, IndexClearCacheRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexClearCacheRequestInner {
+    index: Index<'static>,
+}
+
+/**
+# Clear index cache request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexClearCacheRequestBuilder`][IndexClearCacheRequestBuilder] with this `Client` that can be configured before sending.
+
+    The index can be a comma-separated list of indices or contain wildcards.
+    All caches are cleared unless specific caches are chosen using the [builder methods][builder-methods].
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Clear all caches for an index called `myindex`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").clear_cache().send()?;
+
+    assert!(response.all_successful());
+    # Ok(())
+    # }
+    ```
+
+    [IndexClearCacheRequestBuilder]: requests/index_clear_cache/type.IndexClearCacheRequestBuilder.html
+    [builder-methods]: requests/index_clear_cache/type.IndexClearCacheRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_clear_cache/type.IndexClearCacheRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_clear_cache/type.IndexClearCacheRequestBuilder.html#send-asynchronously
+    */
+    pub fn clear_cache(self) -> IndexClearCacheRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexClearCacheRequestInner { index: self.index },
+        )
+    }
+}
+
+impl IndexClearCacheRequestInner {
+    fn into_request(self) -> IndicesClearCacheRequest<'static, DefaultBody> {
+        IndicesClearCacheRequest::for_index(self.index, empty_body())
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexClearCacheRequestBuilder` before sending it.
+*/
+impl<TSender> IndexClearCacheRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /** Clear the query cache. */
+    pub fn query_cache(self) -> Self {
+        self.params_fluent(|params| params.url_param("query", true))
+    }
+
+    /** Clear the fielddata cache. */
+    pub fn fielddata(self) -> Self {
+        self.params_fluent(|params| params.url_param("fielddata", true))
+    }
+
+    /** Clear the shard request cache. */
+    pub fn request_cache(self) -> Self {
+        self.params_fluent(|params| params.url_param("request", true))
+    }
+
+    /** Only clear the fielddata cache for the given fields. */
+    pub fn fields<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        let fields = fields
+            .into_iter()
+            .map(|field| field.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self.params_fluent(move |params| params.url_param("fields", fields.clone()))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexClearCacheRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexClearCacheRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Clear all caches for an index called `myindex`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").clear_cache().send()?;
+
+    assert!(response.all_successful());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesShardsResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexClearCacheRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexClearCacheRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised shards response.
+
+    # Examples
+
+    Clear all caches for an index called `myindex`:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("myindex").clear_cache().send();
+
+    future.and_then(|response| {
+        assert!(response.all_successful());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesShardsResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.index("testindex").clear_cache().inner.into_request();
+
+        assert_eq!("/testindex/_cache/clear", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_caches() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .index("testindex")
+            .clear_cache()
+            .query_cache()
+            .fielddata()
+            .fields(vec!["title", "tags"])
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("query=true"));
+        assert!(qry.contains("fielddata=true"));
+        assert!(qry.contains("fields=title%2Ctags"));
+    }
+}
@@ -5,10 +5,16 @@ Builders for [create index requests][docs-create-index].
 */
 
 use futures::Future;
+use serde_json::{
+    self,
+    Map,
+    Value,
+};
 
 use crate::{
     client::{
         requests::{
+            index_settings::IndexSettings,
             raw::RawRequestInner,
             Pending as BasePending,
             RequestBuilder,
@@ -17,7 +23,10 @@ use crate::{
         IndexClient,
     },
     endpoints::IndicesCreateRequest,
-    error::Error,
+    error::{
+        self,
+        Error,
+    },
     http::{
         empty_body,
         sender::{
@@ -116,6 +125,26 @@ where
     # }
     ```
 
+    Create an index with typed [`IndexSettings`][IndexSettings]:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let settings = IndexSettings::new()
+        .number_of_shards(3)
+        .number_of_replicas(2);
+
+    let response = client.index("myindex")
+                         .create()
+                         .settings(settings)
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
     For more details on document types and mapping, see the [`types`][types-mod] module.
 
     [IndexCreateRequestBuilder]: requests/index_create/type.IndexCreateRequestBuilder.html
@@ -124,6 +153,7 @@ where
     [send-async]: requests/index_create/type.IndexCreateRequestBuilder.html#send-asynchronously
     [types-mod]: ../types/index.html
     [documents-mod]: ../types/document/index.html
+    [IndexSettings]: requests/index_settings/struct.IndexSettings.html
     */
     pub fn create(self) -> IndexCreateRequestBuilder<TSender, DefaultBody> {
         RequestBuilder::initial(
@@ -142,6 +172,177 @@ impl<TBody> IndexCreateRequestInner<TBody> {
     }
 }
 
+impl IndexCreateRequestInner<IndexSettingsBody> {
+    fn into_settings_request(self) -> Result<IndicesCreateRequest<'static, Vec<u8>>, Error> {
+        let IndexSettingsBody { settings, body } = self.body;
+
+        settings.validate()?;
+
+        let settings = serde_json::to_value(&settings).map_err(error::request)?;
+
+        let mut body = match body? {
+            Some(Value::Object(body)) => body,
+            Some(_) => {
+                return Err(error::request(error::message(
+                    "the create index body must be a JSON object",
+                )))
+            }
+            None => Default::default(),
+        };
+
+        // Typed settings are merged into any settings in the raw body
+        match (body.get_mut("settings"), settings) {
+            (Some(Value::Object(body_settings)), Value::Object(settings)) => {
+                check_settings_overlap(body_settings, &settings)?;
+                merge_settings(body_settings, settings);
+            }
+            (_, settings) => {
+                body.insert("settings".to_owned(), settings);
+            }
+        }
+
+        let body = serde_json::to_vec(&body).map_err(error::request)?;
+
+        Ok(IndicesCreateRequest::for_index(self.index, body))
+    }
+}
+
+/**
+Check that typed settings don't set any of the same settings as a raw body.
+
+Settings can be nested objects or dotted keys, with or without an `index.` prefix, so they're compared by their normalised leaf keys.
+*/
+fn check_settings_overlap(
+    body: &Map<String, Value>,
+    settings: &Map<String, Value>,
+) -> Result<(), Error> {
+    let mut body_keys = Vec::new();
+    setting_keys(None, body, &mut body_keys);
+
+    let mut settings_keys = Vec::new();
+    setting_keys(None, settings, &mut settings_keys);
+
+    for key in &settings_keys {
+        let overlap = body_keys.iter().any(|body_key| {
+            body_key == key
+                || body_key.starts_with(&format!("{}.", key))
+                || key.starts_with(&format!("{}.", body_key))
+        });
+
+        if overlap {
+            return Err(error::request(error::message(format!(
+                "the setting `{}` is set in both the create index body and the typed settings",
+                key
+            ))));
+        }
+    }
+
+    Ok(())
+}
+
+fn setting_keys(prefix: Option<&str>, settings: &Map<String, Value>, keys: &mut Vec<String>) {
+    for (key, value) in settings {
+        let key = match prefix {
+            Some(prefix) => format!("{}.{}", prefix, key),
+            None => key.to_owned(),
+        };
+
+        match value {
+            Value::Object(settings) => setting_keys(Some(&key), settings, keys),
+            _ => {
+                let key = match key.strip_prefix("index.") {
+                    Some(key) => key.to_owned(),
+                    None => key,
+                };
+
+                keys.push(key)
+            }
+        }
+    }
+}
+
+/** Recursively merge settings objects. */
+fn merge_settings(target: &mut Map<String, Value>, source: Map<String, Value>) {
+    for (key, value) in source {
+        match (target.get_mut(&key), value) {
+            (Some(Value::Object(target)), Value::Object(source)) => merge_settings(target, source),
+            (_, value) => {
+                target.insert(key, value);
+            }
+        }
+    }
+}
+
+/**
+A create index body that typed settings can be merged into.
+
+Raw bodies are parsed as JSON, so other parts of the body, like `mappings`, are kept when [`settings`][settings] are set.
+
+[settings]: type.IndexCreateRequestBuilder.html#method.settings
+*/
+pub trait IndexCreateBody {
+    /**
+    Parse the body as JSON.
+
+    An empty body is `None`.
+    */
+    fn into_json(self) -> Result<Option<Value>, Error>;
+}
+
+impl IndexCreateBody for &'static [u8] {
+    fn into_json(self) -> Result<Option<Value>, Error> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        serde_json::from_slice(self)
+            .map(Some)
+            .map_err(error::request)
+    }
+}
+
+impl IndexCreateBody for &'static str {
+    fn into_json(self) -> Result<Option<Value>, Error> {
+        self.as_bytes().into_json()
+    }
+}
+
+impl IndexCreateBody for Vec<u8> {
+    fn into_json(self) -> Result<Option<Value>, Error> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        serde_json::from_slice(&self)
+            .map(Some)
+            .map_err(error::request)
+    }
+}
+
+impl IndexCreateBody for String {
+    fn into_json(self) -> Result<Option<Value>, Error> {
+        self.into_bytes().into_json()
+    }
+}
+
+impl IndexCreateBody for Value {
+    fn into_json(self) -> Result<Option<Value>, Error> {
+        Ok(Some(self))
+    }
+}
+
+impl IndexCreateBody for IndexSettingsBody {
+    fn into_json(self) -> Result<Option<Value>, Error> {
+        self.body
+    }
+}
+
+#[doc(hidden)]
+pub struct IndexSettingsBody {
+    settings: IndexSettings,
+    body: Result<Option<Value>, Error>,
+}
+
 /**
 # Builder methods
 
@@ -172,6 +373,37 @@ where
     }
 }
 
+impl<TSender, TBody> IndexCreateRequestBuilder<TSender, TBody>
+where
+    TSender: Sender,
+    TBody: IndexCreateBody,
+{
+    /**
+    Set typed settings for the create index request.
+
+    The settings are merged into the `settings` of any JSON body set using [`body`](#method.body), so other parts of the body like `mappings` are kept.
+    Setting the same setting in both the body and the typed settings is an error, even if one uses nested objects or an `index.` prefix and the other doesn't.
+    Calling `settings` again replaces the typed settings.
+    The settings are checked for invalid combinations before the request is sent.
+    */
+    pub fn settings(
+        self,
+        settings: IndexSettings,
+    ) -> IndexCreateRequestBuilder<TSender, IndexSettingsBody> {
+        RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            IndexCreateRequestInner {
+                index: self.inner.index,
+                body: IndexSettingsBody {
+                    settings,
+                    body: self.inner.body.into_json(),
+                },
+            },
+        )
+    }
+}
+
 /**
 # Send synchronously
 */
@@ -256,11 +488,105 @@ where
     }
 }
 
+/**
+# Send synchronously with typed settings
+*/
+impl IndexCreateRequestBuilder<SyncSender, IndexSettingsBody> {
+    /**
+    Send an `IndexCreateRequestBuilder` with typed settings synchronously using a [`SyncClient`][SyncClient].
+
+    This will return an error without sending the request if the settings are invalid.
+
+    # Examples
+
+    Create an index called `myindex` with a single shard:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex")
+                         .create()
+                         .settings(IndexSettings::new().number_of_shards(1))
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse, Error> {
+        let req = self.inner.into_settings_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously with typed settings
+*/
+impl IndexCreateRequestBuilder<AsyncSender, IndexSettingsBody> {
+    /**
+    Send an `IndexCreateRequestBuilder` with typed settings asynchronously using an [`AsyncClient`][AsyncClient].
+
+    The returned future will resolve to an error without sending the request if the settings are invalid.
+
+    # Examples
+
+    Create an index called `myindex` with a single shard:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("myindex")
+                       .create()
+                       .settings(IndexSettings::new().number_of_shards(1))
+                       .send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client
+            .sender
+            .maybe_async(move || inner.into_settings_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
 /** A future returned by calling `send`. */
 pub type Pending = BasePending<CommandResponse>;
 
 #[cfg(test)]
 mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
     use crate::{
         prelude::*,
         tests::*,
@@ -293,4 +619,166 @@ mod tests {
 
         assert_eq!("{}", req.body);
     }
+
+    #[test]
+    fn specify_settings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .create()
+            .settings(
+                IndexSettings::new()
+                    .number_of_shards(1)
+                    .number_of_replicas(0),
+            )
+            .inner
+            .into_settings_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "settings": {
+                "number_of_shards": 1,
+                "number_of_replicas": 0
+            }
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/testindex", req.url.as_ref());
+        assert_eq!(expected_body, actual_body);
+    }
+
+    #[test]
+    fn specify_settings_and_mappings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let body = json!({
+            "settings": {
+                "index.codec": "best_compression"
+            },
+            "mappings": {
+                "properties": {
+                    "title": { "type": "text" }
+                }
+            }
+        });
+
+        let req = client
+            .index("testindex")
+            .create()
+            .body(body.to_string())
+            .settings(IndexSettings::new().number_of_replicas(2))
+            .settings(IndexSettings::new().number_of_shards(1))
+            .inner
+            .into_settings_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "settings": {
+                "index.codec": "best_compression",
+                "number_of_shards": 1
+            },
+            "mappings": {
+                "properties": {
+                    "title": { "type": "text" }
+                }
+            }
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body, actual_body);
+    }
+
+    #[test]
+    fn specify_json_body_and_settings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .create()
+            .body(json!({
+                "settings": {
+                    "analysis": {
+                        "analyzer": {
+                            "raw_analyzer": { "type": "keyword" }
+                        }
+                    }
+                }
+            }))
+            .settings(IndexSettings::new().analysis(
+                Analysis::new().analyzer("typed_analyzer", Analyzer::builtin("standard")),
+            ))
+            .inner
+            .into_settings_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "settings": {
+                "analysis": {
+                    "analyzer": {
+                        "raw_analyzer": { "type": "keyword" },
+                        "typed_analyzer": { "type": "standard" }
+                    }
+                }
+            }
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body, actual_body);
+    }
+
+    #[test]
+    fn overlapping_body_and_settings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let bodies = vec![
+            json!({ "settings": { "number_of_shards": 2 } }),
+            json!({ "settings": { "index.number_of_shards": 2 } }),
+            json!({ "settings": { "index": { "number_of_shards": 2 } } }),
+        ];
+
+        for body in bodies {
+            let req = client
+                .index("testindex")
+                .create()
+                .body(body)
+                .settings(IndexSettings::new().number_of_shards(1))
+                .inner
+                .into_settings_request();
+
+            assert!(req.is_err());
+        }
+    }
+
+    #[test]
+    fn invalid_body_with_settings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .create()
+            .body("not json")
+            .settings(IndexSettings::new().number_of_shards(1))
+            .inner
+            .into_settings_request();
+
+        assert!(req.is_err());
+    }
+
+    #[test]
+    fn invalid_settings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .create()
+            .settings(IndexSettings::new().number_of_shards(0))
+            .inner
+            .into_settings_request();
+
+        assert!(req.is_err());
+    }
 }
@@ -0,0 +1,211 @@
+/*!
+Builders for [delete alias requests][docs-aliases].
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::CommandResponse,
+        IndexClient,
+    },
+    endpoints::IndicesDeleteAliasRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Index,
+        Name,
+    },
+};
+
+/**
+A [delete alias request][docs-aliases] builder that can be configured before sending.
+
+Call [`Client.index.delete_alias`][Client.index.delete_alias] to get an `IndexDeleteAliasRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.delete_alias]: ../../struct.IndexClient.html#delete-alias-request
+*/
+pub type IndexDeleteAliasRequestBuilder<TSender> =
+    RequestBuilder<TSender, IndexDeleteAliasRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexDeleteAliasRequestInner {
+    index: Index<'static>,
+    alias: Name<'static>,
+}
+
+/**
+# Delete alias request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexDeleteAliasRequestBuilder`][IndexDeleteAliasRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Remove the alias called `myalias` from the `myindex` index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").delete_alias("myalias").send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [IndexDeleteAliasRequestBuilder]: requests/index_delete_alias/type.IndexDeleteAliasRequestBuilder.html
+    [send-sync]: requests/index_delete_alias/type.IndexDeleteAliasRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_delete_alias/type.IndexDeleteAliasRequestBuilder.html#send-asynchronously
+    */
+    pub fn delete_alias(
+        self,
+        alias: impl Into<Name<'static>>,
+    ) -> IndexDeleteAliasRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexDeleteAliasRequestInner {
+                index: self.index,
+                alias: alias.into(),
+            },
+        )
+    }
+}
+
+impl IndexDeleteAliasRequestInner {
+    fn into_request(self) -> IndicesDeleteAliasRequest<'static> {
+        IndicesDeleteAliasRequest::for_index_name(self.index, self.alias)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexDeleteAliasRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexDeleteAliasRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Remove the alias called `myalias` from the `myindex` index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").delete_alias("myalias").send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexDeleteAliasRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexDeleteAliasRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Remove the alias called `myalias` from the `myindex` index:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("myindex").delete_alias("myalias").send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<CommandResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .delete_alias("testalias")
+            .inner
+            .into_request();
+
+        assert_eq!("/testindex/_aliases/testalias", req.url.as_ref());
+    }
+}
@@ -0,0 +1,201 @@
+/*!
+Builders for [delete index template requests][docs-templates].
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::CommandResponse,
+        IndexTemplateClient,
+    },
+    endpoints::IndicesDeleteTemplateRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::Name,
+};
+
+/**
+A [delete index template request][docs-templates] builder that can be configured before sending.
+
+Call [`Client.index_template.delete`][Client.index_template.delete] to get an `IndexDeleteTemplateRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index_template.delete]: ../../struct.IndexTemplateClient.html#delete-index-template-request
+*/
+pub type IndexDeleteTemplateRequestBuilder<TSender> =
+    RequestBuilder<TSender, IndexDeleteTemplateRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexDeleteTemplateRequestInner {
+    name: Name<'static>,
+}
+
+/**
+# Delete index template request
+*/
+impl<TSender> IndexTemplateClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexDeleteTemplateRequestBuilder`][IndexDeleteTemplateRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Delete the template called `mytemplate`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template("mytemplate").delete().send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [IndexDeleteTemplateRequestBuilder]: requests/index_delete_template/type.IndexDeleteTemplateRequestBuilder.html
+    [send-sync]: requests/index_delete_template/type.IndexDeleteTemplateRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_delete_template/type.IndexDeleteTemplateRequestBuilder.html#send-asynchronously
+    */
+    pub fn delete(self) -> IndexDeleteTemplateRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexDeleteTemplateRequestInner { name: self.name },
+        )
+    }
+}
+
+impl IndexDeleteTemplateRequestInner {
+    fn into_request(self) -> IndicesDeleteTemplateRequest<'static> {
+        IndicesDeleteTemplateRequest::for_name(self.name)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexDeleteTemplateRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexDeleteTemplateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Delete the template called `mytemplate`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template("mytemplate").delete().send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexDeleteTemplateRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexDeleteTemplateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Delete the template called `mytemplate`:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index_template("mytemplate").delete().send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<CommandResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template("testtemplate")
+            .delete()
+            .inner
+            .into_request();
+
+        assert_eq!("/_template/testtemplate", req.url.as_ref());
+    }
+}
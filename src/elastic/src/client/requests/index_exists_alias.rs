@@ -0,0 +1,213 @@
+/*!
+Builders for [alias exists requests][docs-aliases].
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesExistsAliasResponse,
+        IndexClient,
+    },
+    endpoints::IndicesExistsAliasRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Index,
+        Name,
+    },
+};
+
+/**
+An [alias exists request][docs-aliases] builder that can be configured before sending.
+
+Call [`Client.index.alias_exists`][Client.index.alias_exists] to get an `IndexExistsAliasRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.alias_exists]: ../../struct.IndexClient.html#alias-exists-request
+*/
+pub type IndexExistsAliasRequestBuilder<TSender> =
+    RequestBuilder<TSender, IndexExistsAliasRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexExistsAliasRequestInner {
+    index: Index<'static>,
+    alias: Name<'static>,
+}
+
+/**
+# Alias exists request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexExistsAliasRequestBuilder`][IndexExistsAliasRequestBuilder] with this `Client` that can be configured before sending.
+
+    Use the `_all` index to check whether the alias exists for any index.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check whether the `myindex` index has an alias called `myalias`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").alias_exists("myalias").send()?;
+
+    assert!(response.exists());
+    # Ok(())
+    # }
+    ```
+
+    [IndexExistsAliasRequestBuilder]: requests/index_exists_alias/type.IndexExistsAliasRequestBuilder.html
+    [send-sync]: requests/index_exists_alias/type.IndexExistsAliasRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_exists_alias/type.IndexExistsAliasRequestBuilder.html#send-asynchronously
+    */
+    pub fn alias_exists(
+        self,
+        alias: impl Into<Name<'static>>,
+    ) -> IndexExistsAliasRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexExistsAliasRequestInner {
+                index: self.index,
+                alias: alias.into(),
+            },
+        )
+    }
+}
+
+impl IndexExistsAliasRequestInner {
+    fn into_request(self) -> IndicesExistsAliasRequest<'static> {
+        IndicesExistsAliasRequest::for_index_name(self.index, self.alias)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexExistsAliasRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexExistsAliasRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Check whether the `myindex` index has an alias called `myalias`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").alias_exists("myalias").send()?;
+
+    assert!(response.exists());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesExistsAliasResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexExistsAliasRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexExistsAliasRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised alias exists response.
+
+    # Examples
+
+    Check whether the `myindex` index has an alias called `myalias`:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("myindex").alias_exists("myalias").send();
+
+    future.and_then(|response| {
+        assert!(response.exists());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesExistsAliasResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .alias_exists("testalias")
+            .inner
+            .into_request();
+
+        assert_eq!("/testindex/_alias/testalias", req.url.as_ref());
+    }
+}
@@ -0,0 +1,201 @@
+/*!
+Builders for [index template exists requests][docs-templates].
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesExistsTemplateResponse,
+        IndexTemplateClient,
+    },
+    endpoints::IndicesExistsTemplateRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::Name,
+};
+
+/**
+An [index template exists request][docs-templates] builder that can be configured before sending.
+
+Call [`Client.index_template.exists`][Client.index_template.exists] to get an `IndexExistsTemplateRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index_template.exists]: ../../struct.IndexTemplateClient.html#index-template-exists-request
+*/
+pub type IndexExistsTemplateRequestBuilder<TSender> =
+    RequestBuilder<TSender, IndexExistsTemplateRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexExistsTemplateRequestInner {
+    name: Name<'static>,
+}
+
+/**
+# Index template exists request
+*/
+impl<TSender> IndexTemplateClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexExistsTemplateRequestBuilder`][IndexExistsTemplateRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check whether a template called `mytemplate` exists:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template("mytemplate").exists().send()?;
+
+    assert!(response.exists());
+    # Ok(())
+    # }
+    ```
+
+    [IndexExistsTemplateRequestBuilder]: requests/index_exists_template/type.IndexExistsTemplateRequestBuilder.html
+    [send-sync]: requests/index_exists_template/type.IndexExistsTemplateRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_exists_template/type.IndexExistsTemplateRequestBuilder.html#send-asynchronously
+    */
+    pub fn exists(self) -> IndexExistsTemplateRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexExistsTemplateRequestInner { name: self.name },
+        )
+    }
+}
+
+impl IndexExistsTemplateRequestInner {
+    fn into_request(self) -> IndicesExistsTemplateRequest<'static> {
+        IndicesExistsTemplateRequest::for_name(self.name)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexExistsTemplateRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexExistsTemplateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Check whether a template called `mytemplate` exists:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template("mytemplate").exists().send()?;
+
+    assert!(response.exists());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesExistsTemplateResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexExistsTemplateRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexExistsTemplateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised index template exists response.
+
+    # Examples
+
+    Check whether a template called `mytemplate` exists:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index_template("mytemplate").exists().send();
+
+    future.and_then(|response| {
+        assert!(response.exists());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesExistsTemplateResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template("testtemplate")
+            .exists()
+            .inner
+            .into_request();
+
+        assert_eq!("/_template/testtemplate", req.url.as_ref());
+    }
+}
@@ -0,0 +1,244 @@
+/*!
+Builders for [flush index requests][docs-flush].
+
+[docs-flush]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-flush.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesShardsResponse,
+        IndexClient,
+    },
+    endpoints::IndicesFlushRequest,
+    error::Error,
+    http::{
+        empty_body,
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+        DefaultBody,
+    },
+    params::Index,
+};
+
+/**
+A [flush index request][docs-flush] builder that can be configured before sending.
+
+Call [`Client.index.flush`][Client.index.flush] to get an `IndexFlushRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-flush]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-flush.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.flush]: ../../struct.IndexClient.html#flush-index-request
+*/
+pub type IndexFlushRequestBuilder<TSender> = RequestBuilder<TSender, IndexFlushRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexFlushRequestInner {
+    index: Index<'static>,
+}
+
+/**
+# Flush index request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexFlushRequestBuilder`][IndexFlushRequestBuilder] with this `Client` that can be configured before sending.
+
+    The index can be a comma-separated list of indices or contain wildcards.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Flush an index called `myindex`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").flush().send()?;
+
+    assert!(response.all_successful());
+    # Ok(())
+    # }
+    ```
+
+    [IndexFlushRequestBuilder]: requests/index_flush/type.IndexFlushRequestBuilder.html
+    [builder-methods]: requests/index_flush/type.IndexFlushRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_flush/type.IndexFlushRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_flush/type.IndexFlushRequestBuilder.html#send-asynchronously
+    */
+    pub fn flush(self) -> IndexFlushRequestBuilder<TSender> {
+        RequestBuilder::initial(self.inner, IndexFlushRequestInner { index: self.index })
+    }
+}
+
+impl IndexFlushRequestInner {
+    fn into_request(self) -> IndicesFlushRequest<'static, DefaultBody> {
+        IndicesFlushRequest::for_index(self.index, empty_body())
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexFlushRequestBuilder` before sending it.
+*/
+impl<TSender> IndexFlushRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /** Flush even if there are no changes to commit. */
+    pub fn force(self) -> Self {
+        self.params_fluent(|params| params.url_param("force", true))
+    }
+
+    /**
+    Wait for any ongoing flush to finish instead of returning immediately.
+
+    By default a flush will fail if another one is already running.
+    */
+    pub fn wait_if_ongoing(self) -> Self {
+        self.params_fluent(|params| params.url_param("wait_if_ongoing", true))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexFlushRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexFlushRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Flush an index called `myindex`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").flush().send()?;
+
+    assert!(response.all_successful());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesShardsResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexFlushRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexFlushRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised shards response.
+
+    # Examples
+
+    Flush an index called `myindex`:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("myindex").flush().send();
+
+    future.and_then(|response| {
+        assert!(response.all_successful());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesShardsResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.index("testindex").flush().inner.into_request();
+
+        assert_eq!("/testindex/_flush", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_force_and_wait_if_ongoing() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .index("testindex")
+            .flush()
+            .force()
+            .wait_if_ongoing()
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("force=true"));
+        assert!(qry.contains("wait_if_ongoing=true"));
+    }
+}
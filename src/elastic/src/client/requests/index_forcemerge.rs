@@ -0,0 +1,245 @@
+/*!
+Builders for [force merge index requests][docs-forcemerge].
+
+[docs-forcemerge]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-forcemerge.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesShardsResponse,
+        IndexClient,
+    },
+    endpoints::IndicesForcemergeRequest,
+    error::Error,
+    http::{
+        empty_body,
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+        DefaultBody,
+    },
+    params::Index,
+};
+
+/**
+A [force merge index request][docs-forcemerge] builder that can be configured before sending.
+
+Call [`Client.index.forcemerge`][Client.index.forcemerge] to get an `IndexForceMergeRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-forcemerge]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-forcemerge.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.forcemerge]: ../../struct.IndexClient.html#force-merge-index-request
+*/
+pub type IndexForceMergeRequestBuilder<TSender> =
+    RequestBuilder<TSender, IndexForceMergeRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexForceMergeRequestInner {
+    index: Index<'static>,
+}
+
+/**
+# Force merge index request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexForceMergeRequestBuilder`][IndexForceMergeRequestBuilder] with this `Client` that can be configured before sending.
+
+    The index can be a comma-separated list of indices or contain wildcards.
+    Each shard will be merged down to at most `max_num_segments` segments.
+    Force merging can use a lot of resources so it should only be used on indices that are no longer written to.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Merge an index called `myindex` down to a single segment per shard:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").forcemerge(1).send()?;
+
+    assert!(response.all_successful());
+    # Ok(())
+    # }
+    ```
+
+    [IndexForceMergeRequestBuilder]: requests/index_forcemerge/type.IndexForceMergeRequestBuilder.html
+    [builder-methods]: requests/index_forcemerge/type.IndexForceMergeRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_forcemerge/type.IndexForceMergeRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_forcemerge/type.IndexForceMergeRequestBuilder.html#send-asynchronously
+    */
+    pub fn forcemerge(self, max_num_segments: u32) -> IndexForceMergeRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexForceMergeRequestInner { index: self.index },
+        )
+        .params_fluent(move |params| params.url_param("max_num_segments", max_num_segments))
+    }
+}
+
+impl IndexForceMergeRequestInner {
+    fn into_request(self) -> IndicesForcemergeRequest<'static, DefaultBody> {
+        IndicesForcemergeRequest::for_index(self.index, empty_body())
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexForceMergeRequestBuilder` before sending it.
+*/
+impl<TSender> IndexForceMergeRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set whether or not to flush the index after merging.
+
+    The index is flushed by default.
+    */
+    pub fn flush(self, flush: bool) -> Self {
+        self.params_fluent(move |params| params.url_param("flush", flush))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexForceMergeRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexForceMergeRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Merge an index called `myindex` down to a single segment per shard:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").forcemerge(1).send()?;
+
+    assert!(response.all_successful());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesShardsResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexForceMergeRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexForceMergeRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised shards response.
+
+    # Examples
+
+    Merge an index called `myindex` down to a single segment per shard:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("myindex").forcemerge(1).send();
+
+    future.and_then(|response| {
+        assert!(response.all_successful());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesShardsResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.index("testindex").forcemerge(1).inner.into_request();
+
+        assert_eq!("/testindex/_forcemerge", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_flush() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .index("testindex")
+            .forcemerge(1)
+            .flush(false)
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("max_num_segments=1"));
+        assert!(qry.contains("flush=false"));
+    }
+}
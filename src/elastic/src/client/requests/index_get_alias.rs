@@ -0,0 +1,252 @@
+/*!
+Builders for [get alias requests][docs-aliases].
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesGetAliasResponse,
+        IndexClient,
+    },
+    endpoints::IndicesGetAliasRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Index,
+        Name,
+    },
+};
+
+/**
+A [get alias request][docs-aliases] builder that can be configured before sending.
+
+Call [`Client.index.get_aliases`][Client.index.get_aliases] to get an `IndexGetAliasRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.get_aliases]: ../../struct.IndexClient.html#get-alias-request
+*/
+pub type IndexGetAliasRequestBuilder<TSender> = RequestBuilder<TSender, IndexGetAliasRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexGetAliasRequestInner {
+    index: Index<'static>,
+    alias: Option<Name<'static>>,
+}
+
+/**
+# Get alias request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexGetAliasRequestBuilder`][IndexGetAliasRequestBuilder] with this `Client` that can be configured before sending.
+
+    Use the `_all` index to get aliases for every index.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the aliases for the `myindex` index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").get_aliases().send()?;
+
+    for (index, aliases) in response.iter() {
+        for (alias, _) in aliases.iter() {
+            println!("{} -> {}", alias, index);
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [IndexGetAliasRequestBuilder]: requests/index_get_alias/type.IndexGetAliasRequestBuilder.html
+    [builder-methods]: requests/index_get_alias/type.IndexGetAliasRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_get_alias/type.IndexGetAliasRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_get_alias/type.IndexGetAliasRequestBuilder.html#send-asynchronously
+    */
+    pub fn get_aliases(self) -> IndexGetAliasRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexGetAliasRequestInner {
+                index: self.index,
+                alias: None,
+            },
+        )
+    }
+}
+
+impl IndexGetAliasRequestInner {
+    fn into_request(self) -> IndicesGetAliasRequest<'static> {
+        match self.alias {
+            Some(alias) => IndicesGetAliasRequest::for_index_name(self.index, alias),
+            None => IndicesGetAliasRequest::for_index(self.index),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexGetAliasRequestBuilder` before sending it.
+*/
+impl<TSender> IndexGetAliasRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Only get aliases with the given name.
+
+    The name can be a comma-separated list or contain wildcards.
+    */
+    pub fn alias(mut self, alias: impl Into<Name<'static>>) -> Self {
+        self.inner.alias = Some(alias.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexGetAliasRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexGetAliasRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the indices that the `myalias` alias points to:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("_all").get_aliases().alias("myalias").send()?;
+
+    for index in response.indices_for_alias("myalias") {
+        println!("{}", index);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesGetAliasResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexGetAliasRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexGetAliasRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get alias response.
+
+    # Examples
+
+    Get the indices that the `myalias` alias points to:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("_all").get_aliases().alias("myalias").send();
+
+    future.and_then(|response| {
+        for index in response.indices_for_alias("myalias") {
+            println!("{}", index);
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesGetAliasResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.index("testindex").get_aliases().inner.into_request();
+
+        assert_eq!("/testindex/_alias", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_alias() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .get_aliases()
+            .alias("testalias")
+            .inner
+            .into_request();
+
+        assert_eq!("/testindex/_alias/testalias", req.url.as_ref());
+    }
+}
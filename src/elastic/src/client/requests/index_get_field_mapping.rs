@@ -0,0 +1,239 @@
+/*!
+Builders for [get field mapping requests][docs-get-field-mapping].
+
+[docs-get-field-mapping]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-field-mapping.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesGetFieldMappingResponse,
+        IndexClient,
+    },
+    endpoints::IndicesGetFieldMappingRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Fields,
+        Index,
+    },
+};
+
+/**
+A [get field mapping request][docs-get-field-mapping] builder that can be configured before sending.
+
+Call [`Client.index.get_field_mapping`][Client.index.get_field_mapping] to get an `IndexGetFieldMappingRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-get-field-mapping]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-field-mapping.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.get_field_mapping]: ../../struct.IndexClient.html#get-field-mapping-request
+*/
+pub type IndexGetFieldMappingRequestBuilder<TSender> =
+    RequestBuilder<TSender, IndexGetFieldMappingRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexGetFieldMappingRequestInner {
+    index: Index<'static>,
+    fields: Fields<'static>,
+}
+
+/**
+# Get field mapping request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexGetFieldMappingRequestBuilder`][IndexGetFieldMappingRequestBuilder] with this `Client` that can be configured before sending.
+
+    The fields are full names, like `title.keyword`, and can be a comma-separated list or contain wildcards.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the mapping for the `title` field in the `myindex` index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").get_field_mapping("title").send()?;
+
+    if let Some(field) = response.field("myindex", "title") {
+        println!("{}", field.ty());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [IndexGetFieldMappingRequestBuilder]: requests/index_get_field_mapping/type.IndexGetFieldMappingRequestBuilder.html
+    [builder-methods]: requests/index_get_field_mapping/type.IndexGetFieldMappingRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_get_field_mapping/type.IndexGetFieldMappingRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_get_field_mapping/type.IndexGetFieldMappingRequestBuilder.html#send-asynchronously
+    */
+    pub fn get_field_mapping(
+        self,
+        fields: impl Into<Fields<'static>>,
+    ) -> IndexGetFieldMappingRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexGetFieldMappingRequestInner {
+                index: self.index,
+                fields: fields.into(),
+            },
+        )
+    }
+}
+
+impl IndexGetFieldMappingRequestInner {
+    fn into_request(self) -> IndicesGetFieldMappingRequest<'static> {
+        IndicesGetFieldMappingRequest::for_index_fields(self.index, self.fields)
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexGetFieldMappingRequestBuilder` before sending it.
+*/
+impl<TSender> IndexGetFieldMappingRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /** Include default values for mapping parameters that weren't set explicitly. */
+    pub fn include_defaults(self) -> Self {
+        self.params_fluent(|params| params.url_param("include_defaults", true))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexGetFieldMappingRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexGetFieldMappingRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the mapping for every multi-field of `title` in every index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("_all").get_field_mapping("title.*").send()?;
+
+    for (index, name, field) in response.iter() {
+        println!("{}: {} {}", index, name, field.ty());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesGetFieldMappingResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexGetFieldMappingRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexGetFieldMappingRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get field mapping response.
+
+    # Examples
+
+    Get the mapping for every multi-field of `title` in every index:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("_all").get_field_mapping("title.*").send();
+
+    future.and_then(|response| {
+        for (index, name, field) in response.iter() {
+            println!("{}: {} {}", index, name, field.ty());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesGetFieldMappingResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .get_field_mapping("title,title.keyword")
+            .inner
+            .into_request();
+
+        assert_eq!(
+            "/testindex/_mapping/field/title,title.keyword",
+            req.url.as_ref()
+        );
+    }
+}
@@ -0,0 +1,208 @@
+/*!
+Builders for [get mapping requests][docs-get-mapping].
+
+[docs-get-mapping]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesGetMappingResponse,
+        IndexClient,
+    },
+    endpoints::IndicesGetMappingRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::Index,
+};
+
+/**
+A [get mapping request][docs-get-mapping] builder that can be configured before sending.
+
+Call [`Client.index.get_mapping`][Client.index.get_mapping] to get an `IndexGetMappingRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-get-mapping]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.get_mapping]: ../../struct.IndexClient.html#get-mapping-request
+*/
+pub type IndexGetMappingRequestBuilder<TSender> =
+    RequestBuilder<TSender, IndexGetMappingRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexGetMappingRequestInner {
+    index: Index<'static>,
+}
+
+/**
+# Get mapping request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexGetMappingRequestBuilder`][IndexGetMappingRequestBuilder] with this `Client` that can be configured before sending.
+
+    The index can be a comma-separated list of indices or contain wildcards.
+    Use the `_all` index to get the mapping for every index.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the mapping for the `myindex` index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").get_mapping().send()?;
+
+    if let Some(mapping) = response.index("myindex") {
+        for (name, field) in mapping.fields() {
+            println!("{}: {}", name, field.ty());
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [IndexGetMappingRequestBuilder]: requests/index_get_mapping/type.IndexGetMappingRequestBuilder.html
+    [send-sync]: requests/index_get_mapping/type.IndexGetMappingRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_get_mapping/type.IndexGetMappingRequestBuilder.html#send-asynchronously
+    */
+    pub fn get_mapping(self) -> IndexGetMappingRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexGetMappingRequestInner { index: self.index },
+        )
+    }
+}
+
+impl IndexGetMappingRequestInner {
+    fn into_request(self) -> IndicesGetMappingRequest<'static> {
+        IndicesGetMappingRequest::for_index(self.index)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexGetMappingRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexGetMappingRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the datatype of the `title` field in every index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("_all").get_mapping().send()?;
+
+    for (index, mapping) in response.iter() {
+        println!("{}: {:?}", index, mapping.field("title").map(|field| field.ty()));
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesGetMappingResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexGetMappingRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexGetMappingRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get mapping response.
+
+    # Examples
+
+    Get the datatype of the `title` field in every index:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("_all").get_mapping().send();
+
+    future.and_then(|response| {
+        for (index, mapping) in response.iter() {
+            println!("{}: {:?}", index, mapping.field("title").map(|field| field.ty()));
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesGetMappingResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.index("testindex").get_mapping().inner.into_request();
+
+        assert_eq!("/testindex/_mapping", req.url.as_ref());
+    }
+}
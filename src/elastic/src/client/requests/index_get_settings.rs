@@ -0,0 +1,256 @@
+/*!
+Builders for [get index settings requests][docs-get-settings].
+
+[docs-get-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-settings.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesGetSettingsResponse,
+        IndexClient,
+    },
+    endpoints::IndicesGetSettingsRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Index,
+        Name,
+    },
+};
+
+/**
+A [get index settings request][docs-get-settings] builder that can be configured before sending.
+
+Call [`Client.index.get_settings`][Client.index.get_settings] to get an `IndexGetSettingsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-get-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-settings.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.get_settings]: ../../struct.IndexClient.html#get-index-settings-request
+*/
+pub type IndexGetSettingsRequestBuilder<TSender> =
+    RequestBuilder<TSender, IndexGetSettingsRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexGetSettingsRequestInner {
+    index: Index<'static>,
+    name: Option<Name<'static>>,
+}
+
+/**
+# Get index settings request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexGetSettingsRequestBuilder`][IndexGetSettingsRequestBuilder] with this `Client` that can be configured before sending.
+
+    Use the `_all` index to get settings for every index.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the settings for the `myindex` index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").get_settings().send()?;
+
+    if let Some(settings) = response.index("myindex") {
+        println!("{:?}", settings.get_number_of_shards());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [IndexGetSettingsRequestBuilder]: requests/index_get_settings/type.IndexGetSettingsRequestBuilder.html
+    [builder-methods]: requests/index_get_settings/type.IndexGetSettingsRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_get_settings/type.IndexGetSettingsRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_get_settings/type.IndexGetSettingsRequestBuilder.html#send-asynchronously
+    */
+    pub fn get_settings(self) -> IndexGetSettingsRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexGetSettingsRequestInner {
+                index: self.index,
+                name: None,
+            },
+        )
+    }
+}
+
+impl IndexGetSettingsRequestInner {
+    fn into_request(self) -> IndicesGetSettingsRequest<'static> {
+        match self.name {
+            Some(name) => IndicesGetSettingsRequest::for_index_name(self.index, name),
+            None => IndicesGetSettingsRequest::for_index(self.index),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexGetSettingsRequestBuilder` before sending it.
+*/
+impl<TSender> IndexGetSettingsRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Only get settings with the given name, like `index.number_of_*`.
+
+    The name can be a comma-separated list or contain wildcards.
+    Settings are always returned under the `index` namespace, so names should be prefixed with `index.`.
+    */
+    pub fn name(mut self, name: impl Into<Name<'static>>) -> Self {
+        self.inner.name = Some(name.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexGetSettingsRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexGetSettingsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the refresh interval for every index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("_all").get_settings().send()?;
+
+    for (index, settings) in response.iter() {
+        println!("{}: {:?}", index, settings.get_refresh_interval());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesGetSettingsResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexGetSettingsRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexGetSettingsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get index settings response.
+
+    # Examples
+
+    Get the refresh interval for every index:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("_all").get_settings().send();
+
+    future.and_then(|response| {
+        for (index, settings) in response.iter() {
+            println!("{}: {:?}", index, settings.get_refresh_interval());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesGetSettingsResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .get_settings()
+            .inner
+            .into_request();
+
+        assert_eq!("/testindex/_settings", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_name() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .get_settings()
+            .name("index.number_of_*")
+            .inner
+            .into_request();
+
+        assert_eq!("/testindex/_settings/index.number_of_*", req.url.as_ref());
+    }
+}
@@ -0,0 +1,208 @@
+/*!
+Builders for [get index template requests][docs-templates].
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesGetTemplateResponse,
+        IndexTemplateClient,
+    },
+    endpoints::IndicesGetTemplateRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::Name,
+};
+
+/**
+A [get index template request][docs-templates] builder that can be configured before sending.
+
+Call [`Client.index_template.get`][Client.index_template.get] to get an `IndexGetTemplateRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index_template.get]: ../../struct.IndexTemplateClient.html#get-index-template-request
+*/
+pub type IndexGetTemplateRequestBuilder<TSender> =
+    RequestBuilder<TSender, IndexGetTemplateRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexGetTemplateRequestInner {
+    name: Name<'static>,
+}
+
+/**
+# Get index template request
+*/
+impl<TSender> IndexTemplateClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexGetTemplateRequestBuilder`][IndexGetTemplateRequestBuilder] with this `Client` that can be configured before sending.
+
+    The template name can be a comma-separated list or contain wildcards.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the template called `mytemplate`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template("mytemplate").get().send()?;
+
+    if let Some(template) = response.template("mytemplate") {
+        for pattern in template.index_patterns() {
+            println!("{}", pattern);
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [IndexGetTemplateRequestBuilder]: requests/index_get_template/type.IndexGetTemplateRequestBuilder.html
+    [send-sync]: requests/index_get_template/type.IndexGetTemplateRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_get_template/type.IndexGetTemplateRequestBuilder.html#send-asynchronously
+    */
+    pub fn get(self) -> IndexGetTemplateRequestBuilder<TSender> {
+        RequestBuilder::initial(self.inner, IndexGetTemplateRequestInner { name: self.name })
+    }
+}
+
+impl IndexGetTemplateRequestInner {
+    fn into_request(self) -> IndicesGetTemplateRequest<'static> {
+        IndicesGetTemplateRequest::for_name(self.name)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexGetTemplateRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexGetTemplateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get all templates with a name starting with `my`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template("my*").get().send()?;
+
+    for (name, template) in response.iter() {
+        println!("{}: {}", name, template.order());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesGetTemplateResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexGetTemplateRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexGetTemplateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get index template response.
+
+    # Examples
+
+    Get all templates with a name starting with `my`:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index_template("my*").get().send();
+
+    future.and_then(|response| {
+        for (name, template) in response.iter() {
+            println!("{}: {}", name, template.order());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesGetTemplateResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template("testtemplate")
+            .get()
+            .inner
+            .into_request();
+
+        assert_eq!("/_template/testtemplate", req.url.as_ref());
+    }
+}
@@ -0,0 +1,313 @@
+/*!
+Builders for [put alias requests][docs-aliases].
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+*/
+
+use futures::Future;
+use serde_json::{
+    self,
+    Value,
+};
+
+use crate::{
+    client::{
+        requests::{
+            index_update_aliases::AliasBuilder,
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::CommandResponse,
+        IndexClient,
+    },
+    endpoints::IndicesPutAliasRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Index,
+        Name,
+    },
+};
+
+/**
+A [put alias request][docs-aliases] builder that can be configured before sending.
+
+Call [`Client.index.put_alias`][Client.index.put_alias] to get an `IndexPutAliasRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.put_alias]: ../../struct.IndexClient.html#put-alias-request
+*/
+pub type IndexPutAliasRequestBuilder<TSender> = RequestBuilder<TSender, IndexPutAliasRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexPutAliasRequestInner {
+    index: Index<'static>,
+    alias: Name<'static>,
+    body: AliasBuilder,
+}
+
+/**
+# Put alias request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexPutAliasRequestBuilder`][IndexPutAliasRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Add an alias called `myalias` for the `myindex` index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").put_alias("myalias").send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [IndexPutAliasRequestBuilder]: requests/index_put_alias/type.IndexPutAliasRequestBuilder.html
+    [builder-methods]: requests/index_put_alias/type.IndexPutAliasRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_put_alias/type.IndexPutAliasRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_put_alias/type.IndexPutAliasRequestBuilder.html#send-asynchronously
+    */
+    pub fn put_alias(
+        self,
+        alias: impl Into<Name<'static>>,
+    ) -> IndexPutAliasRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexPutAliasRequestInner {
+                index: self.index,
+                alias: alias.into(),
+                body: AliasBuilder::new(),
+            },
+        )
+    }
+}
+
+impl IndexPutAliasRequestInner {
+    fn into_request(self) -> Result<IndicesPutAliasRequest<'static, Vec<u8>>, Error> {
+        let body = serde_json::to_vec(&self.body).map_err(error::request)?;
+
+        Ok(IndicesPutAliasRequest::for_index_name(
+            self.index, self.alias, body,
+        ))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexPutAliasRequestBuilder` before sending it.
+*/
+impl<TSender> IndexPutAliasRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set a query filter for the alias.
+
+    Searches and counts through the alias will only see documents that match the filter.
+    */
+    pub fn filter(mut self, filter: impl Into<Value>) -> Self {
+        self.inner.body = self.inner.body.filter(filter);
+        self
+    }
+
+    /** Set the routing value used for both indexing and searching through the alias. */
+    pub fn routing(mut self, routing: impl ToString) -> Self {
+        self.inner.body = self.inner.body.routing(routing);
+        self
+    }
+
+    /** Set the routing value used for indexing through the alias. */
+    pub fn index_routing(mut self, routing: impl ToString) -> Self {
+        self.inner.body = self.inner.body.index_routing(routing);
+        self
+    }
+
+    /** Set the routing value used for searching through the alias. */
+    pub fn search_routing(mut self, routing: impl ToString) -> Self {
+        self.inner.body = self.inner.body.search_routing(routing);
+        self
+    }
+
+    /**
+    Set whether the index is the write index for the alias.
+
+    Indexing requests sent to an alias that points to multiple indices will go to its write index.
+    */
+    pub fn is_write_index(mut self, is_write_index: bool) -> Self {
+        self.inner.body = self.inner.body.is_write_index(is_write_index);
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexPutAliasRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexPutAliasRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Add an alias called `myalias` for the `myindex` index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").put_alias("myalias").send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexPutAliasRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexPutAliasRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Add an alias called `myalias` for the `myindex` index:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("myindex").put_alias("myalias").send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<CommandResponse>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .put_alias("testalias")
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/testindex/_aliases/testalias", req.url.as_ref());
+        assert_eq!(json!({}), actual_body);
+    }
+
+    #[test]
+    fn specify_body() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .put_alias("testalias")
+            .filter(json!({ "term": { "user": "kimchy" } }))
+            .index_routing("1")
+            .search_routing("2")
+            .is_write_index(false)
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "filter": { "term": { "user": "kimchy" } },
+            "index_routing": "1",
+            "search_routing": "2",
+            "is_write_index": false
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body, actual_body);
+    }
+}
@@ -0,0 +1,609 @@
+/*!
+Builders for [put index template requests][docs-templates].
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+*/
+
+use futures::Future;
+use serde_json::{
+    self,
+    Map,
+    Value,
+};
+
+use crate::{
+    client::{
+        requests::{
+            index_settings::IndexSettings,
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::CommandResponse,
+        IndexTemplateClient,
+    },
+    endpoints::IndicesPutTemplateRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::Name,
+    types::document::DocumentType,
+};
+
+/**
+A [put index template request][docs-templates] builder that can be configured before sending.
+
+Call [`Client.index_template.put`][Client.index_template.put] to get an `IndexPutTemplateRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index_template.put]: ../../struct.IndexTemplateClient.html#put-index-template-request
+*/
+pub type IndexPutTemplateRequestBuilder<TSender> =
+    RequestBuilder<TSender, IndexPutTemplateRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexPutTemplateRequestInner {
+    name: Name<'static>,
+    index_patterns: Vec<String>,
+    order: Option<i32>,
+    version: Option<i32>,
+    settings: Option<SettingsSource>,
+    mappings: Vec<MappingSource>,
+}
+
+enum SettingsSource {
+    Typed(IndexSettings),
+    Raw(Value),
+}
+
+enum MappingSource {
+    Document(fn() -> Result<Value, serde_json::Error>),
+    Raw(Value),
+}
+
+fn document_mapping<TDocument>() -> Result<Value, serde_json::Error>
+where
+    TDocument: DocumentType,
+{
+    serde_json::to_value(TDocument::index_mapping())
+}
+
+/**
+Merge the `source` mapping into `target`.
+
+Objects are merged recursively so properties from multiple document types are combined.
+Any other values must be the same in both mappings, so a field can't be mapped with different types.
+*/
+fn merge_mapping(
+    path: &str,
+    target: &mut Map<String, Value>,
+    source: Map<String, Value>,
+) -> Result<(), Error> {
+    for (key, value) in source {
+        let path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", path, key)
+        };
+
+        match (target.get_mut(&key), value) {
+            (Some(Value::Object(target)), Value::Object(source)) => {
+                merge_mapping(&path, target, source)?
+            }
+            (Some(target), value) => {
+                if *target != value {
+                    return Err(error::request(error::message(format!(
+                        "conflicting values for `{}` in the index template mappings: `{}` and `{}`",
+                        path, target, value
+                    ))));
+                }
+            }
+            (None, value) => {
+                target.insert(key, value);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/**
+# Put index template request
+*/
+impl<TSender> IndexTemplateClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexPutTemplateRequestBuilder`][IndexPutTemplateRequestBuilder] with this `Client` that can be configured before sending.
+
+    At least one index pattern must be given using [`index_patterns`][index_patterns].
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Create a template for daily indices with the mapping for a [`DocumentType`][documents-mod] called `MyType`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # #[macro_use] extern crate serde_json;
+    # use elastic::prelude::*;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template("mytemplate")
+                         .put()
+                         .index_patterns(vec!["myindex-*"])
+                         .settings(IndexSettings::new().number_of_shards(1))
+                         .mapping::<MyType>()
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [IndexPutTemplateRequestBuilder]: requests/index_put_template/type.IndexPutTemplateRequestBuilder.html
+    [builder-methods]: requests/index_put_template/type.IndexPutTemplateRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_put_template/type.IndexPutTemplateRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_put_template/type.IndexPutTemplateRequestBuilder.html#send-asynchronously
+    [index_patterns]: requests/index_put_template/type.IndexPutTemplateRequestBuilder.html#method.index_patterns
+    [documents-mod]: ../types/document/index.html
+    */
+    pub fn put(self) -> IndexPutTemplateRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexPutTemplateRequestInner {
+                name: self.name,
+                index_patterns: Vec::new(),
+                order: None,
+                version: None,
+                settings: None,
+                mappings: Vec::new(),
+            },
+        )
+    }
+}
+
+impl IndexPutTemplateRequestInner {
+    fn into_request(self) -> Result<IndicesPutTemplateRequest<'static, Vec<u8>>, Error> {
+        #[derive(Serialize)]
+        struct Body {
+            index_patterns: Vec<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            order: Option<i32>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            version: Option<i32>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            settings: Option<Value>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            mappings: Option<Map<String, Value>>,
+        }
+
+        if self.index_patterns.is_empty() {
+            return Err(error::request(error::message(
+                "an index template needs at least one index pattern",
+            )));
+        }
+
+        let settings = match self.settings {
+            Some(SettingsSource::Typed(settings)) => {
+                settings.validate()?;
+
+                Some(serde_json::to_value(&settings).map_err(error::request)?)
+            }
+            Some(SettingsSource::Raw(settings)) => Some(settings),
+            None => None,
+        };
+
+        let mut mappings = None;
+        for mapping in self.mappings {
+            let mapping = match mapping {
+                MappingSource::Document(mapping) => mapping().map_err(error::request)?,
+                MappingSource::Raw(mapping) => mapping,
+            };
+
+            match mapping {
+                Value::Object(mapping) => {
+                    merge_mapping("", mappings.get_or_insert_with(Map::new), mapping)?
+                }
+                _ => {
+                    return Err(error::request(error::message(
+                        "an index template mapping must be an object",
+                    )))
+                }
+            }
+        }
+
+        let body = serde_json::to_vec(&Body {
+            index_patterns: self.index_patterns,
+            order: self.order,
+            version: self.version,
+            settings,
+            mappings,
+        })
+        .map_err(error::request)?;
+
+        Ok(IndicesPutTemplateRequest::for_name(self.name, body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexPutTemplateRequestBuilder` before sending it.
+*/
+impl<TSender> IndexPutTemplateRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the index patterns the template applies to.
+
+    Patterns can contain wildcards, like `logs-*`.
+    */
+    pub fn index_patterns<I>(mut self, index_patterns: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.inner.index_patterns = index_patterns
+            .into_iter()
+            .map(|pattern| pattern.to_string())
+            .collect();
+        self
+    }
+
+    /**
+    Set the order of the template.
+
+    When multiple templates match an index, templates with a higher order are merged over ones with a lower order.
+    */
+    pub fn order(mut self, order: i32) -> Self {
+        self.inner.order = Some(order);
+        self
+    }
+
+    /** Set a version number for the template. */
+    pub fn version(mut self, version: i32) -> Self {
+        self.inner.version = Some(version);
+        self
+    }
+
+    /**
+    Set typed index settings for indices created from the template.
+
+    The settings are checked for invalid combinations before the request is sent.
+    Calling `settings` or [`settings_raw`](#method.settings_raw) again replaces the settings.
+    */
+    pub fn settings(mut self, settings: IndexSettings) -> Self {
+        self.inner.settings = Some(SettingsSource::Typed(settings));
+        self
+    }
+
+    /**
+    Set raw index settings for indices created from the template.
+
+    The settings are sent as they are, so they can include settings that aren't supported by [`IndexSettings`][IndexSettings].
+
+    [IndexSettings]: ../index_settings/struct.IndexSettings.html
+    */
+    pub fn settings_raw(mut self, settings: impl Into<Value>) -> Self {
+        self.inner.settings = Some(SettingsSource::Raw(settings.into()));
+        self
+    }
+
+    /**
+    Add the mapping for a document type to the template.
+
+    This method can be called multiple times to combine the mappings for several document types.
+    Sending the request will fail if the document types map the same field differently.
+    */
+    pub fn mapping<TDocument>(mut self) -> Self
+    where
+        TDocument: DocumentType,
+    {
+        self.inner
+            .mappings
+            .push(MappingSource::Document(document_mapping::<TDocument>));
+        self
+    }
+
+    /**
+    Add a raw mapping to the template.
+
+    The mapping is combined with any other mappings added to the template.
+    */
+    pub fn mapping_raw(mut self, mapping: impl Into<Value>) -> Self {
+        self.inner.mappings.push(MappingSource::Raw(mapping.into()));
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexPutTemplateRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexPutTemplateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Create a template called `mytemplate` for indices matching `myindex-*`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index_template("mytemplate")
+                         .put()
+                         .index_patterns(vec!["myindex-*"])
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexPutTemplateRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexPutTemplateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Create a template called `mytemplate` for indices matching `myindex-*`:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index_template("mytemplate")
+                       .put()
+                       .index_patterns(vec!["myindex-*"])
+                       .send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<CommandResponse>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[derive(ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    #[allow(dead_code)]
+    struct FirstDoc {
+        id: i32,
+    }
+
+    #[derive(ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    #[allow(dead_code)]
+    struct SecondDoc {
+        title: String,
+    }
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template("testtemplate")
+            .put()
+            .index_patterns(vec!["test-*"])
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/_template/testtemplate", req.url.as_ref());
+        assert_eq!(json!({ "index_patterns": ["test-*"] }), actual_body);
+    }
+
+    #[test]
+    fn missing_index_patterns() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template("testtemplate")
+            .put()
+            .inner
+            .into_request();
+
+        assert!(req.is_err());
+    }
+
+    #[test]
+    fn specify_body() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template("testtemplate")
+            .put()
+            .index_patterns(vec!["test-*", "other-*"])
+            .order(1)
+            .version(3)
+            .settings(IndexSettings::new().number_of_shards(1))
+            .mapping::<FirstDoc>()
+            .mapping::<SecondDoc>()
+            .mapping_raw(json!({ "_source": { "enabled": false } }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "index_patterns": ["test-*", "other-*"],
+            "order": 1,
+            "version": 3,
+            "settings": { "number_of_shards": 1 },
+            "mappings": {
+                "properties": {
+                    "id": { "type": "integer" },
+                    "title": {
+                        "type": "text",
+                        "fields": {
+                            "keyword": { "type": "keyword", "ignore_above": 256 }
+                        }
+                    }
+                },
+                "_source": { "enabled": false }
+            }
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body, actual_body);
+    }
+
+    #[test]
+    fn specify_raw_settings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template("testtemplate")
+            .put()
+            .index_patterns(vec!["test-*"])
+            .settings_raw(json!({ "index.lifecycle.name": "test-policy" }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "index_patterns": ["test-*"],
+            "settings": { "index.lifecycle.name": "test-policy" }
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body, actual_body);
+    }
+
+    #[test]
+    fn invalid_settings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template("testtemplate")
+            .put()
+            .index_patterns(vec!["test-*"])
+            .settings(IndexSettings::new().number_of_shards(0))
+            .inner
+            .into_request();
+
+        assert!(req.is_err());
+    }
+
+    #[derive(ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    #[allow(dead_code)]
+    struct ConflictingDoc {
+        id: String,
+    }
+
+    #[test]
+    fn conflicting_mappings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template("testtemplate")
+            .put()
+            .index_patterns(vec!["test-*"])
+            .mapping::<FirstDoc>()
+            .mapping::<ConflictingDoc>()
+            .inner
+            .into_request();
+
+        assert!(req.is_err());
+    }
+
+    #[test]
+    fn same_mappings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index_template("testtemplate")
+            .put()
+            .index_patterns(vec!["test-*"])
+            .mapping::<FirstDoc>()
+            .mapping_raw(json!({ "properties": { "id": { "type": "integer" } } }))
+            .inner
+            .into_request();
+
+        assert!(req.is_ok());
+    }
+}
@@ -0,0 +1,199 @@
+/*!
+Builders for [refresh index requests][docs-refresh].
+
+[docs-refresh]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-refresh.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesShardsResponse,
+        IndexClient,
+    },
+    endpoints::IndicesRefreshRequest,
+    error::Error,
+    http::{
+        empty_body,
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+        DefaultBody,
+    },
+    params::Index,
+};
+
+/**
+A [refresh index request][docs-refresh] builder that can be configured before sending.
+
+Call [`Client.index.refresh`][Client.index.refresh] to get an `IndexRefreshRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-refresh]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-refresh.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.refresh]: ../../struct.IndexClient.html#refresh-index-request
+*/
+pub type IndexRefreshRequestBuilder<TSender> = RequestBuilder<TSender, IndexRefreshRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexRefreshRequestInner {
+    index: Index<'static>,
+}
+
+/**
+# Refresh index request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexRefreshRequestBuilder`][IndexRefreshRequestBuilder] with this `Client` that can be configured before sending.
+
+    The index can be a comma-separated list of indices or contain wildcards.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Refresh an index called `myindex` so recent changes are visible to search:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").refresh().send()?;
+
+    assert!(response.all_successful());
+    # Ok(())
+    # }
+    ```
+
+    [IndexRefreshRequestBuilder]: requests/index_refresh/type.IndexRefreshRequestBuilder.html
+    [send-sync]: requests/index_refresh/type.IndexRefreshRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_refresh/type.IndexRefreshRequestBuilder.html#send-asynchronously
+    */
+    pub fn refresh(self) -> IndexRefreshRequestBuilder<TSender> {
+        RequestBuilder::initial(self.inner, IndexRefreshRequestInner { index: self.index })
+    }
+}
+
+impl IndexRefreshRequestInner {
+    fn into_request(self) -> IndicesRefreshRequest<'static, DefaultBody> {
+        IndicesRefreshRequest::for_index(self.index, empty_body())
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexRefreshRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexRefreshRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Refresh an index called `myindex` so recent changes are visible to search:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").refresh().send()?;
+
+    assert!(response.all_successful());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesShardsResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexRefreshRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexRefreshRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised shards response.
+
+    # Examples
+
+    Refresh an index called `myindex` so recent changes are visible to search:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("myindex").refresh().send();
+
+    future.and_then(|response| {
+        assert!(response.all_successful());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesShardsResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.index("testindex").refresh().inner.into_request();
+
+        assert_eq!("/testindex/_refresh", req.url.as_ref());
+    }
+}
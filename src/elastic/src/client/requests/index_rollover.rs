@@ -0,0 +1,426 @@
+/*!
+Builders for [rollover index requests][docs-rollover].
+
+[docs-rollover]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-rollover-index.html
+*/
+
+use std::time::Duration;
+
+use futures::Future;
+use serde_json;
+
+use crate::{
+    client::{
+        requests::{
+            common::format_duration,
+            index_settings::IndexSettings,
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesRolloverResponse,
+        IndexClient,
+    },
+    endpoints::IndicesRolloverRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Alias,
+        NewIndex,
+    },
+};
+
+/**
+A [rollover index request][docs-rollover] builder that can be configured before sending.
+
+Call [`Client.index.rollover`][Client.index.rollover] to get an `IndexRolloverRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-rollover]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-rollover-index.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.rollover]: ../../struct.IndexClient.html#rollover-index-request
+*/
+pub type IndexRolloverRequestBuilder<TSender> = RequestBuilder<TSender, IndexRolloverRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexRolloverRequestInner {
+    alias: Alias<'static>,
+    new_index: Option<NewIndex<'static>>,
+    conditions: RolloverConditions,
+    settings: Option<IndexSettings>,
+}
+
+/**
+The conditions that must be met before an alias is rolled over to a new index.
+
+The alias is rolled over if any of the conditions are met.
+*/
+#[derive(Serialize, Default)]
+struct RolloverConditions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_age: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_docs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_size: Option<String>,
+}
+
+/**
+# Rollover index request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexRolloverRequestBuilder`][IndexRolloverRequestBuilder] with this `Client` that can be configured before sending.
+
+    The index for this `IndexClient` is the alias to roll over.
+    If no conditions are set then the alias is always rolled over.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Roll the `logs` alias over to a new index if the current one is more than a day old or has more than a million documents:
+
+    ```no_run
+    # use std::time::Duration;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("logs")
+                         .rollover()
+                         .max_age(Duration::from_secs(60 * 60 * 24))
+                         .max_docs(1_000_000)
+                         .send()?;
+
+    if response.rolled_over() {
+        println!("rolled over from {} to {}", response.old_index(), response.new_index());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [IndexRolloverRequestBuilder]: requests/index_rollover/type.IndexRolloverRequestBuilder.html
+    [builder-methods]: requests/index_rollover/type.IndexRolloverRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_rollover/type.IndexRolloverRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_rollover/type.IndexRolloverRequestBuilder.html#send-asynchronously
+    */
+    pub fn rollover(self) -> IndexRolloverRequestBuilder<TSender> {
+        let alias: String = self.index.into();
+
+        RequestBuilder::initial(
+            self.inner,
+            IndexRolloverRequestInner {
+                alias: alias.into(),
+                new_index: None,
+                conditions: RolloverConditions::default(),
+                settings: None,
+            },
+        )
+    }
+}
+
+impl IndexRolloverRequestInner {
+    fn into_request(self) -> Result<IndicesRolloverRequest<'static, Vec<u8>>, Error> {
+        #[derive(Serialize)]
+        struct Body {
+            conditions: RolloverConditions,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            settings: Option<IndexSettings>,
+        }
+
+        if let Some(ref settings) = self.settings {
+            settings.validate()?;
+        }
+
+        let body = serde_json::to_vec(&Body {
+            conditions: self.conditions,
+            settings: self.settings,
+        })
+        .map_err(error::request)?;
+
+        Ok(match self.new_index {
+            Some(new_index) => {
+                IndicesRolloverRequest::for_alias_new_index(self.alias, new_index, body)
+            }
+            None => IndicesRolloverRequest::for_alias(self.alias, body),
+        })
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexRolloverRequestBuilder` before sending it.
+*/
+impl<TSender> IndexRolloverRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the name of the new index.
+
+    If no name is given then the name of the current index must end in a number, like `logs-000001`, which is incremented for the new index.
+    */
+    pub fn new_index(mut self, new_index: impl Into<NewIndex<'static>>) -> Self {
+        self.inner.new_index = Some(new_index.into());
+        self
+    }
+
+    /** Roll over if the current index was created more than `max_age` ago. */
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.inner.conditions.max_age = Some(format_duration(max_age));
+        self
+    }
+
+    /** Roll over if the current index contains at least `max_docs` documents. */
+    pub fn max_docs(mut self, max_docs: u64) -> Self {
+        self.inner.conditions.max_docs = Some(max_docs);
+        self
+    }
+
+    /** Roll over if the primary shards of the current index are at least `max_size_bytes` bytes in size. */
+    pub fn max_size(mut self, max_size_bytes: u64) -> Self {
+        self.inner.conditions.max_size = Some(format_bytes(max_size_bytes));
+        self
+    }
+
+    /** Set the settings for the new index. */
+    pub fn settings(mut self, settings: IndexSettings) -> Self {
+        self.inner.settings = Some(settings);
+        self
+    }
+
+    /**
+    Check the conditions without rolling the alias over.
+
+    The response will report which conditions matched.
+    */
+    pub fn dry_run(mut self) -> Self {
+        self.params_builder = self
+            .params_builder
+            .fluent(|params| params.url_param("dry_run", true))
+            .shared();
+        self
+    }
+}
+
+/** Format a number of bytes using the largest unit that represents it exactly. */
+fn format_bytes(bytes: u64) -> String {
+    let units = [
+        ("tb", 1 << 40),
+        ("gb", 1 << 30),
+        ("mb", 1 << 20),
+        ("kb", 1 << 10),
+    ];
+
+    for &(unit, size) in &units {
+        if bytes >= size && bytes % size == 0 {
+            return format!("{}{}", bytes / size, unit);
+        }
+    }
+
+    format!("{}b", bytes)
+}
+
+/**
+# Send synchronously
+*/
+impl IndexRolloverRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexRolloverRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Check whether the `logs` alias would be rolled over:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("logs")
+                         .rollover()
+                         .max_docs(1_000_000)
+                         .dry_run()
+                         .send()?;
+
+    for condition in response.matched_conditions() {
+        println!("matched {}", condition);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesRolloverResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexRolloverRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexRolloverRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised rollover index response.
+
+    # Examples
+
+    Check whether the `logs` alias would be rolled over:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("logs")
+                       .rollover()
+                       .max_docs(1_000_000)
+                       .dry_run()
+                       .send();
+
+    future.and_then(|response| {
+        for condition in response.matched_conditions() {
+            println!("matched {}", condition);
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesRolloverResponse>;
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use super::format_bytes;
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testalias")
+            .rollover()
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/testalias/_rollover", req.url.as_ref());
+        assert_eq!(json!({ "conditions": {} }), actual_body);
+    }
+
+    #[test]
+    fn specify_body() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testalias")
+            .rollover()
+            .new_index("testindex-2")
+            .max_age(Duration::from_secs(7 * 24 * 60 * 60))
+            .max_docs(1000)
+            .max_size(5 * (1 << 30))
+            .settings(IndexSettings::new().number_of_shards(2))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "conditions": {
+                "max_age": "7d",
+                "max_docs": 1000,
+                "max_size": "5gb"
+            },
+            "settings": {
+                "number_of_shards": 2
+            }
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/testalias/_rollover/testindex-2", req.url.as_ref());
+        assert_eq!(expected_body, actual_body);
+    }
+
+    #[test]
+    fn invalid_settings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testalias")
+            .rollover()
+            .settings(IndexSettings::new().number_of_shards(0))
+            .inner
+            .into_request();
+
+        assert!(req.is_err());
+    }
+
+    #[test]
+    fn format_byte_sizes() {
+        assert_eq!("50gb", format_bytes(50 * (1 << 30)));
+        assert_eq!("1536kb", format_bytes(1536 * (1 << 10)));
+        assert_eq!("100b", format_bytes(100));
+    }
+}
@@ -0,0 +1,212 @@
+/*!
+Builders for [index segments requests][docs-segments].
+
+[docs-segments]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-segments.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesSegmentsResponse,
+        IndexClient,
+    },
+    endpoints::IndicesSegmentsRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::Index,
+};
+
+/**
+An [index segments request][docs-segments] builder that can be configured before sending.
+
+Call [`Client.index.segments`][Client.index.segments] to get an `IndexSegmentsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-segments]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-segments.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.segments]: ../../struct.IndexClient.html#index-segments-request
+*/
+pub type IndexSegmentsRequestBuilder<TSender> = RequestBuilder<TSender, IndexSegmentsRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexSegmentsRequestInner {
+    index: Index<'static>,
+}
+
+/**
+# Index segments request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexSegmentsRequestBuilder`][IndexSegmentsRequestBuilder] with this `Client` that can be configured before sending.
+
+    The index can be a comma-separated list of indices or contain wildcards.
+    Use the `_all` index to get segments for every index.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the number of searchable segments in each shard of the `myindex` index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").segments().send()?;
+
+    if let Some(index) = response.index("myindex") {
+        for (shard, segments) in index.shards() {
+            println!("{}: {}", shard, segments.num_search_segments());
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [IndexSegmentsRequestBuilder]: requests/index_segments/type.IndexSegmentsRequestBuilder.html
+    [send-sync]: requests/index_segments/type.IndexSegmentsRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_segments/type.IndexSegmentsRequestBuilder.html#send-asynchronously
+    */
+    pub fn segments(self) -> IndexSegmentsRequestBuilder<TSender> {
+        RequestBuilder::initial(self.inner, IndexSegmentsRequestInner { index: self.index })
+    }
+}
+
+impl IndexSegmentsRequestInner {
+    fn into_request(self) -> IndicesSegmentsRequest<'static> {
+        IndicesSegmentsRequest::for_index(self.index)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexSegmentsRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexSegmentsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the number of deleted documents in every segment of every index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("_all").segments().send()?;
+
+    for (index, segments) in response.iter() {
+        for (shard, copy) in segments.shards() {
+            for (name, segment) in copy.segments() {
+                println!("{}/{}/{}: {}", index, shard, name, segment.deleted_docs());
+            }
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesSegmentsResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexSegmentsRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexSegmentsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised index segments response.
+
+    # Examples
+
+    Get the number of deleted documents in every segment of every index:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("_all").segments().send();
+
+    future.and_then(|response| {
+        for (index, segments) in response.iter() {
+            for (shard, copy) in segments.shards() {
+                for (name, segment) in copy.segments() {
+                    println!("{}/{}/{}: {}", index, shard, name, segment.deleted_docs());
+                }
+            }
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesSegmentsResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.index("testindex").segments().inner.into_request();
+
+        assert_eq!("/testindex/_segments", req.url.as_ref());
+    }
+}
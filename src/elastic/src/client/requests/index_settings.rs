@@ -0,0 +1,816 @@
+/*!
+A typed model for [index settings][docs-index-settings].
+
+Use [`IndexSettings`][IndexSettings] to configure settings when creating an index or updating the settings of an existing one.
+
+[docs-index-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules.html
+[IndexSettings]: struct.IndexSettings.html
+*/
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    str::FromStr,
+    time::Duration,
+};
+
+use serde::{
+    de::{
+        self,
+        Deserialize,
+        Deserializer,
+    },
+    ser::{
+        Serialize,
+        Serializer,
+    },
+};
+use serde_json::{
+    Map,
+    Value,
+};
+
+use crate::{
+    client::requests::common::format_duration,
+    error::{
+        self,
+        Error,
+    },
+};
+
+/**
+Settings for an index.
+
+Settings that aren't set won't be sent, so Elasticsearch will use its defaults for them.
+Settings that don't have a typed method can be set using [`setting`](#method.setting).
+
+# Examples
+
+Create settings for an index with a single shard and a custom analyzer:
+
+```
+# use elastic::prelude::*;
+let settings = IndexSettings::new()
+    .number_of_shards(1)
+    .number_of_replicas(0)
+    .analysis(Analysis::new()
+        .analyzer("my_analyzer", Analyzer::custom("standard").filter("lowercase")));
+```
+*/
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct IndexSettings {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_number"
+    )]
+    number_of_shards: Option<u32>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_number"
+    )]
+    number_of_replicas: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    refresh_interval: Option<RefreshInterval>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_number"
+    )]
+    max_result_window: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    codec: Option<Codec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    analysis: Option<Analysis>,
+    #[serde(flatten)]
+    other: Map<String, Value>,
+}
+
+impl IndexSettings {
+    /** Create an empty set of index settings. */
+    pub fn new() -> Self {
+        IndexSettings::default()
+    }
+
+    /**
+    Set the number of primary shards.
+
+    The number of shards can only be set when an index is created.
+    */
+    pub fn number_of_shards(mut self, number_of_shards: u32) -> Self {
+        self.number_of_shards = Some(number_of_shards);
+        self
+    }
+
+    /** Set the number of replicas for each primary shard. */
+    pub fn number_of_replicas(mut self, number_of_replicas: u32) -> Self {
+        self.number_of_replicas = Some(number_of_replicas);
+        self
+    }
+
+    /** Set how often the index is refreshed so that changes are visible to search. */
+    pub fn refresh_interval(mut self, refresh_interval: impl Into<RefreshInterval>) -> Self {
+        self.refresh_interval = Some(refresh_interval.into());
+        self
+    }
+
+    /** Set the maximum value of `from + size` for searches on the index. */
+    pub fn max_result_window(mut self, max_result_window: u32) -> Self {
+        self.max_result_window = Some(max_result_window);
+        self
+    }
+
+    /** Set the compression codec for stored fields. */
+    pub fn codec(mut self, codec: Codec) -> Self {
+        self.codec = Some(codec);
+        self
+    }
+
+    /** Set the analysis components for the index. */
+    pub fn analysis(mut self, analysis: Analysis) -> Self {
+        self.analysis = Some(analysis);
+        self
+    }
+
+    /**
+    Set a setting that isn't covered by the other methods.
+
+    The key is relative to the `index` namespace, so `index.mapping.total_fields.limit` is set using `mapping.total_fields.limit`.
+    */
+    pub fn setting(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.other.insert(key.into(), value.into());
+        self
+    }
+
+    /** Get the number of primary shards. */
+    pub fn get_number_of_shards(&self) -> Option<u32> {
+        self.number_of_shards
+    }
+
+    /** Get the number of replicas for each primary shard. */
+    pub fn get_number_of_replicas(&self) -> Option<u32> {
+        self.number_of_replicas
+    }
+
+    /** Get how often the index is refreshed. */
+    pub fn get_refresh_interval(&self) -> Option<RefreshInterval> {
+        self.refresh_interval
+    }
+
+    /** Get the maximum value of `from + size` for searches on the index. */
+    pub fn get_max_result_window(&self) -> Option<u32> {
+        self.max_result_window
+    }
+
+    /** Get the compression codec for stored fields. */
+    pub fn get_codec(&self) -> Option<Codec> {
+        self.codec
+    }
+
+    /** Get the analysis components for the index. */
+    pub fn get_analysis(&self) -> Option<&Analysis> {
+        self.analysis.as_ref()
+    }
+
+    /** Get a setting that isn't covered by the other methods. */
+    pub fn get_setting(&self, key: &str) -> Option<&Value> {
+        self.other.get(key)
+    }
+
+    /**
+    Check the settings for combinations Elasticsearch will reject.
+    */
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        if self.number_of_shards == Some(0) {
+            return Err(invalid("number_of_shards must be greater than 0"));
+        }
+
+        if self.max_result_window == Some(0) {
+            return Err(invalid("max_result_window must be greater than 0"));
+        }
+
+        if let Some(ref analysis) = self.analysis {
+            analysis.validate()?;
+        }
+
+        Ok(())
+    }
+
+    /**
+    Check the settings can be applied to an index that already exists.
+    */
+    pub(crate) fn validate_update(&self) -> Result<(), Error> {
+        if self.number_of_shards.is_some() {
+            return Err(invalid(
+                "number_of_shards can't be changed on an existing index",
+            ));
+        }
+
+        self.validate()
+    }
+}
+
+/** How often an index is refreshed. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RefreshInterval {
+    /** Refresh the index periodically. */
+    Every(Duration),
+    /** Don't refresh the index automatically. */
+    Disabled,
+}
+
+impl From<Duration> for RefreshInterval {
+    fn from(interval: Duration) -> Self {
+        RefreshInterval::Every(interval)
+    }
+}
+
+impl fmt::Display for RefreshInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RefreshInterval::Every(interval) => f.write_str(&format_duration(interval)),
+            RefreshInterval::Disabled => f.write_str("-1"),
+        }
+    }
+}
+
+impl FromStr for RefreshInterval {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "-1" {
+            return Ok(RefreshInterval::Disabled);
+        }
+
+        let split = s
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(|| format!("missing time unit in `{}`", s))?;
+
+        let (value, unit) = s.split_at(split);
+        let value: u64 = value
+            .parse()
+            .map_err(|_| format!("invalid time value in `{}`", s))?;
+
+        let interval = match unit {
+            "nanos" => Duration::from_nanos(value),
+            "micros" => Duration::from_micros(value),
+            "ms" => Duration::from_millis(value),
+            "s" => Duration::from_secs(value),
+            "m" => Duration::from_secs(value * 60),
+            "h" => Duration::from_secs(value * 60 * 60),
+            "d" => Duration::from_secs(value * 60 * 60 * 24),
+            _ => return Err(format!("unknown time unit in `{}`", s)),
+        };
+
+        Ok(RefreshInterval::Every(interval))
+    }
+}
+
+impl Serialize for RefreshInterval {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RefreshInterval {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::String(interval) => interval.parse().map_err(de::Error::custom),
+            Value::Number(ref n) if n.as_i64() == Some(-1) => Ok(RefreshInterval::Disabled),
+            other => Err(de::Error::custom(format!(
+                "expected a refresh interval but got `{}`",
+                other
+            ))),
+        }
+    }
+}
+
+/** The compression codec used for stored fields. */
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Codec {
+    /** Compress stored fields with LZ4. */
+    Default,
+    /** Compress stored fields with DEFLATE for a higher compression ratio at the cost of slower performance. */
+    BestCompression,
+}
+
+/**
+The [analysis components][docs-analysis] defined on an index.
+
+Each component is registered under a name that can be referenced in mappings or by other components.
+
+[docs-analysis]: https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis.html
+*/
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Analysis {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    analyzer: BTreeMap<String, Analyzer>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    normalizer: BTreeMap<String, Normalizer>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    tokenizer: BTreeMap<String, AnalysisComponent>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    filter: BTreeMap<String, AnalysisComponent>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    char_filter: BTreeMap<String, AnalysisComponent>,
+}
+
+impl Analysis {
+    /** Create an empty set of analysis components. */
+    pub fn new() -> Self {
+        Analysis::default()
+    }
+
+    /** Add an analyzer. */
+    pub fn analyzer(mut self, name: impl Into<String>, analyzer: Analyzer) -> Self {
+        self.analyzer.insert(name.into(), analyzer);
+        self
+    }
+
+    /** Add a normalizer. */
+    pub fn normalizer(mut self, name: impl Into<String>, normalizer: Normalizer) -> Self {
+        self.normalizer.insert(name.into(), normalizer);
+        self
+    }
+
+    /** Add a tokenizer. */
+    pub fn tokenizer(mut self, name: impl Into<String>, tokenizer: AnalysisComponent) -> Self {
+        self.tokenizer.insert(name.into(), tokenizer);
+        self
+    }
+
+    /** Add a token filter. */
+    pub fn filter(mut self, name: impl Into<String>, filter: AnalysisComponent) -> Self {
+        self.filter.insert(name.into(), filter);
+        self
+    }
+
+    /** Add a character filter. */
+    pub fn char_filter(mut self, name: impl Into<String>, char_filter: AnalysisComponent) -> Self {
+        self.char_filter.insert(name.into(), char_filter);
+        self
+    }
+
+    /** Get an analyzer by name. */
+    pub fn get_analyzer(&self, name: &str) -> Option<&Analyzer> {
+        self.analyzer.get(name)
+    }
+
+    /** Get a normalizer by name. */
+    pub fn get_normalizer(&self, name: &str) -> Option<&Normalizer> {
+        self.normalizer.get(name)
+    }
+
+    /** Get a tokenizer by name. */
+    pub fn get_tokenizer(&self, name: &str) -> Option<&AnalysisComponent> {
+        self.tokenizer.get(name)
+    }
+
+    /** Get a token filter by name. */
+    pub fn get_filter(&self, name: &str) -> Option<&AnalysisComponent> {
+        self.filter.get(name)
+    }
+
+    /** Get a character filter by name. */
+    pub fn get_char_filter(&self, name: &str) -> Option<&AnalysisComponent> {
+        self.char_filter.get(name)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        for (name, analyzer) in &self.analyzer {
+            match (analyzer.is_custom(), &analyzer.tokenizer) {
+                (true, None) => {
+                    return Err(invalid(format!(
+                        "custom analyzer `{}` must have a tokenizer",
+                        name
+                    )))
+                }
+                (false, Some(_)) => {
+                    return Err(invalid(format!(
+                        "analyzer `{}` sets a tokenizer but isn't a custom analyzer",
+                        name
+                    )))
+                }
+                _ => (),
+            }
+        }
+
+        for (name, normalizer) in &self.normalizer {
+            if !normalizer.is_custom() {
+                return Err(invalid(format!(
+                    "normalizer `{}` must be a custom normalizer",
+                    name
+                )));
+            }
+        }
+
+        let components = self
+            .tokenizer
+            .iter()
+            .chain(self.filter.iter())
+            .chain(self.char_filter.iter());
+
+        for (name, component) in components {
+            if component.ty.is_empty() {
+                return Err(invalid(format!(
+                    "analysis component `{}` must have a type",
+                    name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/**
+An [analyzer][docs-analyzers] that converts text into tokens.
+
+[docs-analyzers]: https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-analyzers.html
+*/
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Analyzer {
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    ty: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tokenizer: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    char_filter: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    filter: Vec<String>,
+    #[serde(flatten)]
+    options: Map<String, Value>,
+}
+
+impl Analyzer {
+    /** Create a custom analyzer that uses the given tokenizer. */
+    pub fn custom(tokenizer: impl Into<String>) -> Self {
+        Analyzer {
+            ty: Some("custom".to_owned()),
+            tokenizer: Some(tokenizer.into()),
+            char_filter: Vec::new(),
+            filter: Vec::new(),
+            options: Map::new(),
+        }
+    }
+
+    /**
+    Configure a built-in analyzer, like `standard` or `pattern`.
+
+    Built-in analyzers can be configured using [`option`](#method.option).
+    */
+    pub fn builtin(ty: impl Into<String>) -> Self {
+        Analyzer {
+            ty: Some(ty.into()),
+            tokenizer: None,
+            char_filter: Vec::new(),
+            filter: Vec::new(),
+            options: Map::new(),
+        }
+    }
+
+    /** Add a character filter to a custom analyzer. */
+    pub fn char_filter(mut self, char_filter: impl Into<String>) -> Self {
+        self.char_filter.push(char_filter.into());
+        self
+    }
+
+    /** Add a token filter to a custom analyzer. */
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.filter.push(filter.into());
+        self
+    }
+
+    /** Set an option for the analyzer. */
+    pub fn option(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.options.insert(key.into(), value.into());
+        self
+    }
+
+    /** The type of analyzer. */
+    pub fn ty(&self) -> Option<&str> {
+        self.ty.as_ref().map(|ty| ty.as_ref())
+    }
+
+    /** The tokenizer used by a custom analyzer. */
+    pub fn tokenizer(&self) -> Option<&str> {
+        self.tokenizer.as_ref().map(|tokenizer| tokenizer.as_ref())
+    }
+
+    /** Iterate over the character filters used by a custom analyzer. */
+    pub fn char_filters(&self) -> impl Iterator<Item = &str> {
+        self.char_filter
+            .iter()
+            .map(|char_filter| char_filter.as_ref())
+    }
+
+    /** Iterate over the token filters used by a custom analyzer. */
+    pub fn filters(&self) -> impl Iterator<Item = &str> {
+        self.filter.iter().map(|filter| filter.as_ref())
+    }
+
+    /** Get an option for the analyzer. */
+    pub fn get_option(&self, key: &str) -> Option<&Value> {
+        self.options.get(key)
+    }
+
+    fn is_custom(&self) -> bool {
+        match self.ty {
+            Some(ref ty) => ty == "custom",
+            None => true,
+        }
+    }
+}
+
+/**
+A [normalizer][docs-normalizers] that converts a keyword into a single token.
+
+[docs-normalizers]: https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-normalizers.html
+*/
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Normalizer {
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    ty: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    char_filter: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    filter: Vec<String>,
+}
+
+impl Normalizer {
+    /** Create a custom normalizer. */
+    pub fn custom() -> Self {
+        Normalizer {
+            ty: Some("custom".to_owned()),
+            char_filter: Vec::new(),
+            filter: Vec::new(),
+        }
+    }
+
+    /** Add a character filter to the normalizer. */
+    pub fn char_filter(mut self, char_filter: impl Into<String>) -> Self {
+        self.char_filter.push(char_filter.into());
+        self
+    }
+
+    /** Add a token filter to the normalizer. */
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.filter.push(filter.into());
+        self
+    }
+
+    /** Iterate over the character filters used by the normalizer. */
+    pub fn char_filters(&self) -> impl Iterator<Item = &str> {
+        self.char_filter
+            .iter()
+            .map(|char_filter| char_filter.as_ref())
+    }
+
+    /** Iterate over the token filters used by the normalizer. */
+    pub fn filters(&self) -> impl Iterator<Item = &str> {
+        self.filter.iter().map(|filter| filter.as_ref())
+    }
+
+    fn is_custom(&self) -> bool {
+        match self.ty {
+            Some(ref ty) => ty == "custom",
+            None => true,
+        }
+    }
+}
+
+/**
+A tokenizer, token filter or character filter.
+
+Components have a type, like `ngram` or `stop`, and a set of options that depend on the type.
+*/
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AnalysisComponent {
+    #[serde(rename = "type")]
+    ty: String,
+    #[serde(flatten)]
+    options: Map<String, Value>,
+}
+
+impl AnalysisComponent {
+    /** Create a component with the given type. */
+    pub fn new(ty: impl Into<String>) -> Self {
+        AnalysisComponent {
+            ty: ty.into(),
+            options: Map::new(),
+        }
+    }
+
+    /** Set an option for the component. */
+    pub fn option(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.options.insert(key.into(), value.into());
+        self
+    }
+
+    /** The type of component. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** Get an option for the component. */
+    pub fn get_option(&self, key: &str) -> Option<&Value> {
+        self.options.get(key)
+    }
+}
+
+fn invalid(message: impl Into<String>) -> Error {
+    error::request(error::message(message))
+}
+
+/**
+Deserialize a number that may be encoded as a string.
+
+Elasticsearch returns all index settings as strings.
+*/
+fn deserialize_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Deserialize<'de>,
+    T::Err: fmt::Display,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString<T> {
+        Number(T),
+        String(String),
+    }
+
+    match Option::<NumberOrString<T>>::deserialize(deserializer)? {
+        Some(NumberOrString::Number(n)) => Ok(Some(n)),
+        Some(NumberOrString::String(s)) => s.parse().map(Some).map_err(de::Error::custom),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use super::*;
+
+    #[test]
+    fn serialize_settings() {
+        let settings = IndexSettings::new()
+            .number_of_shards(3)
+            .number_of_replicas(1)
+            .refresh_interval(Duration::from_millis(1500))
+            .max_result_window(20000)
+            .codec(Codec::BestCompression)
+            .setting("mapping.total_fields.limit", 2000)
+            .analysis(
+                Analysis::new()
+                    .analyzer(
+                        "my_analyzer",
+                        Analyzer::custom("my_tokenizer")
+                            .char_filter("html_strip")
+                            .filter("lowercase"),
+                    )
+                    .analyzer(
+                        "my_stop",
+                        Analyzer::builtin("standard").option("stopwords", "_english_"),
+                    )
+                    .normalizer("my_normalizer", Normalizer::custom().filter("lowercase"))
+                    .tokenizer(
+                        "my_tokenizer",
+                        AnalysisComponent::new("ngram").option("min_gram", 3),
+                    ),
+            );
+
+        let expected = json!({
+            "number_of_shards": 3,
+            "number_of_replicas": 1,
+            "refresh_interval": "1500ms",
+            "max_result_window": 20000,
+            "codec": "best_compression",
+            "mapping.total_fields.limit": 2000,
+            "analysis": {
+                "analyzer": {
+                    "my_analyzer": {
+                        "type": "custom",
+                        "tokenizer": "my_tokenizer",
+                        "char_filter": ["html_strip"],
+                        "filter": ["lowercase"]
+                    },
+                    "my_stop": {
+                        "type": "standard",
+                        "stopwords": "_english_"
+                    }
+                },
+                "normalizer": {
+                    "my_normalizer": {
+                        "type": "custom",
+                        "filter": ["lowercase"]
+                    }
+                },
+                "tokenizer": {
+                    "my_tokenizer": {
+                        "type": "ngram",
+                        "min_gram": 3
+                    }
+                }
+            }
+        });
+
+        let actual = serde_json::to_value(&settings).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn deserialize_string_settings() {
+        let settings: IndexSettings = serde_json::from_value(json!({
+            "number_of_shards": "5",
+            "number_of_replicas": "1",
+            "refresh_interval": "-1",
+            "max_result_window": "500",
+            "codec": "default",
+            "uuid": "Z9-4WOcTSB-4o1ihJk8P0A"
+        }))
+        .unwrap();
+
+        assert_eq!(Some(5), settings.get_number_of_shards());
+        assert_eq!(Some(1), settings.get_number_of_replicas());
+        assert_eq!(
+            Some(RefreshInterval::Disabled),
+            settings.get_refresh_interval()
+        );
+        assert_eq!(Some(500), settings.get_max_result_window());
+        assert_eq!(Some(Codec::Default), settings.get_codec());
+        assert_eq!(
+            Some(&Value::from("Z9-4WOcTSB-4o1ihJk8P0A")),
+            settings.get_setting("uuid")
+        );
+    }
+
+    #[test]
+    fn parse_refresh_interval() {
+        assert_eq!(
+            Ok(RefreshInterval::Every(Duration::from_secs(30))),
+            "30s".parse()
+        );
+        assert_eq!(
+            Ok(RefreshInterval::Every(Duration::from_secs(120))),
+            "2m".parse()
+        );
+        assert_eq!(Ok(RefreshInterval::Disabled), "-1".parse());
+        assert!("30".parse::<RefreshInterval>().is_err());
+        assert!("30y".parse::<RefreshInterval>().is_err());
+    }
+
+    #[test]
+    fn validate_zero_shards() {
+        let settings = IndexSettings::new().number_of_shards(0);
+
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn validate_custom_analyzer_without_tokenizer() {
+        let settings = IndexSettings::new().analysis(Analysis::new().analyzer(
+            "my_analyzer",
+            Analyzer::builtin("custom").filter("lowercase"),
+        ));
+
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn validate_builtin_analyzer_with_tokenizer() {
+        let analyzer: Analyzer = serde_json::from_value(json!({
+            "type": "standard",
+            "tokenizer": "whitespace"
+        }))
+        .unwrap();
+
+        let settings =
+            IndexSettings::new().analysis(Analysis::new().analyzer("my_analyzer", analyzer));
+
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn validate_update_number_of_shards() {
+        let settings = IndexSettings::new().number_of_shards(1);
+
+        assert!(settings.validate().is_ok());
+        assert!(settings.validate_update().is_err());
+    }
+}
@@ -0,0 +1,297 @@
+/*!
+Builders for [shrink index requests][docs-shrink].
+
+[docs-shrink]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-shrink-index.html
+*/
+
+use futures::Future;
+use serde_json;
+
+use crate::{
+    client::{
+        requests::{
+            index_settings::IndexSettings,
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesResizeResponse,
+        IndexClient,
+    },
+    endpoints::IndicesShrinkRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Index,
+        Target,
+    },
+};
+
+/**
+A [shrink index request][docs-shrink] builder that can be configured before sending.
+
+Call [`Client.index.shrink`][Client.index.shrink] to get an `IndexShrinkRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-shrink]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-shrink-index.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.shrink]: ../../struct.IndexClient.html#shrink-index-request
+*/
+pub type IndexShrinkRequestBuilder<TSender> = RequestBuilder<TSender, IndexShrinkRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexShrinkRequestInner {
+    index: Index<'static>,
+    target: Target<'static>,
+    settings: Option<IndexSettings>,
+}
+
+/**
+# Shrink index request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexShrinkRequestBuilder`][IndexShrinkRequestBuilder] with this `Client` that can be configured before sending.
+
+    The source index must be read-only and have a copy of every shard on a single node before it can be shrunk.
+    The target index will have a single shard unless a different number is set in its [`settings`][settings].
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Shrink the `myindex` index into a new index called `myindex-shrunk` with a single shard:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").shrink("myindex-shrunk").send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [IndexShrinkRequestBuilder]: requests/index_shrink/type.IndexShrinkRequestBuilder.html
+    [builder-methods]: requests/index_shrink/type.IndexShrinkRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_shrink/type.IndexShrinkRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_shrink/type.IndexShrinkRequestBuilder.html#send-asynchronously
+    [settings]: requests/index_shrink/type.IndexShrinkRequestBuilder.html#method.settings
+    */
+    pub fn shrink(self, target: impl Into<Target<'static>>) -> IndexShrinkRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexShrinkRequestInner {
+                index: self.index,
+                target: target.into(),
+                settings: None,
+            },
+        )
+    }
+}
+
+impl IndexShrinkRequestInner {
+    fn into_request(self) -> Result<IndicesShrinkRequest<'static, Vec<u8>>, Error> {
+        #[derive(Serialize)]
+        struct Body {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            settings: Option<IndexSettings>,
+        }
+
+        if let Some(ref settings) = self.settings {
+            settings.validate()?;
+        }
+
+        let body = serde_json::to_vec(&Body {
+            settings: self.settings,
+        })
+        .map_err(error::request)?;
+
+        Ok(IndicesShrinkRequest::for_index_target(
+            self.index,
+            self.target,
+            body,
+        ))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexShrinkRequestBuilder` before sending it.
+*/
+impl<TSender> IndexShrinkRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the settings for the target index.
+
+    The number of shards in the target index must be a factor of the number of shards in the source index.
+    */
+    pub fn settings(mut self, settings: IndexSettings) -> Self {
+        self.inner.settings = Some(settings);
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexShrinkRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexShrinkRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Shrink the `myindex` index into a new index called `myindex-shrunk` with two shards:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex")
+                         .shrink("myindex-shrunk")
+                         .settings(IndexSettings::new().number_of_shards(2))
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesResizeResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexShrinkRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexShrinkRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised resize index response.
+
+    # Examples
+
+    Shrink the `myindex` index into a new index called `myindex-shrunk` with two shards:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("myindex")
+                       .shrink("myindex-shrunk")
+                       .settings(IndexSettings::new().number_of_shards(2))
+                       .send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesResizeResponse>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .shrink("testtarget")
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/testindex/_shrink/testtarget", req.url.as_ref());
+        assert_eq!(json!({}), actual_body);
+    }
+
+    #[test]
+    fn specify_settings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .shrink("testtarget")
+            .settings(IndexSettings::new().number_of_shards(2))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(
+            json!({ "settings": { "number_of_shards": 2 } }),
+            actual_body
+        );
+    }
+}
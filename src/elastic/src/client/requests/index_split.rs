@@ -0,0 +1,323 @@
+/*!
+Builders for [split index requests][docs-split].
+
+[docs-split]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-split-index.html
+*/
+
+use futures::Future;
+use serde_json;
+
+use crate::{
+    client::{
+        requests::{
+            index_settings::IndexSettings,
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesResizeResponse,
+        IndexClient,
+    },
+    endpoints::IndicesSplitRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Index,
+        Target,
+    },
+};
+
+/**
+A [split index request][docs-split] builder that can be configured before sending.
+
+Call [`Client.index.split`][Client.index.split] to get an `IndexSplitRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-split]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-split-index.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.split]: ../../struct.IndexClient.html#split-index-request
+*/
+pub type IndexSplitRequestBuilder<TSender> = RequestBuilder<TSender, IndexSplitRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexSplitRequestInner {
+    index: Index<'static>,
+    target: Target<'static>,
+    number_of_shards: u32,
+    settings: Option<IndexSettings>,
+}
+
+/**
+# Split index request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexSplitRequestBuilder`][IndexSplitRequestBuilder] with this `Client` that can be configured before sending.
+
+    The source index must be read-only before it can be split.
+    The number of shards in the target index must be a multiple of the number of shards in the source index.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Split the `myindex` index into a new index called `myindex-split` with four shards:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex").split("myindex-split", 4).send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [IndexSplitRequestBuilder]: requests/index_split/type.IndexSplitRequestBuilder.html
+    [builder-methods]: requests/index_split/type.IndexSplitRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_split/type.IndexSplitRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_split/type.IndexSplitRequestBuilder.html#send-asynchronously
+    */
+    pub fn split(
+        self,
+        target: impl Into<Target<'static>>,
+        number_of_shards: u32,
+    ) -> IndexSplitRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexSplitRequestInner {
+                index: self.index,
+                target: target.into(),
+                number_of_shards,
+                settings: None,
+            },
+        )
+    }
+}
+
+impl IndexSplitRequestInner {
+    fn into_request(self) -> Result<IndicesSplitRequest<'static, Vec<u8>>, Error> {
+        #[derive(Serialize)]
+        struct Body {
+            settings: IndexSettings,
+        }
+
+        let settings = self
+            .settings
+            .unwrap_or_default()
+            .number_of_shards(self.number_of_shards);
+
+        settings.validate()?;
+
+        let body = serde_json::to_vec(&Body { settings }).map_err(error::request)?;
+
+        Ok(IndicesSplitRequest::for_index_target(
+            self.index,
+            self.target,
+            body,
+        ))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexSplitRequestBuilder` before sending it.
+*/
+impl<TSender> IndexSplitRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the settings for the target index.
+
+    The number of shards passed to [`split`][Client.index.split] replaces any number of shards set here.
+
+    [Client.index.split]: ../../struct.IndexClient.html#split-index-request
+    */
+    pub fn settings(mut self, settings: IndexSettings) -> Self {
+        self.inner.settings = Some(settings);
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexSplitRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexSplitRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Split the `myindex` index into a new index called `myindex-split` with four shards and no replicas:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex")
+                         .split("myindex-split", 4)
+                         .settings(IndexSettings::new().number_of_replicas(0))
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesResizeResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexSplitRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexSplitRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised resize index response.
+
+    # Examples
+
+    Split the `myindex` index into a new index called `myindex-split` with four shards and no replicas:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("myindex")
+                       .split("myindex-split", 4)
+                       .settings(IndexSettings::new().number_of_replicas(0))
+                       .send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesResizeResponse>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .split("testtarget", 4)
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/testindex/_split/testtarget", req.url.as_ref());
+        assert_eq!(
+            json!({ "settings": { "number_of_shards": 4 } }),
+            actual_body
+        );
+    }
+
+    #[test]
+    fn specify_settings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .split("testtarget", 4)
+            .settings(
+                IndexSettings::new()
+                    .number_of_shards(2)
+                    .number_of_replicas(0),
+            )
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(
+            json!({ "settings": { "number_of_shards": 4, "number_of_replicas": 0 } }),
+            actual_body
+        );
+    }
+
+    #[test]
+    fn zero_shards() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .split("testtarget", 0)
+            .inner
+            .into_request();
+
+        assert!(req.is_err());
+    }
+}
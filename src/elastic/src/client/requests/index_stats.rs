@@ -0,0 +1,361 @@
+/*!
+Builders for [index stats requests][docs-stats].
+
+[docs-stats]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-stats.html
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::IndicesStatsResponse,
+        IndexClient,
+    },
+    endpoints::IndicesStatsRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::Index,
+};
+
+/**
+An [index stats request][docs-stats] builder that can be configured before sending.
+
+Call [`Client.index.stats`][Client.index.stats] to get an `IndexStatsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-stats]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-stats.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.stats]: ../../struct.IndexClient.html#index-stats-request
+*/
+pub type IndexStatsRequestBuilder<TSender> = RequestBuilder<TSender, IndexStatsRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexStatsRequestInner {
+    index: Index<'static>,
+    metrics: Vec<StatsMetric>,
+}
+
+/** A group of stats that can be requested for an index. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatsMetric {
+    /** Document counts. */
+    Docs,
+    /** Store size. */
+    Store,
+    /** Indexing operations. */
+    Indexing,
+    /** Get operations. */
+    Get,
+    /** Search operations. */
+    Search,
+    /** Merge operations. */
+    Merge,
+    /** Refresh operations. */
+    Refresh,
+    /** Flush operations. */
+    Flush,
+    /** Warmer operations. */
+    Warmer,
+    /** Query cache usage. */
+    QueryCache,
+    /** Fielddata usage. */
+    Fielddata,
+    /** Completion suggester usage. */
+    Completion,
+    /** Segment counts and memory. */
+    Segments,
+    /** Translog operations and size. */
+    Translog,
+    /** Shard request cache usage. */
+    RequestCache,
+    /** Shard recoveries. */
+    Recovery,
+}
+
+impl StatsMetric {
+    fn as_str(self) -> &'static str {
+        match self {
+            StatsMetric::Docs => "docs",
+            StatsMetric::Store => "store",
+            StatsMetric::Indexing => "indexing",
+            StatsMetric::Get => "get",
+            StatsMetric::Search => "search",
+            StatsMetric::Merge => "merge",
+            StatsMetric::Refresh => "refresh",
+            StatsMetric::Flush => "flush",
+            StatsMetric::Warmer => "warmer",
+            StatsMetric::QueryCache => "query_cache",
+            StatsMetric::Fielddata => "fielddata",
+            StatsMetric::Completion => "completion",
+            StatsMetric::Segments => "segments",
+            StatsMetric::Translog => "translog",
+            StatsMetric::RequestCache => "request_cache",
+            StatsMetric::Recovery => "recovery",
+        }
+    }
+}
+
+/** The level of detail to return stats at. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatsLevel {
+    /** Only return stats aggregated across all indices. */
+    Cluster,
+    /** Also return stats for each index. This is the default. */
+    Indices,
+    /** Also return stats for each index and shard. */
+    Shards,
+}
+
+impl StatsLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            StatsLevel::Cluster => "cluster",
+            StatsLevel::Indices => "indices",
+            StatsLevel::Shards => "shards",
+        }
+    }
+}
+
+/**
+# Index stats request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexStatsRequestBuilder`][IndexStatsRequestBuilder] with this `Client` that can be configured before sending.
+
+    The index can be a comma-separated list of indices or contain wildcards.
+    Use the `_all` index to get stats for every index.
+    All metrics are returned unless specific ones are chosen using the [builder methods][builder-methods].
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the number of documents and store size for the `myindex` index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex")
+                         .stats()
+                         .metric(StatsMetric::Docs)
+                         .metric(StatsMetric::Store)
+                         .send()?;
+
+    let total = response.all().total();
+
+    println!("docs: {:?}", total.docs().map(|docs| docs.count()));
+    println!("bytes: {:?}", total.store().map(|store| store.size_in_bytes()));
+    # Ok(())
+    # }
+    ```
+
+    [IndexStatsRequestBuilder]: requests/index_stats/type.IndexStatsRequestBuilder.html
+    [builder-methods]: requests/index_stats/type.IndexStatsRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_stats/type.IndexStatsRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_stats/type.IndexStatsRequestBuilder.html#send-asynchronously
+    */
+    pub fn stats(self) -> IndexStatsRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexStatsRequestInner {
+                index: self.index,
+                metrics: Vec::new(),
+            },
+        )
+    }
+}
+
+impl IndexStatsRequestInner {
+    fn into_request(self) -> IndicesStatsRequest<'static> {
+        if self.metrics.is_empty() {
+            IndicesStatsRequest::for_index(self.index)
+        } else {
+            let metrics = self
+                .metrics
+                .iter()
+                .map(|metric| metric.as_str())
+                .collect::<Vec<_>>()
+                .join(",");
+
+            IndicesStatsRequest::for_index_metric(self.index, metrics)
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexStatsRequestBuilder` before sending it.
+*/
+impl<TSender> IndexStatsRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Only return the given group of stats.
+
+    This method can be called multiple times to return multiple groups.
+    */
+    pub fn metric(mut self, metric: StatsMetric) -> Self {
+        if !self.inner.metrics.contains(&metric) {
+            self.inner.metrics.push(metric);
+        }
+
+        self
+    }
+
+    /** Set the level of detail to return stats at. */
+    pub fn level(self, level: StatsLevel) -> Self {
+        self.params_fluent(move |params| params.url_param("level", level.as_str()))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexStatsRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexStatsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the number of search queries for each shard of the `myindex` index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.index("myindex")
+                         .stats()
+                         .metric(StatsMetric::Search)
+                         .level(StatsLevel::Shards)
+                         .send()?;
+
+    for (shard, stats) in response.index("myindex").into_iter().flat_map(|index| index.shards()) {
+        println!("{}: {:?}", shard, stats.metrics().search().map(|search| search.query_total()));
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<IndicesStatsResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexStatsRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexStatsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised index stats response.
+
+    # Examples
+
+    Get the number of search queries for each shard of the `myindex` index:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.index("myindex")
+                       .stats()
+                       .metric(StatsMetric::Search)
+                       .level(StatsLevel::Shards)
+                       .send();
+
+    future.and_then(|response| {
+        for (shard, stats) in response.index("myindex").into_iter().flat_map(|index| index.shards()) {
+            println!("{}: {:?}", shard, stats.metrics().search().map(|search| search.query_total()));
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<IndicesStatsResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.index("testindex").stats().inner.into_request();
+
+        assert_eq!("/testindex/_stats", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_metrics() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .index("testindex")
+            .stats()
+            .metric(StatsMetric::Docs)
+            .metric(StatsMetric::QueryCache)
+            .metric(StatsMetric::Docs)
+            .inner
+            .into_request();
+
+        assert_eq!("/testindex/_stats/docs,query_cache", req.url.as_ref());
+    }
+}
@@ -0,0 +1,437 @@
+/*!
+Builders for [update aliases requests][docs-aliases].
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+*/
+
+use futures::Future;
+use serde_json::{
+    self,
+    Value,
+};
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::CommandResponse,
+        Client,
+    },
+    endpoints::IndicesUpdateAliasesRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Index,
+        Name,
+    },
+};
+
+/**
+An [update aliases request][docs-aliases] builder that can be configured before sending.
+
+Call [`Client.update_aliases`][Client.update_aliases] to get an `IndexUpdateAliasesRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.update_aliases]: ../../struct.Client.html#update-aliases-request
+*/
+pub type IndexUpdateAliasesRequestBuilder<TSender> =
+    RequestBuilder<TSender, IndexUpdateAliasesRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexUpdateAliasesRequestInner {
+    actions: Vec<AliasAction>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum AliasAction {
+    Add {
+        index: String,
+        alias: String,
+        #[serde(flatten)]
+        alias_body: AliasBuilder,
+    },
+    Remove {
+        index: String,
+        alias: String,
+    },
+    RemoveIndex {
+        index: String,
+    },
+}
+
+/**
+A builder for the properties of an alias.
+
+An `AliasBuilder` is used to configure aliases added by an [update aliases request][IndexUpdateAliasesRequestBuilder] or a [put alias request][IndexPutAliasRequestBuilder].
+
+[IndexUpdateAliasesRequestBuilder]: type.IndexUpdateAliasesRequestBuilder.html
+[IndexPutAliasRequestBuilder]: ../index_put_alias/type.IndexPutAliasRequestBuilder.html
+*/
+#[derive(Serialize, Default, Clone, Debug)]
+pub struct AliasBuilder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    routing: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index_routing: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_routing: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_write_index: Option<bool>,
+}
+
+impl AliasBuilder {
+    /** Create a new alias builder. */
+    pub fn new() -> Self {
+        AliasBuilder::default()
+    }
+
+    /**
+    Set a query filter for the alias.
+
+    Searches and counts through the alias will only see documents that match the filter.
+    */
+    pub fn filter(mut self, filter: impl Into<Value>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /** Set the routing value used for both indexing and searching through the alias. */
+    pub fn routing(mut self, routing: impl ToString) -> Self {
+        self.routing = Some(routing.to_string());
+        self
+    }
+
+    /** Set the routing value used for indexing through the alias. */
+    pub fn index_routing(mut self, routing: impl ToString) -> Self {
+        self.index_routing = Some(routing.to_string());
+        self
+    }
+
+    /** Set the routing value used for searching through the alias. */
+    pub fn search_routing(mut self, routing: impl ToString) -> Self {
+        self.search_routing = Some(routing.to_string());
+        self
+    }
+
+    /**
+    Set whether the index is the write index for the alias.
+
+    Indexing requests sent to an alias that points to multiple indices will go to its write index.
+    */
+    pub fn is_write_index(mut self, is_write_index: bool) -> Self {
+        self.is_write_index = Some(is_write_index);
+        self
+    }
+}
+
+/**
+# Update aliases request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexUpdateAliasesRequestBuilder`][IndexUpdateAliasesRequestBuilder] with this `Client` that can be configured before sending.
+
+    All of the actions in an update aliases request are applied atomically.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Swap the `myindex` alias from an old index to a new one:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.update_aliases()
+                         .remove("myindex-v1", "myindex")
+                         .add("myindex-v2", "myindex")
+                         .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [IndexUpdateAliasesRequestBuilder]: requests/index_update_aliases/type.IndexUpdateAliasesRequestBuilder.html
+    [builder-methods]: requests/index_update_aliases/type.IndexUpdateAliasesRequestBuilder.html#builder-methods
+    [send-sync]: requests/index_update_aliases/type.IndexUpdateAliasesRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_update_aliases/type.IndexUpdateAliasesRequestBuilder.html#send-asynchronously
+    */
+    pub fn update_aliases(&self) -> IndexUpdateAliasesRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.clone(),
+            IndexUpdateAliasesRequestInner {
+                actions: Vec::new(),
+            },
+        )
+    }
+}
+
+impl IndexUpdateAliasesRequestInner {
+    fn into_request(self) -> Result<IndicesUpdateAliasesRequest<'static, Vec<u8>>, Error> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            actions: &'a [AliasAction],
+        }
+
+        let body = serde_json::to_vec(&Body {
+            actions: &self.actions,
+        })
+        .map_err(error::request)?;
+
+        Ok(IndicesUpdateAliasesRequest::new(body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `IndexUpdateAliasesRequestBuilder` before sending it.
+*/
+impl<TSender> IndexUpdateAliasesRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /** Add an alias for an index. */
+    pub fn add(self, index: impl Into<Index<'static>>, alias: impl Into<Name<'static>>) -> Self {
+        self.add_fluent(index, alias, |alias| alias)
+    }
+
+    /**
+    Add an alias for an index with additional properties.
+
+    # Examples
+
+    Add a filtered alias that's the write index for `myindex`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.update_aliases()
+                         .add_fluent("myindex-v2", "myindex", |alias| alias
+                             .filter(json!({ "term": { "user": "kimchy" } }))
+                             .routing("1")
+                             .is_write_index(true))
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn add_fluent(
+        mut self,
+        index: impl Into<Index<'static>>,
+        alias: impl Into<Name<'static>>,
+        builder: impl FnOnce(AliasBuilder) -> AliasBuilder,
+    ) -> Self {
+        self.inner.actions.push(AliasAction::Add {
+            index: index.into().into(),
+            alias: alias.into().into(),
+            alias_body: builder(AliasBuilder::new()),
+        });
+        self
+    }
+
+    /** Remove an alias from an index. */
+    pub fn remove(
+        mut self,
+        index: impl Into<Index<'static>>,
+        alias: impl Into<Name<'static>>,
+    ) -> Self {
+        self.inner.actions.push(AliasAction::Remove {
+            index: index.into().into(),
+            alias: alias.into().into(),
+        });
+        self
+    }
+
+    /**
+    Delete an index.
+
+    Removing an index in the same request as adding an alias with the same name can be used to replace a concrete index with an alias.
+    */
+    pub fn remove_index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.actions.push(AliasAction::RemoveIndex {
+            index: index.into().into(),
+        });
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexUpdateAliasesRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexUpdateAliasesRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Add an alias called `myalias` for the `myindex` index:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.update_aliases().add("myindex", "myalias").send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexUpdateAliasesRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexUpdateAliasesRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised command response.
+
+    # Examples
+
+    Add an alias called `myalias` for the `myindex` index:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.update_aliases().add("myindex", "myalias").send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<CommandResponse>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.update_aliases().inner.into_request().unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/_aliases", req.url.as_ref());
+        assert_eq!(json!({ "actions": [] }), actual_body);
+    }
+
+    #[test]
+    fn specify_actions() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .update_aliases()
+            .remove("idx-v1", "alias")
+            .add_fluent("idx-v2", "alias", |alias| {
+                alias
+                    .filter(json!({ "term": { "user": "kimchy" } }))
+                    .routing("1")
+                    .is_write_index(true)
+            })
+            .remove_index("idx-v0")
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "actions": [
+                { "remove": { "index": "idx-v1", "alias": "alias" } },
+                {
+                    "add": {
+                        "index": "idx-v2",
+                        "alias": "alias",
+                        "filter": { "term": { "user": "kimchy" } },
+                        "routing": "1",
+                        "is_write_index": true
+                    }
+                },
+                { "remove_index": { "index": "idx-v0" } }
+            ]
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body, actual_body);
+    }
+}
@@ -9,7 +9,10 @@ use std::{
     sync::Arc,
 };
 
-use fluent_builder::SharedFluentBuilder;
+use fluent_builder::{
+    SharedFluentBuilder,
+    Stack,
+};
 use futures::{
     Future,
     Poll,
@@ -45,49 +48,187 @@ pub mod sql;
 pub use self::sql::SqlRequestBuilder;
 
 // Document requests
+pub mod document_check_mapping;
+pub mod document_create;
 pub mod document_delete;
+pub mod document_delete_by_query;
+pub mod document_exists;
+pub mod document_explain;
 pub mod document_get;
+pub mod document_get_source;
 pub mod document_index;
+pub mod document_modify;
+pub mod document_mterm_vectors;
 pub mod document_put_mapping;
+pub mod document_term_vectors;
 pub mod document_update;
+pub mod document_update_by_query;
 
 #[doc(inline)]
 pub use self::{
+    document_check_mapping::CheckMappingRequestBuilder,
+    document_create::CreateRequestBuilder,
     document_delete::DeleteRequestBuilder,
+    document_delete_by_query::DeleteByQueryRequestBuilder,
+    document_exists::ExistsRequestBuilder,
+    document_explain::ExplainRequestBuilder,
     document_get::GetRequestBuilder,
+    document_get_source::GetSourceRequestBuilder,
     document_index::IndexRequestBuilder,
+    document_modify::ModifyRequestBuilder,
+    document_mterm_vectors::MtermVectorsRequestBuilder,
     document_put_mapping::PutMappingRequestBuilder,
+    document_term_vectors::TermVectorsRequestBuilder,
     document_update::UpdateRequestBuilder,
+    document_update_by_query::UpdateByQueryRequestBuilder,
 };
 
 // Index requests
+pub mod index_clear_cache;
 pub mod index_close;
 pub mod index_create;
 pub mod index_delete;
+pub mod index_delete_alias;
+pub mod index_delete_template;
 pub mod index_exists;
+pub mod index_exists_alias;
+pub mod index_exists_template;
+pub mod index_flush;
+pub mod index_forcemerge;
+pub mod index_get_alias;
+pub mod index_get_field_mapping;
+pub mod index_get_mapping;
+pub mod index_get_settings;
+pub mod index_get_template;
 pub mod index_open;
+pub mod index_put_alias;
+pub mod index_put_settings;
+pub mod index_put_template;
+pub mod index_refresh;
+pub mod index_rollover;
+pub mod index_segments;
+pub mod index_settings;
+pub mod index_shrink;
+pub mod index_split;
+pub mod index_stats;
+pub mod index_update_aliases;
 
 #[doc(inline)]
 pub use self::{
+    index_clear_cache::IndexClearCacheRequestBuilder,
     index_close::IndexCloseRequestBuilder,
     index_create::IndexCreateRequestBuilder,
     index_delete::IndexDeleteRequestBuilder,
+    index_delete_alias::IndexDeleteAliasRequestBuilder,
+    index_delete_template::IndexDeleteTemplateRequestBuilder,
     index_exists::IndexExistsRequestBuilder,
+    index_exists_alias::IndexExistsAliasRequestBuilder,
+    index_exists_template::IndexExistsTemplateRequestBuilder,
+    index_flush::IndexFlushRequestBuilder,
+    index_forcemerge::IndexForceMergeRequestBuilder,
+    index_get_alias::IndexGetAliasRequestBuilder,
+    index_get_field_mapping::IndexGetFieldMappingRequestBuilder,
+    index_get_mapping::IndexGetMappingRequestBuilder,
+    index_get_settings::IndexGetSettingsRequestBuilder,
+    index_get_template::IndexGetTemplateRequestBuilder,
     index_open::IndexOpenRequestBuilder,
+    index_put_alias::IndexPutAliasRequestBuilder,
+    index_put_settings::IndexPutSettingsRequestBuilder,
+    index_put_template::IndexPutTemplateRequestBuilder,
+    index_refresh::IndexRefreshRequestBuilder,
+    index_rollover::IndexRolloverRequestBuilder,
+    index_segments::IndexSegmentsRequestBuilder,
+    index_settings::{
+        Analysis,
+        AnalysisComponent,
+        Analyzer,
+        Codec,
+        IndexSettings,
+        Normalizer,
+        RefreshInterval,
+    },
+    index_shrink::IndexShrinkRequestBuilder,
+    index_split::IndexSplitRequestBuilder,
+    index_stats::{
+        IndexStatsRequestBuilder,
+        StatsLevel,
+        StatsMetric,
+    },
+    index_update_aliases::IndexUpdateAliasesRequestBuilder,
+};
+
+// Cluster requests
+pub mod cluster_get_settings;
+pub mod cluster_health;
+pub mod cluster_put_settings;
+pub mod cluster_settings;
+pub mod cluster_state;
+
+#[doc(inline)]
+pub use self::{
+    cluster_get_settings::ClusterGetSettingsRequestBuilder,
+    cluster_health::{
+        ActiveShards,
+        ClusterHealthRequestBuilder,
+        HealthLevel,
+        NodeCount,
+    },
+    cluster_put_settings::ClusterPutSettingsRequestBuilder,
+    cluster_settings::{
+        AllocationEnable,
+        ClusterSetting,
+        ClusterSettings,
+        RebalanceEnable,
+    },
+    cluster_state::{
+        ClusterStateMetric,
+        ClusterStateRequestBuilder,
+    },
+};
+
+// Task requests
+pub mod tasks_cancel;
+pub mod tasks_get;
+pub mod tasks_list;
+
+#[doc(inline)]
+pub use self::{
+    tasks_cancel::TasksCancelRequestBuilder,
+    tasks_get::{
+        PollUntilCompleteBuilder,
+        TasksGetRequestBuilder,
+    },
+    tasks_list::TasksListRequestBuilder,
 };
 
 // Misc requests
 pub mod bulk;
 pub mod ping;
+pub mod reindex;
+pub mod reindex_rethrottle;
 
 #[doc(inline)]
 pub use self::{
     bulk::BulkRequestBuilder,
     ping::PingRequestBuilder,
+    reindex::{
+        OpType,
+        ReindexRemote,
+        ReindexRequestBuilder,
+    },
+    reindex_rethrottle::ReindexRethrottleRequestBuilder,
 };
 
 pub mod common;
 
+#[doc(inline)]
+pub use self::common::{
+    Conflicts,
+    RequestsPerSecond,
+    Slices,
+    VersionType,
+};
+
 /**
 A builder for a request.
 
@@ -104,7 +245,7 @@ where
     TSender: Sender,
 {
     client: Client<TSender>,
-    params_builder: SharedFluentBuilder<RequestParams>,
+    params_builder: SharedFluentBuilder<RequestParams, Stack>,
     inner: TRequest,
 }
 
@@ -127,7 +268,7 @@ where
 
     fn new(
         client: Client<TSender>,
-        builder: SharedFluentBuilder<RequestParams>,
+        builder: SharedFluentBuilder<RequestParams, Stack>,
         req: TRequest,
     ) -> Self {
         RequestBuilder {
@@ -142,7 +283,9 @@ where
 
     This method will box the given closure and use it to mutate the request parameters.
     It will be called after a node address has been chosen so `params` can be used to override the url a request will be sent to.
-    Each call to `params` will be chained so it can be called multiple times but it's recommended to only call once.
+
+    Each call to `params_fluent` is stacked on top of the previous ones rather than replacing them.
+    Parameters set by earlier calls, including the ones set by builder methods like `dry_run`, are kept unless a later call sets the same parameter.
 
     # Examples
 
@@ -293,21 +436,132 @@ pub mod prelude {
         bulk,
         bulk_raw,
         BulkOperation,
+        ErasedDocument,
     };
 
     pub use super::{
+        ActiveShards,
+        AllocationEnable,
+        Analysis,
+        AnalysisComponent,
+        Analyzer,
+        CheckMappingRequestBuilder,
+        ClusterGetSettingsRequestBuilder,
+        ClusterHealthRequestBuilder,
+        ClusterPutSettingsRequestBuilder,
+        ClusterSetting,
+        ClusterSettings,
+        ClusterStateMetric,
+        ClusterStateRequestBuilder,
+        Codec,
+        Conflicts,
+        CreateRequestBuilder,
+        DeleteByQueryRequestBuilder,
         DeleteRequestBuilder,
+        ExistsRequestBuilder,
+        ExplainRequestBuilder,
         GetRequestBuilder,
+        GetSourceRequestBuilder,
+        HealthLevel,
+        IndexClearCacheRequestBuilder,
         IndexCloseRequestBuilder,
         IndexCreateRequestBuilder,
+        IndexDeleteAliasRequestBuilder,
         IndexDeleteRequestBuilder,
+        IndexDeleteTemplateRequestBuilder,
+        IndexExistsAliasRequestBuilder,
+        IndexExistsTemplateRequestBuilder,
+        IndexFlushRequestBuilder,
+        IndexForceMergeRequestBuilder,
+        IndexGetAliasRequestBuilder,
+        IndexGetFieldMappingRequestBuilder,
+        IndexGetMappingRequestBuilder,
+        IndexGetSettingsRequestBuilder,
+        IndexGetTemplateRequestBuilder,
         IndexOpenRequestBuilder,
+        IndexPutAliasRequestBuilder,
+        IndexPutSettingsRequestBuilder,
+        IndexPutTemplateRequestBuilder,
+        IndexRefreshRequestBuilder,
         IndexRequestBuilder,
+        IndexRolloverRequestBuilder,
+        IndexSegmentsRequestBuilder,
+        IndexSettings,
+        IndexShrinkRequestBuilder,
+        IndexSplitRequestBuilder,
+        IndexStatsRequestBuilder,
+        IndexUpdateAliasesRequestBuilder,
+        ModifyRequestBuilder,
+        MtermVectorsRequestBuilder,
+        NodeCount,
+        Normalizer,
+        OpType,
         PingRequestBuilder,
+        PollUntilCompleteBuilder,
         PutMappingRequestBuilder,
         RawRequestBuilder,
+        RebalanceEnable,
+        RefreshInterval,
+        ReindexRemote,
+        ReindexRequestBuilder,
+        ReindexRethrottleRequestBuilder,
+        RequestsPerSecond,
         SearchRequestBuilder,
+        Slices,
         SqlRequestBuilder,
+        StatsLevel,
+        StatsMetric,
+        TasksCancelRequestBuilder,
+        TasksGetRequestBuilder,
+        TasksListRequestBuilder,
+        TermVectorsRequestBuilder,
+        UpdateByQueryRequestBuilder,
         UpdateRequestBuilder,
+        VersionType,
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn params_fluent_calls_are_stacked() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .ping()
+            .params_fluent(|params| params.url_param("a", 1))
+            .params_fluent(|params| params.url_param("b", 2))
+            .params_fluent(|params| params.url_param("a", 3))
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("a=3"));
+        assert!(qry.contains("b=2"));
+        assert!(!qry.contains("a=1"));
+    }
+
+    #[test]
+    fn params_fluent_is_stacked_on_builder_methods() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .index("myalias")
+            .rollover()
+            .dry_run()
+            .params_fluent(|params| params.url_param("wait_for_active_shards", 1))
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("dry_run=true"));
+        assert!(qry.contains("wait_for_active_shards=1"));
+    }
+}
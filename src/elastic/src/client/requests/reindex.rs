@@ -0,0 +1,699 @@
+/*!
+Builders for [reindex requests][docs-reindex].
+
+[docs-reindex]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html
+*/
+
+use futures::Future;
+use serde::{
+    de::DeserializeOwned,
+    ser::Serialize,
+};
+use serde_json::{
+    self,
+    Value,
+};
+use std::{
+    fmt,
+    marker::PhantomData,
+    time::Duration,
+};
+
+use crate::{
+    client::{
+        requests::{
+            common::{
+                format_duration,
+                Conflicts,
+                DefaultParams,
+                RequestsPerSecond,
+                Script,
+                ScriptBuilder,
+                Slices,
+            },
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::{
+            BulkByScrollResponse,
+            TaskResponse,
+        },
+        Client,
+    },
+    endpoints::ReindexRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::{
+        receiver::IsOk,
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+    },
+    params::Index,
+};
+
+/**
+A [reindex request][docs-reindex] builder that can be configured before sending.
+
+Call [`Client.reindex`][Client.reindex] to get a `ReindexRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+By default, sending the request waits for all documents to be copied and returns a [`BulkByScrollResponse`][BulkByScrollResponse].
+Call [`as_task`](#method.as_task) to return a handle to a background task instead.
+
+[docs-reindex]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.reindex]: ../../struct.Client.html#reindex-request
+[BulkByScrollResponse]: ../../responses/struct.BulkByScrollResponse.html
+*/
+pub type ReindexRequestBuilder<TSender, TParams = DefaultParams, TResponse = BulkByScrollResponse> =
+    RequestBuilder<TSender, ReindexRequestInner<TParams, TResponse>>;
+
+#[doc(hidden)]
+pub struct ReindexRequestInner<TParams, TResponse> {
+    source: ReindexSource,
+    dest: ReindexDest,
+    script: Option<Script<TParams>>,
+    _marker: PhantomData<TResponse>,
+}
+
+#[derive(Serialize)]
+struct ReindexSource {
+    index: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remote: Option<ReindexRemote>,
+}
+
+#[derive(Serialize)]
+struct ReindexDest {
+    index: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    op_type: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pipeline: Option<String>,
+}
+
+/**
+A remote cluster to reindex documents from.
+
+The remote host needs to be whitelisted in the `reindex.remote.whitelist` setting of the destination cluster.
+
+# Examples
+
+Reindex from a remote cluster that requires authentication:
+
+```no_run
+# use elastic::prelude::*;
+# use std::time::Duration;
+# fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+# let client = SyncClientBuilder::new().build()?;
+let remote = ReindexRemote::new("http://otherhost:9200")
+    .credentials("user", "password")
+    .socket_timeout(Duration::from_secs(60));
+
+let response = client.reindex("source", "dest")
+                     .remote(remote)
+                     .send()?;
+# Ok(())
+# }
+```
+*/
+#[derive(Serialize, Clone)]
+pub struct ReindexRemote {
+    host: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    socket_timeout: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    connect_timeout: Option<String>,
+}
+
+impl ReindexRemote {
+    /** Reindex from the cluster at the given host, like `http://otherhost:9200`. */
+    pub fn new(host: impl Into<String>) -> Self {
+        ReindexRemote {
+            host: host.into(),
+            username: None,
+            password: None,
+            socket_timeout: None,
+            connect_timeout: None,
+        }
+    }
+
+    /** Authenticate with the remote cluster using basic authentication. */
+    pub fn credentials(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self.password = Some(password.into());
+        self
+    }
+
+    /** Set how long to wait for each read from the remote cluster. */
+    pub fn socket_timeout(mut self, timeout: Duration) -> Self {
+        self.socket_timeout = Some(format_duration(timeout));
+        self
+    }
+
+    /** Set how long to wait for a connection to the remote cluster. */
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(format_duration(timeout));
+        self
+    }
+}
+
+impl fmt::Debug for ReindexRemote {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ReindexRemote")
+            .field("host", &self.host)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "[redacted]"))
+            .field("socket_timeout", &self.socket_timeout)
+            .field("connect_timeout", &self.connect_timeout)
+            .finish()
+    }
+}
+
+/** How documents are written to the destination index. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OpType {
+    /** Create new documents and overwrite existing ones. This is the default. */
+    Index,
+    /** Only create documents that are missing from the destination index. */
+    Create,
+}
+
+impl OpType {
+    fn as_str(self) -> &'static str {
+        match self {
+            OpType::Index => "index",
+            OpType::Create => "create",
+        }
+    }
+}
+
+/**
+# Reindex request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`ReindexRequestBuilder`][ReindexRequestBuilder] with this `Client` that can be configured before sending.
+
+    Documents are copied from the `source` index to the `dest` index.
+    The destination index should be created with its mappings before reindexing.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Copy the documents in `source` that belong to a user into `dest`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.reindex("source", "dest")
+                         .query(json!({
+                             "term": {
+                                 "user": "kimchy"
+                             }
+                         }))
+                         .op_type(OpType::Create)
+                         .conflicts(Conflicts::Proceed)
+                         .send()?;
+
+    println!("created {} documents", response.created());
+    # Ok(())
+    # }
+    ```
+
+    [ReindexRequestBuilder]: requests/reindex/type.ReindexRequestBuilder.html
+    [builder-methods]: requests/reindex/type.ReindexRequestBuilder.html#builder-methods
+    [send-sync]: requests/reindex/type.ReindexRequestBuilder.html#send-synchronously
+    [send-async]: requests/reindex/type.ReindexRequestBuilder.html#send-asynchronously
+    */
+    pub fn reindex(
+        &self,
+        source: impl Into<Index<'static>>,
+        dest: impl Into<Index<'static>>,
+    ) -> ReindexRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.clone(),
+            ReindexRequestInner {
+                source: ReindexSource {
+                    index: source.into().to_string(),
+                    query: None,
+                    size: None,
+                    remote: None,
+                },
+                dest: ReindexDest {
+                    index: dest.into().to_string(),
+                    op_type: None,
+                    pipeline: None,
+                },
+                script: None,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<TParams, TResponse> ReindexRequestInner<TParams, TResponse>
+where
+    TParams: Serialize,
+{
+    fn into_request(self) -> Result<ReindexRequest<'static, Vec<u8>>, Error> {
+        #[derive(Serialize)]
+        struct Body<'a, TParams> {
+            source: &'a ReindexSource,
+            dest: &'a ReindexDest,
+            #[serde(flatten)]
+            script: Option<&'a Script<TParams>>,
+        }
+
+        let body = serde_json::to_vec(&Body {
+            source: &self.source,
+            dest: &self.dest,
+            script: self.script.as_ref(),
+        })
+        .map_err(error::request)?;
+
+        Ok(ReindexRequest::new(body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `ReindexRequestBuilder` before sending it.
+*/
+impl<TSender, TParams, TResponse> ReindexRequestBuilder<TSender, TParams, TResponse>
+where
+    TSender: Sender,
+{
+    /** Only reindex documents in the source index that match the given query. */
+    pub fn query(mut self, query: impl Into<Value>) -> Self {
+        self.inner.source.query = Some(query.into());
+        self
+    }
+
+    /**
+    Set the number of documents to fetch from the source index in each batch.
+
+    The default batch size is `1000`.
+    */
+    pub fn size(mut self, size: u32) -> Self {
+        self.inner.source.size = Some(size);
+        self
+    }
+
+    /**
+    Reindex documents from a remote cluster.
+
+    The source index is read from the remote cluster instead of the one the request is sent to.
+    Remote reindexing can't be split into slices.
+    */
+    pub fn remote(mut self, remote: ReindexRemote) -> Self {
+        self.inner.source.remote = Some(remote);
+        self
+    }
+
+    /** Set how documents are written to the destination index. */
+    pub fn op_type(mut self, op_type: OpType) -> Self {
+        self.inner.dest.op_type = Some(op_type.as_str());
+        self
+    }
+
+    /** Run documents through the given ingest pipeline before writing them to the destination index. */
+    pub fn pipeline(mut self, pipeline: impl Into<String>) -> Self {
+        self.inner.dest.pipeline = Some(pipeline.into());
+        self
+    }
+
+    /**
+    Transform each document using [a script][painless-lang] before writing it to the destination index.
+
+    The script can change `ctx._source`, or the metadata like `ctx._index` and `ctx._id`.
+
+    # Examples
+
+    Rename a field while reindexing:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.reindex("source", "dest")
+                         .script("ctx._source.tag = ctx._source.remove(\"flag\")")
+                         .send()?;
+    # Ok(())
+    # }
+    ```
+
+    [painless-lang]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-painless.html
+    */
+    pub fn script<TScript, TNewParams>(
+        self,
+        builder: TScript,
+    ) -> ReindexRequestBuilder<TSender, TNewParams, TResponse>
+    where
+        TScript: Into<ScriptBuilder<TNewParams>>,
+    {
+        RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            ReindexRequestInner {
+                source: self.inner.source,
+                dest: self.inner.dest,
+                script: Some(builder.into().build()),
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    /**
+    Transform each document using [a script][painless-lang] configured by a fluent closure API.
+
+    [painless-lang]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-painless.html
+    */
+    pub fn script_fluent<TScript, TNewParams>(
+        self,
+        source: TScript,
+        builder: impl FnOnce(ScriptBuilder<DefaultParams>) -> ScriptBuilder<TNewParams>,
+    ) -> ReindexRequestBuilder<TSender, TNewParams, TResponse>
+    where
+        TScript: ToString,
+    {
+        let builder = builder(ScriptBuilder::new(source));
+
+        self.script(builder)
+    }
+
+    /**
+    Set what to do when a document conflicts with one in the destination index.
+
+    Conflicts are only possible when the op type is [`OpType::Create`](enum.OpType.html#variant.Create).
+    */
+    pub fn conflicts(self, conflicts: Conflicts) -> Self {
+        self.params_fluent(move |params| params.url_param("conflicts", conflicts.as_str()))
+    }
+
+    /** Split the request into slices that are processed in parallel. */
+    pub fn slices(self, slices: impl Into<Slices>) -> Self {
+        let slices = slices.into();
+
+        self.params_fluent(move |params| params.url_param("slices", slices))
+    }
+
+    /**
+    Throttle the request so that it doesn't overwhelm the cluster.
+
+    The throttle can be changed while the request is running with a [reindex rethrottle request](../../struct.Client.html#reindex-rethrottle-request).
+    */
+    pub fn requests_per_second(self, requests_per_second: impl Into<RequestsPerSecond>) -> Self {
+        let requests_per_second = requests_per_second.into();
+
+        self.params_fluent(move |params| {
+            params.url_param("requests_per_second", requests_per_second)
+        })
+    }
+
+    /** Refresh the destination index once the request has finished so the documents are visible to search. */
+    pub fn refresh(self) -> Self {
+        self.params_fluent(|params| params.url_param("refresh", true))
+    }
+
+    /**
+    Run the request as a background task instead of waiting for it to finish.
+
+    Sending the request will return a [`TaskResponse`][TaskResponse] as soon as the task has started.
+
+    # Examples
+
+    Start copying documents from `source` to `dest` in the background:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.reindex("source", "dest")
+                         .slices(Slices::Auto)
+                         .requests_per_second(1000.0)
+                         .as_task()
+                         .send()?;
+
+    println!("started task {}", response.task());
+    # Ok(())
+    # }
+    ```
+
+    [TaskResponse]: ../../responses/struct.TaskResponse.html
+    */
+    pub fn as_task(self) -> ReindexRequestBuilder<TSender, TParams, TaskResponse> {
+        let builder = RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            ReindexRequestInner {
+                source: self.inner.source,
+                dest: self.inner.dest,
+                script: self.inner.script,
+                _marker: PhantomData,
+            },
+        );
+
+        builder.params_fluent(|params| params.url_param("wait_for_completion", false))
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TParams, TResponse> ReindexRequestBuilder<SyncSender, TParams, TResponse>
+where
+    TParams: Serialize,
+    TResponse: IsOk + DeserializeOwned,
+{
+    /**
+    Send a `ReindexRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Copy all documents from `source` to `dest`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.reindex("source", "dest").send()?;
+
+    println!("copied {} documents", response.total());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<TResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TParams, TResponse> ReindexRequestBuilder<AsyncSender, TParams, TResponse>
+where
+    TParams: Serialize + Send + 'static,
+    TResponse: IsOk + DeserializeOwned + Send + 'static,
+{
+    /**
+    Send a `ReindexRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised reindex response.
+
+    # Examples
+
+    Copy all documents from `source` to `dest`:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.reindex("source", "dest").send();
+
+    future.and_then(|response| {
+        println!("copied {} documents", response.total());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<TResponse> {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending<TResponse = BulkByScrollResponse> = BasePending<TResponse>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+    use std::time::Duration;
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+        assert_send::<super::Pending<TaskResponse>>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .reindex("source", "dest")
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        let expected_body = json!({
+            "source": { "index": "source" },
+            "dest": { "index": "dest" }
+        });
+
+        assert_eq!("/_reindex", req.url.as_ref());
+        assert_eq!(expected_body, actual_body);
+    }
+
+    #[test]
+    fn specify_source_and_dest() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .reindex("source", "dest")
+            .query(json!({ "term": { "user": "kimchy" } }))
+            .size(100)
+            .remote(
+                ReindexRemote::new("http://otherhost:9200")
+                    .credentials("user", "pass")
+                    .socket_timeout(Duration::from_secs(60)),
+            )
+            .op_type(OpType::Create)
+            .pipeline("some-pipeline")
+            .script_fluent("ctx._source.n = params.n", |script| script.param("n", 1))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        let expected_body = json!({
+            "source": {
+                "index": "source",
+                "query": { "term": { "user": "kimchy" } },
+                "size": 100,
+                "remote": {
+                    "host": "http://otherhost:9200",
+                    "username": "user",
+                    "password": "pass",
+                    "socket_timeout": "1m"
+                }
+            },
+            "dest": {
+                "index": "dest",
+                "op_type": "create",
+                "pipeline": "some-pipeline"
+            },
+            "script": {
+                "inline": "ctx._source.n = params.n",
+                "params": { "n": 1 }
+            }
+        });
+
+        assert_eq!(expected_body, actual_body);
+    }
+
+    #[test]
+    fn specify_params() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .reindex("source", "dest")
+            .conflicts(Conflicts::Proceed)
+            .slices(Slices::Auto)
+            .requests_per_second(100.5)
+            .as_task()
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("conflicts=proceed"));
+        assert!(qry.contains("slices=auto"));
+        assert!(qry.contains("requests_per_second=100.5"));
+        assert!(qry.contains("wait_for_completion=false"));
+    }
+
+    #[test]
+    fn remote_debug_redacts_password() {
+        let remote = ReindexRemote::new("http://otherhost:9200").credentials("user", "secret");
+
+        let debug = format!("{:?}", remote);
+
+        assert!(debug.contains("user"));
+        assert!(debug.contains("[redacted]"));
+        assert!(!debug.contains("secret"));
+    }
+}
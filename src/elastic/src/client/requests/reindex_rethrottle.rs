@@ -0,0 +1,226 @@
+/*!
+Builders for [reindex rethrottle requests][docs-reindex-rethrottle].
+
+[docs-reindex-rethrottle]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html#docs-reindex-rethrottle
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            common::RequestsPerSecond,
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::ReindexRethrottleResponse,
+        Client,
+    },
+    endpoints::ReindexRethrottleRequest,
+    error::Error,
+    http::{
+        empty_body,
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+        DefaultBody,
+    },
+    params::TaskId,
+};
+
+/**
+A [reindex rethrottle request][docs-reindex-rethrottle] builder that can be configured before sending.
+
+Call [`Client.reindex_rethrottle`][Client.reindex_rethrottle] to get a `ReindexRethrottleRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-reindex-rethrottle]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html#docs-reindex-rethrottle
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.reindex_rethrottle]: ../../struct.Client.html#reindex-rethrottle-request
+*/
+pub type ReindexRethrottleRequestBuilder<TSender> =
+    RequestBuilder<TSender, ReindexRethrottleRequestInner>;
+
+#[doc(hidden)]
+pub struct ReindexRethrottleRequestInner {
+    task: TaskId<'static>,
+}
+
+/**
+# Reindex rethrottle request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`ReindexRethrottleRequestBuilder`][ReindexRethrottleRequestBuilder] with this `Client` that can be configured before sending.
+
+    Speeding up a reindex takes effect immediately.
+    Slowing one down takes effect after the current batch has been processed.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Stop throttling a reindex that was started as a background task:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let task = client.reindex("source", "dest")
+                     .requests_per_second(100.0)
+                     .as_task()
+                     .send()?;
+
+    let response = client.reindex_rethrottle(task.task().to_owned(), RequestsPerSecond::Unlimited)
+                         .send()?;
+
+    for task in response.tasks() {
+        if let Some(status) = task.status() {
+            println!("{}: {} of {} documents", task.task(), status.processed(), status.total());
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [ReindexRethrottleRequestBuilder]: requests/reindex_rethrottle/type.ReindexRethrottleRequestBuilder.html
+    [send-sync]: requests/reindex_rethrottle/type.ReindexRethrottleRequestBuilder.html#send-synchronously
+    [send-async]: requests/reindex_rethrottle/type.ReindexRethrottleRequestBuilder.html#send-asynchronously
+    */
+    pub fn reindex_rethrottle(
+        &self,
+        task: impl Into<TaskId<'static>>,
+        requests_per_second: impl Into<RequestsPerSecond>,
+    ) -> ReindexRethrottleRequestBuilder<TSender> {
+        let requests_per_second = requests_per_second.into();
+
+        RequestBuilder::initial(
+            self.clone(),
+            ReindexRethrottleRequestInner { task: task.into() },
+        )
+        .params_fluent(move |params| params.url_param("requests_per_second", requests_per_second))
+    }
+}
+
+impl ReindexRethrottleRequestInner {
+    fn into_request(self) -> ReindexRethrottleRequest<'static, DefaultBody> {
+        ReindexRethrottleRequest::for_task_id(self.task, empty_body())
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl ReindexRethrottleRequestBuilder<SyncSender> {
+    /**
+    Send a `ReindexRethrottleRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Throttle a running reindex task to 500 documents per second:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.reindex_rethrottle("oTUltX4IQMOUUVeiohTt8A:12345", 500.0).send()?;
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<ReindexRethrottleResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl ReindexRethrottleRequestBuilder<AsyncSender> {
+    /**
+    Send a `ReindexRethrottleRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised reindex rethrottle response.
+
+    # Examples
+
+    Throttle a running reindex task to 500 documents per second:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.reindex_rethrottle("oTUltX4IQMOUUVeiohTt8A:12345", 500.0).send();
+
+    future.and_then(|response| {
+        println!("{:?}", response);
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<ReindexRethrottleResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let builder = client.reindex_rethrottle("node:1", RequestsPerSecond::Unlimited);
+
+        let params = builder.params_builder.into_value(RequestParams::default);
+        let req = builder.inner.into_request();
+
+        let (_, qry) = params.get_url_qry();
+
+        assert_eq!("/_reindex/node:1/_rethrottle", req.url.as_ref());
+        assert!(qry.unwrap().contains("requests_per_second=-1"));
+    }
+}
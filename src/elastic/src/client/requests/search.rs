@@ -11,6 +11,7 @@ use std::marker::PhantomData;
 use crate::{
     client::{
         requests::{
+            common::field_list,
             raw::RawRequestInner,
             Pending as BasePending,
             RequestBuilder,
@@ -32,6 +33,7 @@ use crate::{
     },
     params::{
         Index,
+        Routing,
         Type,
     },
     types::document::DocumentType,
@@ -272,6 +274,141 @@ where
         self
     }
 
+    /**
+    Only search the shards for the given routing value.
+
+    Searching with a routing value is cheaper than searching all shards, but documents with a different routing value on the same shards will still be returned.
+    The routing value can be a comma-separated list of values.
+    */
+    pub fn routing(self, routing: impl Into<Routing<'static>>) -> Self {
+        let routing = routing.into().to_string();
+
+        self.params_fluent(move |params| params.url_param("routing", &routing))
+    }
+
+    /**
+    Set whether or not to return the `_source` of each hit.
+
+    Disabling the source is useful when only stored fields or doc value fields are needed.
+    */
+    pub fn source(self, source: bool) -> Self {
+        self.params_fluent(move |params| params.url_param("_source", source))
+    }
+
+    /**
+    Only return the given fields of the `_source` of each hit.
+
+    Fields can contain wildcards, like `address.*`.
+    */
+    pub fn source_includes<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let fields = field_list(fields);
+
+        self.params_fluent(move |params| params.url_param("_source_includes", &fields))
+    }
+
+    /**
+    Don't return the given fields of the `_source` of each hit.
+
+    Fields can contain wildcards, like `address.*`.
+    */
+    pub fn source_excludes<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let fields = field_list(fields);
+
+        self.params_fluent(move |params| params.url_param("_source_excludes", &fields))
+    }
+
+    /**
+    Return the given stored fields for each hit.
+
+    Only fields that are mapped with `store` will be returned.
+    They're available on each hit through [`fields`][Hit.fields].
+
+    [Hit.fields]: ../../responses/search/struct.Hit.html#method.fields
+    */
+    pub fn stored_fields<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let fields = field_list(fields);
+
+        self.params_fluent(move |params| params.url_param("stored_fields", &fields))
+    }
+
+    /**
+    Return the doc values of the given fields for each hit.
+
+    Doc values are read from the on-disk columnar store instead of the `_source`, so they're cheap to fetch for keywords, numbers and dates.
+    They're available on each hit through [`fields`][Hit.fields].
+
+    [Hit.fields]: ../../responses/search/struct.Hit.html#method.fields
+    */
+    pub fn docvalue_fields<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let fields = field_list(fields);
+
+        self.params_fluent(move |params| params.url_param("docvalue_fields", &fields))
+    }
+
+    /**
+    Deserialise the `_source` of each hit into a different type.
+
+    This is useful along with [`source_includes`](#method.source_includes) to only fetch part of large documents.
+
+    # Examples
+
+    Only search for the titles of `MyType` documents:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType { title: String, body: String }
+    #[derive(Debug, Deserialize)]
+    struct Summary {
+        title: String,
+    }
+
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<MyType>()
+                         .search()
+                         .source_includes(vec!["title"])
+                         .project::<Summary>()
+                         .send()?;
+
+    for summary in response.documents() {
+        println!("{}", summary.title);
+    }
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn project<TProjection>(self) -> SearchRequestBuilder<TSender, TProjection, TBody> {
+        RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            SearchRequestInner {
+                body: self.inner.body,
+                index: self.inner.index,
+                ty: self.inner.ty,
+                _marker: PhantomData,
+            },
+        )
+    }
+
     /**
     Set the body for the search request.
 
@@ -465,4 +602,57 @@ mod tests {
 
         assert_eq!("{}", req.body);
     }
+
+    #[test]
+    fn specify_routing() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .search::<Value>()
+            .routing("tenant-1")
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+
+        assert!(qry.unwrap().contains("routing=tenant-1"));
+    }
+
+    #[test]
+    fn specify_fields() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .search::<Value>()
+            .source(false)
+            .stored_fields(vec!["title"])
+            .docvalue_fields(vec!["timestamp", "tags"])
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("_source=false"));
+        assert!(qry.contains("stored_fields=title"));
+        assert!(qry.contains("docvalue_fields=timestamp%2Ctags"));
+    }
+
+    #[test]
+    fn specify_source_filtering() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .search::<Value>()
+            .source_includes(vec!["title", "author.*"])
+            .source_excludes(vec!["author.email"])
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("_source_includes=title%2Cauthor.*"));
+        assert!(qry.contains("_source_excludes=author.email"));
+    }
 }
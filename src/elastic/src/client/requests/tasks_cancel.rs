@@ -0,0 +1,214 @@
+/*!
+Builders for [cancel task requests][docs-tasks-cancel].
+
+[docs-tasks-cancel]: https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html#task-cancellation
+*/
+
+use futures::Future;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::TasksCancelResponse,
+        TasksClient,
+    },
+    endpoints::TasksCancelRequest,
+    error::Error,
+    http::{
+        empty_body,
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+        DefaultBody,
+    },
+    params::TaskId,
+};
+
+/**
+A [cancel task request][docs-tasks-cancel] builder that can be configured before sending.
+
+Call [`Client.tasks.cancel`][Client.tasks.cancel] to get a `TasksCancelRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-tasks-cancel]: https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html#task-cancellation
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.tasks.cancel]: ../../struct.TasksClient.html#cancel-task-request
+*/
+pub type TasksCancelRequestBuilder<TSender> = RequestBuilder<TSender, TasksCancelRequestInner>;
+
+#[doc(hidden)]
+pub struct TasksCancelRequestInner {
+    task: TaskId<'static>,
+}
+
+/**
+# Cancel task request
+*/
+impl<TSender> TasksClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`TasksCancelRequestBuilder`][TasksCancelRequestBuilder] with this `Client` that can be configured before sending.
+
+    Only tasks that are cancellable can be cancelled.
+    Cancelling a task that has sub-tasks, like a sliced reindex, will also cancel its sub-tasks.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Cancel a reindex that was started as a background task:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let task = client.reindex("source", "dest")
+                     .as_task()
+                     .send()?;
+
+    let response = client.tasks()
+                         .cancel(task.task().to_owned())
+                         .send()?;
+
+    assert!(response.task_failures().is_empty());
+    # Ok(())
+    # }
+    ```
+
+    [TasksCancelRequestBuilder]: requests/tasks_cancel/type.TasksCancelRequestBuilder.html
+    [send-sync]: requests/tasks_cancel/type.TasksCancelRequestBuilder.html#send-synchronously
+    [send-async]: requests/tasks_cancel/type.TasksCancelRequestBuilder.html#send-asynchronously
+    */
+    pub fn cancel(self, task: impl Into<TaskId<'static>>) -> TasksCancelRequestBuilder<TSender> {
+        RequestBuilder::initial(self.inner, TasksCancelRequestInner { task: task.into() })
+    }
+}
+
+impl TasksCancelRequestInner {
+    fn into_request(self) -> TasksCancelRequest<'static, DefaultBody> {
+        TasksCancelRequest::for_task_id(self.task, empty_body())
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl TasksCancelRequestBuilder<SyncSender> {
+    /**
+    Send a `TasksCancelRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Cancel a task:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.tasks()
+                         .cancel("oTUltX4IQMOUUVeiohTt8A:12345")
+                         .send()?;
+
+    for task in response.tasks() {
+        println!("cancelled {}", task.task());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<TasksCancelResponse, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl TasksCancelRequestBuilder<AsyncSender> {
+    /**
+    Send a `TasksCancelRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised cancel task response.
+
+    # Examples
+
+    Cancel a task:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.tasks()
+                       .cancel("oTUltX4IQMOUUVeiohTt8A:12345")
+                       .send();
+
+    future.and_then(|response| {
+        for task in response.tasks() {
+            println!("cancelled {}", task.task());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending = BasePending<TasksCancelResponse>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.tasks().cancel("node1:12").inner.into_request();
+
+        assert_eq!("/_tasks/node1:12/_cancel", req.url.as_ref());
+    }
+}
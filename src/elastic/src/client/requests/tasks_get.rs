@@ -0,0 +1,665 @@
+/*!
+Builders for [get task requests][docs-tasks].
+
+[docs-tasks]: https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html
+*/
+
+use futures::{
+    future::{
+        self,
+        Either,
+        Loop,
+    },
+    Future,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::{
+    marker::PhantomData,
+    thread,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+use tokio::timer::Delay;
+
+use crate::{
+    client::{
+        requests::{
+            common::format_duration,
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::TasksGetResponse,
+        TasksClient,
+    },
+    endpoints::TasksGetRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::TaskId,
+};
+
+/**
+A [get task request][docs-tasks] builder that can be configured before sending.
+
+Call [`Client.tasks.get`][Client.tasks.get] to get a `TasksGetRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-tasks]: https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.tasks.get]: ../../struct.TasksClient.html#get-task-request
+*/
+pub type TasksGetRequestBuilder<TSender, TStatus = Value, TResponse = Value> =
+    RequestBuilder<TSender, TasksGetRequestInner<TStatus, TResponse>>;
+
+#[doc(hidden)]
+pub struct TasksGetRequestInner<TStatus, TResponse> {
+    task: TaskId<'static>,
+    _marker: PhantomData<(TStatus, TResponse)>,
+}
+
+/**
+# Get task request
+*/
+impl<TSender> TasksClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`TasksGetRequestBuilder`][TasksGetRequestBuilder] with this `Client` that can be configured before sending.
+
+    The response contains the current progress of the task and, once it's completed, the result of the request it ran.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check on the progress of a reindex task:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let task = client.reindex("source", "dest")
+                     .as_task()
+                     .send()?;
+
+    let response = client.tasks()
+                         .get(task.task().to_owned())
+                         .status::<BulkByScrollStatus>()
+                         .response::<BulkByScrollResponse>()
+                         .send()?;
+
+    if let Some(status) = response.task().status() {
+        println!("{} of {} documents", status.processed(), status.total());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [TasksGetRequestBuilder]: requests/tasks_get/type.TasksGetRequestBuilder.html
+    [builder-methods]: requests/tasks_get/type.TasksGetRequestBuilder.html#builder-methods
+    [send-sync]: requests/tasks_get/type.TasksGetRequestBuilder.html#send-synchronously
+    [send-async]: requests/tasks_get/type.TasksGetRequestBuilder.html#send-asynchronously
+    */
+    pub fn get(self, task: impl Into<TaskId<'static>>) -> TasksGetRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            TasksGetRequestInner {
+                task: task.into(),
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<TStatus, TResponse> TasksGetRequestInner<TStatus, TResponse> {
+    fn into_request(self) -> TasksGetRequest<'static> {
+        TasksGetRequest::for_task_id(self.task)
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `TasksGetRequestBuilder` before sending it.
+*/
+impl<TSender, TStatus, TResponse> TasksGetRequestBuilder<TSender, TStatus, TResponse>
+where
+    TSender: Sender,
+{
+    /** Wait for the task to complete before returning. */
+    pub fn wait_for_completion(self) -> Self {
+        self.params_fluent(|params| params.url_param("wait_for_completion", true))
+    }
+
+    /**
+    Set how long to wait for the task to complete.
+
+    If the task doesn't complete before the timeout then an error will be returned.
+    */
+    pub fn timeout(self, timeout: Duration) -> Self {
+        let timeout = format_duration(timeout);
+
+        self.params_fluent(move |params| params.url_param("timeout", timeout.clone()))
+    }
+
+    /**
+    Set the type to deserialise the status of the task as.
+
+    By default the status is deserialised as a `serde_json::Value`.
+    */
+    pub fn status<TNewStatus>(self) -> TasksGetRequestBuilder<TSender, TNewStatus, TResponse> {
+        RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            TasksGetRequestInner {
+                task: self.inner.task,
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    /**
+    Set the type to deserialise the result of the completed task as.
+
+    By default the result is deserialised as a `serde_json::Value`.
+    */
+    pub fn response<TNewResponse>(self) -> TasksGetRequestBuilder<TSender, TStatus, TNewResponse> {
+        RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            TasksGetRequestInner {
+                task: self.inner.task,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TStatus, TResponse> TasksGetRequestBuilder<SyncSender, TStatus, TResponse>
+where
+    TStatus: DeserializeOwned,
+    TResponse: DeserializeOwned,
+{
+    /**
+    Send a `TasksGetRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Wait up to a minute for a task to complete:
+
+    ```no_run
+    # use std::time::Duration;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.tasks()
+                         .get("oTUltX4IQMOUUVeiohTt8A:12345")
+                         .wait_for_completion()
+                         .timeout(Duration::from_secs(60))
+                         .send()?;
+
+    assert!(response.completed());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<TasksGetResponse<TStatus, TResponse>, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TStatus, TResponse> TasksGetRequestBuilder<AsyncSender, TStatus, TResponse>
+where
+    TStatus: DeserializeOwned + Send + 'static,
+    TResponse: DeserializeOwned + Send + 'static,
+{
+    /**
+    Send a `TasksGetRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised get task response.
+
+    # Examples
+
+    Check whether a task has completed:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.tasks()
+                       .get("oTUltX4IQMOUUVeiohTt8A:12345")
+                       .send();
+
+    future.and_then(|response| {
+        println!("completed: {}", response.completed());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<TStatus, TResponse> {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/**
+A builder that repeatedly gets a task until it completes.
+
+Call [`Client.tasks.poll_until_complete`][Client.tasks.poll_until_complete] to get a `PollUntilCompleteBuilder`.
+The `send` method will either poll [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.tasks.poll_until_complete]: ../../struct.TasksClient.html#poll-until-complete
+*/
+pub struct PollUntilCompleteBuilder<TSender, TResponse>
+where
+    TSender: Sender,
+{
+    tasks: TasksClient<TSender>,
+    task: TaskId<'static>,
+    interval: Duration,
+    timeout: Option<Duration>,
+    _marker: PhantomData<TResponse>,
+}
+
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 1;
+
+/**
+# Poll until complete
+*/
+impl<TSender> TasksClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`PollUntilCompleteBuilder`][PollUntilCompleteBuilder] with this `Client` that repeatedly gets a task until it completes, then returns the result of the request it ran.
+
+    The task is requested again every second while it's still running, which can be changed with [`interval`][interval].
+    By default polling continues until the task completes, which can be limited with [`timeout`][timeout].
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Start a reindex in the background and wait up to an hour for it to finish:
+
+    ```no_run
+    # use std::time::Duration;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let task = client.reindex("source", "dest")
+                     .as_task()
+                     .send()?;
+
+    let response = client.tasks()
+                         .poll_until_complete::<BulkByScrollResponse>(task.task().to_owned())
+                         .timeout(Duration::from_secs(60 * 60))
+                         .send()?;
+
+    println!("created {} documents", response.created());
+    # Ok(())
+    # }
+    ```
+
+    [PollUntilCompleteBuilder]: requests/tasks_get/struct.PollUntilCompleteBuilder.html
+    [interval]: requests/tasks_get/struct.PollUntilCompleteBuilder.html#method.interval
+    [timeout]: requests/tasks_get/struct.PollUntilCompleteBuilder.html#method.timeout
+    [builder-methods]: requests/tasks_get/struct.PollUntilCompleteBuilder.html#builder-methods
+    [send-sync]: requests/tasks_get/struct.PollUntilCompleteBuilder.html#send-synchronously
+    [send-async]: requests/tasks_get/struct.PollUntilCompleteBuilder.html#send-asynchronously
+    */
+    pub fn poll_until_complete<TResponse>(
+        self,
+        task: impl Into<TaskId<'static>>,
+    ) -> PollUntilCompleteBuilder<TSender, TResponse> {
+        PollUntilCompleteBuilder {
+            tasks: self,
+            task: task.into(),
+            interval: Duration::from_secs(DEFAULT_POLL_INTERVAL_SECS),
+            timeout: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `PollUntilCompleteBuilder` before sending it.
+*/
+impl<TSender, TResponse> PollUntilCompleteBuilder<TSender, TResponse>
+where
+    TSender: Sender,
+{
+    /** Set how long to wait before getting a task that's still running again. */
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /**
+    Set how long to keep polling for.
+
+    If the task is still running once the timeout has elapsed then an error is returned.
+    The task itself keeps running in the background.
+    */
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TResponse> PollUntilCompleteBuilder<SyncSender, TResponse>
+where
+    TResponse: DeserializeOwned,
+{
+    /**
+    Poll a task synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until the task completes or the timeout elapses.
+    If the task completes with an error then that error is returned.
+
+    # Examples
+
+    Wait for a reindex task to finish:
+
+    ```no_run
+    # use std::time::Duration;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    # let task = client.reindex("source", "dest").as_task().send()?;
+    let response = client.tasks()
+                         .poll_until_complete::<BulkByScrollResponse>(task.task().to_owned())
+                         .interval(Duration::from_secs(5))
+                         .send()?;
+
+    println!("created {} documents", response.created());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<TResponse, Error> {
+        let PollUntilCompleteBuilder {
+            tasks,
+            task,
+            interval,
+            timeout,
+            ..
+        } = self;
+        let started = Instant::now();
+
+        loop {
+            let res = tasks
+                .clone()
+                .get(task.clone())
+                .response::<TResponse>()
+                .send()?;
+
+            if let Some(response) = res.into_completed()? {
+                return Ok(response);
+            }
+
+            let delay = next_poll_delay(started.elapsed(), interval, timeout)
+                .ok_or_else(|| poll_timeout_error(&task, timeout))?;
+
+            thread::sleep(delay);
+        }
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TResponse> PollUntilCompleteBuilder<AsyncSender, TResponse>
+where
+    TResponse: DeserializeOwned + Send + 'static,
+{
+    /**
+    Poll a task asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the result of the request the task ran once it completes.
+    If the task completes with an error, or the timeout elapses, then the future will resolve to an error.
+    The returned future must be run on a `tokio` runtime.
+
+    # Examples
+
+    Start a reindex in the background and wait for it to finish:
+
+    ```no_run
+    # use std::time::Duration;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let tasks = client.tasks();
+
+    let future = client.reindex("source", "dest")
+                       .as_task()
+                       .send()
+                       .and_then(move |task| {
+                           tasks.poll_until_complete::<BulkByScrollResponse>(task.task().to_owned())
+                                .timeout(Duration::from_secs(60 * 60))
+                                .send()
+                       });
+
+    future.and_then(|response| {
+        println!("created {} documents", response.created());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> PendingCompletion<TResponse> {
+        let PollUntilCompleteBuilder {
+            tasks,
+            task,
+            interval,
+            timeout,
+            ..
+        } = self;
+        let started = Instant::now();
+
+        let res_future = future::loop_fn(tasks, move |tasks| {
+            let task = task.clone();
+
+            tasks
+                .clone()
+                .get(task.clone())
+                .response::<TResponse>()
+                .send()
+                .and_then(move |res| match res.into_completed() {
+                    Ok(Some(response)) => Either::A(future::ok(Loop::Break(response))),
+                    Ok(None) => match next_poll_delay(started.elapsed(), interval, timeout) {
+                        Some(delay) => Either::B(
+                            Delay::new(Instant::now() + delay)
+                                .map(move |_| Loop::Continue(tasks))
+                                .map_err(error::request),
+                        ),
+                        None => Either::A(future::err(poll_timeout_error(&task, timeout))),
+                    },
+                    Err(err) => Either::A(future::err(err)),
+                })
+        });
+
+        PendingCompletion::new(res_future)
+    }
+}
+
+/**
+Get how long to wait before polling a running task again.
+
+This will return `None` if the timeout has elapsed.
+The last delay is shortened so polling stops close to the timeout.
+*/
+fn next_poll_delay(
+    elapsed: Duration,
+    interval: Duration,
+    timeout: Option<Duration>,
+) -> Option<Duration> {
+    match timeout {
+        Some(timeout) if elapsed >= timeout => None,
+        Some(timeout) => Some(interval.min(timeout - elapsed)),
+        None => Some(interval),
+    }
+}
+
+fn poll_timeout_error(task: &TaskId<'static>, timeout: Option<Duration>) -> Error {
+    let timeout = timeout.map(format_duration).unwrap_or_default();
+
+    error::request(error::message(format!(
+        "task `{}` didn't complete within {}",
+        &**task, timeout
+    )))
+}
+
+/** A future returned by calling `send`. */
+pub type Pending<TStatus = Value, TResponse = Value> =
+    BasePending<TasksGetResponse<TStatus, TResponse>>;
+
+/** A future returned by calling `send` on a `PollUntilCompleteBuilder`. */
+pub type PendingCompletion<TResponse = Value> = BasePending<TResponse>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+    use std::time::Duration;
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+        assert_send::<super::PendingCompletion>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.tasks().get("node1:12").inner.into_request();
+
+        assert_eq!("/_tasks/node1:12", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_wait_for_completion() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .tasks()
+            .get("node1:12")
+            .wait_for_completion()
+            .timeout(Duration::from_secs(30))
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("wait_for_completion=true"));
+        assert!(qry.contains("timeout=30s"));
+    }
+
+    #[test]
+    fn specify_poll_options() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let poll = client
+            .tasks()
+            .poll_until_complete::<Value>("node1:12")
+            .interval(Duration::from_millis(500))
+            .timeout(Duration::from_secs(60));
+
+        assert_eq!("node1:12", &*poll.task);
+        assert_eq!(Duration::from_millis(500), poll.interval);
+        assert_eq!(Some(Duration::from_secs(60)), poll.timeout);
+    }
+
+    #[test]
+    fn poll_delay_without_timeout() {
+        assert_eq!(
+            Some(Duration::from_secs(1)),
+            super::next_poll_delay(Duration::from_secs(60), Duration::from_secs(1), None)
+        );
+    }
+
+    #[test]
+    fn poll_delay_with_timeout() {
+        let interval = Duration::from_secs(2);
+        let timeout = Some(Duration::from_secs(5));
+
+        assert_eq!(
+            Some(Duration::from_secs(2)),
+            super::next_poll_delay(Duration::from_secs(1), interval, timeout)
+        );
+        assert_eq!(
+            Some(Duration::from_secs(1)),
+            super::next_poll_delay(Duration::from_secs(4), interval, timeout)
+        );
+        assert_eq!(
+            None,
+            super::next_poll_delay(Duration::from_secs(5), interval, timeout)
+        );
+    }
+}
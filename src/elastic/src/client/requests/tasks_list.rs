@@ -0,0 +1,346 @@
+/*!
+Builders for [list tasks requests][docs-tasks].
+
+[docs-tasks]: https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html
+*/
+
+use futures::Future;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::{
+    marker::PhantomData,
+    time::Duration,
+};
+
+use crate::{
+    client::{
+        requests::{
+            common::{
+                field_list,
+                format_duration,
+            },
+            raw::RawRequestInner,
+            Pending as BasePending,
+            RequestBuilder,
+        },
+        responses::TasksListResponse,
+        TasksClient,
+    },
+    endpoints::TasksListRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::TaskId,
+};
+
+/**
+A [list tasks request][docs-tasks] builder that can be configured before sending.
+
+Call [`Client.tasks.list`][Client.tasks.list] to get a `TasksListRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-tasks]: https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.tasks.list]: ../../struct.TasksClient.html#list-tasks-request
+*/
+pub type TasksListRequestBuilder<TSender, TStatus = Value> =
+    RequestBuilder<TSender, TasksListRequestInner<TStatus>>;
+
+#[doc(hidden)]
+pub struct TasksListRequestInner<TStatus> {
+    _marker: PhantomData<TStatus>,
+}
+
+/**
+# List tasks request
+*/
+impl<TSender> TasksClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`TasksListRequestBuilder`][TasksListRequestBuilder] with this `Client` that can be configured before sending.
+
+    Tasks can be filtered by the action they're running, the node they're running on or their parent task.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    List all the reindex tasks running in the cluster:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.tasks()
+                         .list()
+                         .actions(vec!["*reindex"])
+                         .detailed()
+                         .status::<BulkByScrollStatus>()
+                         .send()?;
+
+    for task in response.tasks() {
+        if let Some(status) = task.status() {
+            println!("{}: {} of {} documents", task.task(), status.processed(), status.total());
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [TasksListRequestBuilder]: requests/tasks_list/type.TasksListRequestBuilder.html
+    [builder-methods]: requests/tasks_list/type.TasksListRequestBuilder.html#builder-methods
+    [send-sync]: requests/tasks_list/type.TasksListRequestBuilder.html#send-synchronously
+    [send-async]: requests/tasks_list/type.TasksListRequestBuilder.html#send-asynchronously
+    */
+    pub fn list(self) -> TasksListRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            TasksListRequestInner {
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<TStatus> TasksListRequestInner<TStatus> {
+    fn into_request(self) -> TasksListRequest<'static> {
+        TasksListRequest::new()
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `TasksListRequestBuilder` before sending it.
+*/
+impl<TSender, TStatus> TasksListRequestBuilder<TSender, TStatus>
+where
+    TSender: Sender,
+{
+    /**
+    Only list tasks running the given actions.
+
+    Actions can contain wildcards, like `*reindex` or `cluster:*`.
+    */
+    pub fn actions<I>(self, actions: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let actions = field_list(actions);
+
+        self.params_fluent(move |params| params.url_param("actions", actions.clone()))
+    }
+
+    /** Only list tasks running on the given nodes. */
+    pub fn nodes<I>(self, nodes: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let nodes = field_list(nodes);
+
+        self.params_fluent(move |params| params.url_param("nodes", nodes.clone()))
+    }
+
+    /** Only list tasks that were started by the given task. */
+    pub fn parent_task(self, task: impl Into<TaskId<'static>>) -> Self {
+        let task = task.into();
+
+        self.params_fluent(move |params| params.url_param("parent_task_id", task.clone()))
+    }
+
+    /**
+    Include a description and the progress of each task.
+
+    Tasks only include a status when they're listed in detail.
+    */
+    pub fn detailed(self) -> Self {
+        self.params_fluent(|params| params.url_param("detailed", true))
+    }
+
+    /** Wait for the matching tasks to complete before returning. */
+    pub fn wait_for_completion(self) -> Self {
+        self.params_fluent(|params| params.url_param("wait_for_completion", true))
+    }
+
+    /** Set how long to wait for the matching tasks to complete. */
+    pub fn timeout(self, timeout: Duration) -> Self {
+        let timeout = format_duration(timeout);
+
+        self.params_fluent(move |params| params.url_param("timeout", timeout.clone()))
+    }
+
+    /**
+    Set the type to deserialise the status of each task as.
+
+    Tasks that run different kinds of actions will have differently shaped statuses,
+    so this should be combined with a filter on [`actions`](#method.actions).
+    By default the status is deserialised as a `serde_json::Value`.
+    */
+    pub fn status<TNewStatus>(self) -> TasksListRequestBuilder<TSender, TNewStatus> {
+        RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            TasksListRequestInner {
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TStatus> TasksListRequestBuilder<SyncSender, TStatus>
+where
+    TStatus: DeserializeOwned,
+{
+    /**
+    Send a `TasksListRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    List the tasks running on a particular node:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.tasks()
+                         .list()
+                         .nodes(vec!["oTUltX4IQMOUUVeiohTt8A"])
+                         .send()?;
+
+    for task in response.tasks() {
+        println!("{}: {}", task.task(), task.action());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<TasksListResponse<TStatus>, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TStatus> TasksListRequestBuilder<AsyncSender, TStatus>
+where
+    TStatus: DeserializeOwned + Send + 'static,
+{
+    /**
+    Send a `TasksListRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised list tasks response.
+
+    # Examples
+
+    List the tasks running on a particular node:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.tasks()
+                       .list()
+                       .nodes(vec!["oTUltX4IQMOUUVeiohTt8A"])
+                       .send();
+
+    future.and_then(|response| {
+        for task in response.tasks() {
+            println!("{}: {}", task.task(), task.action());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<TStatus> {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub type Pending<TStatus = Value> = BasePending<TasksListResponse<TStatus>>;
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.tasks().list().inner.into_request();
+
+        assert_eq!("/_tasks", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_filters() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .tasks()
+            .list()
+            .actions(vec!["*reindex", "*byquery"])
+            .nodes(vec!["node1"])
+            .parent_task("node1:12")
+            .detailed()
+            .timeout(Duration::from_secs(30))
+            .params_builder
+            .into_value(RequestParams::default);
+
+        let (_, qry) = params.get_url_qry();
+        let qry = qry.unwrap();
+
+        assert!(qry.contains("actions=*reindex%2C*byquery"));
+        assert!(qry.contains("nodes=node1"));
+        assert!(qry.contains("parent_task_id=node1%3A12"));
+        assert!(qry.contains("detailed=true"));
+        assert!(qry.contains("timeout=30s"));
+    }
+}
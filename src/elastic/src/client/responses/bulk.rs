@@ -415,9 +415,9 @@ pub struct OkItem<
     index: TIndex,
     ty: TType,
     id: TId,
-    version: Option<u32>,
-    sequence_number: Option<u32>,
-    primary_term: Option<u32>,
+    version: Option<u64>,
+    sequence_number: Option<u64>,
+    primary_term: Option<u64>,
     shards: Option<Shards>,
     result: Option<DocumentResult>,
     get: Option<Value>,
@@ -430,7 +430,7 @@ impl<TIndex, TType, TId> OkItem<TIndex, TType, TId> {
     }
 
     /** The document version after this item. */
-    pub fn version(&self) -> Option<u32> {
+    pub fn version(&self) -> Option<u64> {
         self.version
     }
 
@@ -439,7 +439,7 @@ impl<TIndex, TType, TId> OkItem<TIndex, TType, TId> {
      *
      * [sequence number]: https://www.elastic.co/guide/en/elasticsearch/reference/current/optimistic-concurrency-control.html
      */
-    pub fn sequence_number(&self) -> Option<u32> {
+    pub fn sequence_number(&self) -> Option<u64> {
         self.sequence_number
     }
 
@@ -448,7 +448,7 @@ impl<TIndex, TType, TId> OkItem<TIndex, TType, TId> {
      *
      * [primary term]: https://www.elastic.co/guide/en/elasticsearch/reference/current/optimistic-concurrency-control.html
      */
-    pub fn primary_term(&self) -> Option<u32> {
+    pub fn primary_term(&self) -> Option<u64> {
         self.primary_term
     }
 
@@ -664,11 +664,11 @@ struct ItemDeInner<TIndex, TType, TId> {
     #[serde(rename = "_id")]
     id: TId,
     #[serde(rename = "_version")]
-    version: Option<u32>,
+    version: Option<u64>,
     #[serde(rename = "_seq_no")]
-    sequence_number: Option<u32>,
+    sequence_number: Option<u64>,
     #[serde(rename = "_primary_term")]
-    primary_term: Option<u32>,
+    primary_term: Option<u64>,
     get: Option<Value>,
     #[serde(rename = "_shards")]
     shards: Option<Shards>,
@@ -0,0 +1,284 @@
+/*!
+Response types for [delete by query](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html), [update by query](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html) and [reindex](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html) requests.
+*/
+
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::TaskInfo;
+
+use crate::http::receiver::IsOkOnSuccess;
+
+/**
+Response for a by-query request that was run to completion.
+
+The counts cover all documents that matched the query, across all slices.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct BulkByScrollResponse {
+    took: u64,
+    #[serde(default)]
+    timed_out: bool,
+    total: u64,
+    #[serde(default)]
+    created: u64,
+    #[serde(default)]
+    updated: u64,
+    #[serde(default)]
+    deleted: u64,
+    batches: u64,
+    version_conflicts: u64,
+    noops: u64,
+    retries: Retries,
+    throttled_millis: u64,
+    requests_per_second: f32,
+    #[serde(default)]
+    failures: Vec<BulkByScrollFailure>,
+}
+
+impl BulkByScrollResponse {
+    /** The number of milliseconds the request took. */
+    pub fn took(&self) -> u64 {
+        self.took
+    }
+
+    /** Whether or not any of the requests made during the operation timed out. */
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /** The number of documents that were processed. */
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /** The number of documents that were created. */
+    pub fn created(&self) -> u64 {
+        self.created
+    }
+
+    /** The number of documents that were updated. */
+    pub fn updated(&self) -> u64 {
+        self.updated
+    }
+
+    /** The number of documents that were deleted. */
+    pub fn deleted(&self) -> u64 {
+        self.deleted
+    }
+
+    /** The number of scroll responses that were pulled back. */
+    pub fn batches(&self) -> u64 {
+        self.batches
+    }
+
+    /** The number of documents that had a version conflict. */
+    pub fn version_conflicts(&self) -> u64 {
+        self.version_conflicts
+    }
+
+    /** The number of documents that were ignored because a script set `ctx.op` to `noop`. */
+    pub fn noops(&self) -> u64 {
+        self.noops
+    }
+
+    /** The number of bulk actions that were retried. */
+    pub fn bulk_retries(&self) -> u64 {
+        self.retries.bulk
+    }
+
+    /** The number of search actions that were retried. */
+    pub fn search_retries(&self) -> u64 {
+        self.retries.search
+    }
+
+    /** The number of milliseconds the request slept to respect `requests_per_second`. */
+    pub fn throttled_millis(&self) -> u64 {
+        self.throttled_millis
+    }
+
+    /**
+    The number of documents processed each second.
+
+    This is `None` if the request wasn't throttled.
+    */
+    pub fn requests_per_second(&self) -> Option<f32> {
+        if self.requests_per_second < 0.0 {
+            None
+        } else {
+            Some(self.requests_per_second)
+        }
+    }
+
+    /**
+    Failures that stopped the operation.
+
+    If this isn't empty then the operation was aborted part way through.
+    */
+    pub fn failures(&self) -> &[BulkByScrollFailure] {
+        &self.failures
+    }
+}
+
+impl IsOkOnSuccess for BulkByScrollResponse {}
+
+/**
+The progress of a by-query or reindex request that's running as a task.
+
+The status is returned by the tasks API and when the request is rethrottled.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct BulkByScrollStatus {
+    total: u64,
+    #[serde(default)]
+    created: u64,
+    #[serde(default)]
+    updated: u64,
+    #[serde(default)]
+    deleted: u64,
+    batches: u64,
+    version_conflicts: u64,
+    noops: u64,
+    retries: Retries,
+    throttled_millis: u64,
+    requests_per_second: f32,
+}
+
+impl BulkByScrollStatus {
+    /** The total number of documents the request will process. */
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /** The number of documents that have been created so far. */
+    pub fn created(&self) -> u64 {
+        self.created
+    }
+
+    /** The number of documents that have been updated so far. */
+    pub fn updated(&self) -> u64 {
+        self.updated
+    }
+
+    /** The number of documents that have been deleted so far. */
+    pub fn deleted(&self) -> u64 {
+        self.deleted
+    }
+
+    /** The number of scroll responses that have been pulled back so far. */
+    pub fn batches(&self) -> u64 {
+        self.batches
+    }
+
+    /** The number of documents that have had a version conflict so far. */
+    pub fn version_conflicts(&self) -> u64 {
+        self.version_conflicts
+    }
+
+    /** The number of documents that have been ignored so far because a script set `ctx.op` to `noop`. */
+    pub fn noops(&self) -> u64 {
+        self.noops
+    }
+
+    /** The number of documents that have been processed so far. */
+    pub fn processed(&self) -> u64 {
+        self.created + self.updated + self.deleted + self.noops + self.version_conflicts
+    }
+
+    /** The number of bulk actions that have been retried so far. */
+    pub fn bulk_retries(&self) -> u64 {
+        self.retries.bulk
+    }
+
+    /** The number of search actions that have been retried so far. */
+    pub fn search_retries(&self) -> u64 {
+        self.retries.search
+    }
+
+    /** The number of milliseconds the request has slept to respect `requests_per_second`. */
+    pub fn throttled_millis(&self) -> u64 {
+        self.throttled_millis
+    }
+
+    /**
+    The number of documents processed each second.
+
+    This is `None` if the request isn't throttled.
+    */
+    pub fn requests_per_second(&self) -> Option<f32> {
+        if self.requests_per_second < 0.0 {
+            None
+        } else {
+            Some(self.requests_per_second)
+        }
+    }
+}
+
+/** Response for a [reindex rethrottle request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html#docs-reindex-rethrottle). */
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReindexRethrottleResponse {
+    #[serde(default)]
+    nodes: BTreeMap<String, RethrottledNode>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct RethrottledNode {
+    tasks: BTreeMap<String, TaskInfo<BulkByScrollStatus>>,
+}
+
+impl ReindexRethrottleResponse {
+    /** Iterate over the tasks that were rethrottled, along with their current progress. */
+    pub fn tasks(&self) -> impl Iterator<Item = &TaskInfo<BulkByScrollStatus>> {
+        self.nodes.values().flat_map(|node| node.tasks.values())
+    }
+}
+
+impl IsOkOnSuccess for ReindexRethrottleResponse {}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+struct Retries {
+    bulk: u64,
+    search: u64,
+}
+
+/** A failure to process a document or search a shard during a by-query request. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct BulkByScrollFailure {
+    index: Option<String>,
+    id: Option<String>,
+    status: Option<u16>,
+    #[serde(alias = "reason")]
+    cause: Value,
+}
+
+impl BulkByScrollFailure {
+    /** The index of the document or shard that failed. */
+    pub fn index(&self) -> Option<&str> {
+        self.index.as_ref().map(|index| index.as_ref())
+    }
+
+    /** The id of the document that failed, if the failure was for a single document. */
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_ref().map(|id| id.as_ref())
+    }
+
+    /** The HTTP status code for the failure, if the failure was for a single document. */
+    pub fn status(&self) -> Option<u16> {
+        self.status
+    }
+
+    /** The kind of failure, like `version_conflict_engine_exception`. */
+    pub fn kind(&self) -> Option<&str> {
+        self.cause.get("type").and_then(Value::as_str)
+    }
+
+    /** A description of the failure. */
+    pub fn reason(&self) -> Option<&str> {
+        self.cause.get("reason").and_then(Value::as_str)
+    }
+
+    /** The full cause of the failure. */
+    pub fn cause(&self) -> &Value {
+        &self.cause
+    }
+}
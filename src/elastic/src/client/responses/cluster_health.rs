@@ -0,0 +1,245 @@
+/*!
+Response types for a [cluster health request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-health.html).
+*/
+
+use std::{
+    collections::HashMap,
+    fmt,
+    time::Duration,
+};
+
+use crate::http::{
+    receiver::{
+        HttpResponseHead,
+        IsOk,
+        MaybeOkResponse,
+        ParseError,
+        ResponseBody,
+        Unbuffered,
+    },
+    StatusCode,
+};
+
+/**
+Response for a [cluster health request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-health.html).
+
+If the request waited for a condition that wasn't met before the timeout then [`timed_out`][timed_out] will be `true`.
+Health for each index is only available if the `indices` or `shards` level was requested.
+
+[timed_out]: #method.timed_out
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct ClusterHealthResponse {
+    cluster_name: String,
+    status: HealthStatus,
+    timed_out: bool,
+    number_of_nodes: u32,
+    number_of_data_nodes: u32,
+    active_primary_shards: u32,
+    active_shards: u32,
+    relocating_shards: u32,
+    initializing_shards: u32,
+    unassigned_shards: u32,
+    #[serde(default)]
+    delayed_unassigned_shards: u32,
+    #[serde(default)]
+    number_of_pending_tasks: u32,
+    #[serde(default)]
+    number_of_in_flight_fetch: u32,
+    #[serde(default)]
+    task_max_waiting_in_queue_millis: u64,
+    #[serde(default)]
+    active_shards_percent_as_number: f64,
+    #[serde(default)]
+    indices: HashMap<String, IndexHealth>,
+}
+
+impl ClusterHealthResponse {
+    /** The name of the cluster. */
+    pub fn cluster_name(&self) -> &str {
+        &self.cluster_name
+    }
+
+    /** The health of the cluster. */
+    pub fn status(&self) -> HealthStatus {
+        self.status
+    }
+
+    /** Whether or not the request timed out before the conditions it waited for were met. */
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /** The number of nodes in the cluster. */
+    pub fn number_of_nodes(&self) -> u32 {
+        self.number_of_nodes
+    }
+
+    /** The number of data nodes in the cluster. */
+    pub fn number_of_data_nodes(&self) -> u32 {
+        self.number_of_data_nodes
+    }
+
+    /** The number of active primary shards. */
+    pub fn active_primary_shards(&self) -> u32 {
+        self.active_primary_shards
+    }
+
+    /** The number of active primary and replica shards. */
+    pub fn active_shards(&self) -> u32 {
+        self.active_shards
+    }
+
+    /** The number of shards that are relocating between nodes. */
+    pub fn relocating_shards(&self) -> u32 {
+        self.relocating_shards
+    }
+
+    /** The number of shards that are initializing. */
+    pub fn initializing_shards(&self) -> u32 {
+        self.initializing_shards
+    }
+
+    /** The number of shards that aren't allocated to a node. */
+    pub fn unassigned_shards(&self) -> u32 {
+        self.unassigned_shards
+    }
+
+    /** The number of unassigned shards whose allocation has been delayed. */
+    pub fn delayed_unassigned_shards(&self) -> u32 {
+        self.delayed_unassigned_shards
+    }
+
+    /** The number of cluster-level changes that haven't been executed yet. */
+    pub fn number_of_pending_tasks(&self) -> u32 {
+        self.number_of_pending_tasks
+    }
+
+    /** The number of unfinished shard fetches. */
+    pub fn number_of_in_flight_fetch(&self) -> u32 {
+        self.number_of_in_flight_fetch
+    }
+
+    /** The time the oldest pending task has been waiting. */
+    pub fn task_max_waiting_in_queue(&self) -> Duration {
+        Duration::from_millis(self.task_max_waiting_in_queue_millis)
+    }
+
+    /** The percentage of shards that are active. */
+    pub fn active_shards_percent(&self) -> f64 {
+        self.active_shards_percent_as_number
+    }
+
+    /** Iterate over the indices and their health. */
+    pub fn indices(&self) -> impl Iterator<Item = (&str, &IndexHealth)> {
+        self.indices
+            .iter()
+            .map(|(index, health)| (index.as_ref(), health))
+    }
+
+    /** Get the health for an index. */
+    pub fn index(&self, index: &str) -> Option<&IndexHealth> {
+        self.indices.get(index)
+    }
+}
+
+impl IsOk for ClusterHealthResponse {
+    fn is_ok<B: ResponseBody>(
+        head: HttpResponseHead,
+        body: Unbuffered<B>,
+    ) -> Result<MaybeOkResponse<B>, ParseError> {
+        match head.status() {
+            status if status.is_success() => Ok(MaybeOkResponse::ok(body)),
+            // A timed out wait still returns the health of the cluster
+            StatusCode::REQUEST_TIMEOUT => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/** The health of an index. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndexHealth {
+    status: HealthStatus,
+    number_of_shards: u32,
+    number_of_replicas: u32,
+    active_primary_shards: u32,
+    active_shards: u32,
+    relocating_shards: u32,
+    initializing_shards: u32,
+    unassigned_shards: u32,
+}
+
+impl IndexHealth {
+    /** The health of the index. */
+    pub fn status(&self) -> HealthStatus {
+        self.status
+    }
+
+    /** The number of primary shards in the index. */
+    pub fn number_of_shards(&self) -> u32 {
+        self.number_of_shards
+    }
+
+    /** The number of replicas for each primary shard. */
+    pub fn number_of_replicas(&self) -> u32 {
+        self.number_of_replicas
+    }
+
+    /** The number of active primary shards. */
+    pub fn active_primary_shards(&self) -> u32 {
+        self.active_primary_shards
+    }
+
+    /** The number of active primary and replica shards. */
+    pub fn active_shards(&self) -> u32 {
+        self.active_shards
+    }
+
+    /** The number of shards that are relocating between nodes. */
+    pub fn relocating_shards(&self) -> u32 {
+        self.relocating_shards
+    }
+
+    /** The number of shards that are initializing. */
+    pub fn initializing_shards(&self) -> u32 {
+        self.initializing_shards
+    }
+
+    /** The number of shards that aren't allocated to a node. */
+    pub fn unassigned_shards(&self) -> u32 {
+        self.unassigned_shards
+    }
+}
+
+/**
+The health of a cluster or index.
+
+Statuses are ordered from worst to best, so `status >= HealthStatus::Yellow` checks that all primary shards are allocated.
+*/
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    /** Some primary shards aren't allocated. */
+    Red,
+    /** All primary shards are allocated, but some replicas aren't. */
+    Yellow,
+    /** All shards are allocated. */
+    Green,
+}
+
+impl HealthStatus {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            HealthStatus::Red => "red",
+            HealthStatus::Yellow => "yellow",
+            HealthStatus::Green => "green",
+        }
+    }
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
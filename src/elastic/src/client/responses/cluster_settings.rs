@@ -0,0 +1,174 @@
+/*!
+Response types for [get and update cluster settings requests](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-update-settings.html).
+*/
+
+use serde_json::{
+    Map,
+    Value,
+};
+
+use crate::http::receiver::IsOkOnSuccess;
+
+/**
+Response for a [get cluster settings request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-get-settings.html).
+
+Default settings are only returned if `include_defaults` was requested.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct ClusterGetSettingsResponse {
+    #[serde(default)]
+    persistent: SettingsMap,
+    #[serde(default)]
+    transient: SettingsMap,
+    #[serde(default)]
+    defaults: SettingsMap,
+}
+
+impl ClusterGetSettingsResponse {
+    /** Settings that survive a full cluster restart. */
+    pub fn persistent(&self) -> &SettingsMap {
+        &self.persistent
+    }
+
+    /** Settings that are lost on a full cluster restart. */
+    pub fn transient(&self) -> &SettingsMap {
+        &self.transient
+    }
+
+    /** The default values of settings. */
+    pub fn defaults(&self) -> &SettingsMap {
+        &self.defaults
+    }
+
+    /**
+    Get the value of a setting that's in effect.
+
+    Transient settings take precedence over persistent ones, which take precedence over defaults.
+    */
+    pub fn get(&self, key: impl AsRef<str>) -> Option<&Value> {
+        let key = key.as_ref();
+
+        self.transient
+            .get(key)
+            .or_else(|| self.persistent.get(key))
+            .or_else(|| self.defaults.get(key))
+    }
+}
+
+impl IsOkOnSuccess for ClusterGetSettingsResponse {}
+
+/** Response for an [update cluster settings request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-update-settings.html). */
+#[derive(Deserialize, Debug, Clone)]
+pub struct ClusterPutSettingsResponse {
+    acknowledged: bool,
+    #[serde(default)]
+    persistent: SettingsMap,
+    #[serde(default)]
+    transient: SettingsMap,
+}
+
+impl ClusterPutSettingsResponse {
+    /** Whether or not the update was acknowledged by the cluster. */
+    pub fn acknowledged(&self) -> bool {
+        self.acknowledged
+    }
+
+    /** The persistent settings that were updated. */
+    pub fn persistent(&self) -> &SettingsMap {
+        &self.persistent
+    }
+
+    /** The transient settings that were updated. */
+    pub fn transient(&self) -> &SettingsMap {
+        &self.transient
+    }
+}
+
+impl IsOkOnSuccess for ClusterPutSettingsResponse {}
+
+/**
+A map of settings that can be looked up by their dotted name.
+
+Elasticsearch returns settings either as nested objects or with flat dotted keys, depending on the `flat_settings` parameter.
+Lookups work the same way for both, so `cluster.routing.allocation.enable` will be found in either form.
+
+# Examples
+
+```no_run
+# use elastic::prelude::*;
+# fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+# let client = SyncClientBuilder::new().build()?;
+let response = client.cluster().get_settings().send()?;
+
+if let Some(enable) = response.persistent().get_str(ClusterSetting::AllocationEnable) {
+    println!("allocation: {}", enable);
+}
+# Ok(())
+# }
+```
+*/
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SettingsMap(Map<String, Value>);
+
+impl SettingsMap {
+    /** Get the value of a setting by its dotted name. */
+    pub fn get(&self, key: impl AsRef<str>) -> Option<&Value> {
+        lookup(&self.0, key.as_ref())
+    }
+
+    /**
+    Get the value of a setting as a string.
+
+    Elasticsearch returns most setting values as strings, even if they were set as numbers or booleans.
+    */
+    pub fn get_str(&self, key: impl AsRef<str>) -> Option<&str> {
+        self.get(key).and_then(|value| value.as_str())
+    }
+
+    /** Iterate over the settings by their dotted names. */
+    pub fn iter(&self) -> impl Iterator<Item = (String, &Value)> {
+        let mut flat = Vec::new();
+        flatten(&self.0, None, &mut flat);
+
+        flat.into_iter()
+    }
+
+    /** Whether or not there are any settings. */
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+fn lookup<'a>(map: &'a Map<String, Value>, key: &str) -> Option<&'a Value> {
+    if let Some(value) = map.get(key) {
+        return Some(value);
+    }
+
+    // Try each dotted prefix of the key as a nested object
+    key.match_indices('.').find_map(|(i, _)| {
+        let (prefix, rest) = (&key[..i], &key[i + 1..]);
+
+        match map.get(prefix) {
+            Some(Value::Object(inner)) => lookup(inner, rest),
+            _ => None,
+        }
+    })
+}
+
+fn flatten<'a>(
+    map: &'a Map<String, Value>,
+    prefix: Option<&str>,
+    flat: &mut Vec<(String, &'a Value)>,
+) {
+    for (key, value) in map {
+        let key = match prefix {
+            Some(prefix) => format!("{}.{}", prefix, key),
+            None => key.to_owned(),
+        };
+
+        match value {
+            Value::Object(inner) => flatten(inner, Some(&key), flat),
+            value => flat.push((key, value)),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+/*!
+Response types for a [cluster state request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-state.html).
+*/
+
+use serde_json::{
+    Map,
+    Value,
+};
+use std::collections::HashMap;
+
+use super::cluster_settings::SettingsMap;
+
+use crate::http::receiver::IsOkOnSuccess;
+
+/**
+Response for a [cluster state request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-state.html).
+
+Only the metrics that were requested are returned.
+Metrics without a typed accessor, like `routing_table` or `blocks`, are available through [`get_metric`][get_metric].
+
+[get_metric]: #method.get_metric
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct ClusterStateResponse {
+    cluster_name: String,
+    cluster_uuid: Option<String>,
+    version: Option<u64>,
+    state_uuid: Option<String>,
+    master_node: Option<String>,
+    #[serde(default)]
+    nodes: HashMap<String, NodeState>,
+    metadata: Option<ClusterMetadata>,
+    #[serde(flatten)]
+    other: Map<String, Value>,
+}
+
+impl ClusterStateResponse {
+    /** The name of the cluster. */
+    pub fn cluster_name(&self) -> &str {
+        &self.cluster_name
+    }
+
+    /** The unique id of the cluster. */
+    pub fn cluster_uuid(&self) -> Option<&str> {
+        self.cluster_uuid.as_ref().map(|uuid| uuid.as_ref())
+    }
+
+    /** The version of the cluster state. */
+    pub fn version(&self) -> Option<u64> {
+        self.version
+    }
+
+    /** The unique id of this version of the cluster state. */
+    pub fn state_uuid(&self) -> Option<&str> {
+        self.state_uuid.as_ref().map(|uuid| uuid.as_ref())
+    }
+
+    /** The id of the elected master node. */
+    pub fn master_node(&self) -> Option<&str> {
+        self.master_node.as_ref().map(|node| node.as_ref())
+    }
+
+    /** Iterate over the ids of nodes in the cluster and their details. */
+    pub fn nodes(&self) -> impl Iterator<Item = (&str, &NodeState)> {
+        self.nodes.iter().map(|(id, node)| (id.as_ref(), node))
+    }
+
+    /** Get the details of a node by its id. */
+    pub fn node(&self, id: &str) -> Option<&NodeState> {
+        self.nodes.get(id)
+    }
+
+    /** Metadata for the cluster and its indices. */
+    pub fn metadata(&self) -> Option<&ClusterMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /** Get any other metric by name, like `routing_table` or `blocks`. */
+    pub fn get_metric(&self, name: &str) -> Option<&Value> {
+        self.other.get(name)
+    }
+}
+
+impl IsOkOnSuccess for ClusterStateResponse {}
+
+/** The details of a node in the cluster. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct NodeState {
+    name: String,
+    ephemeral_id: String,
+    transport_address: String,
+    #[serde(default)]
+    attributes: HashMap<String, String>,
+}
+
+impl NodeState {
+    /** The name of the node. */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /** The id of the node that changes each time it restarts. */
+    pub fn ephemeral_id(&self) -> &str {
+        &self.ephemeral_id
+    }
+
+    /** The address the node is listening on for transport connections. */
+    pub fn transport_address(&self) -> &str {
+        &self.transport_address
+    }
+
+    /** Get a custom attribute of the node, like one used for shard allocation awareness. */
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.get(name).map(|value| value.as_ref())
+    }
+}
+
+/** Metadata for the cluster and its indices. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct ClusterMetadata {
+    #[serde(default)]
+    indices: HashMap<String, IndexMetadata>,
+    #[serde(flatten)]
+    other: Map<String, Value>,
+}
+
+impl ClusterMetadata {
+    /** Iterate over the indices and their metadata. */
+    pub fn indices(&self) -> impl Iterator<Item = (&str, &IndexMetadata)> {
+        self.indices
+            .iter()
+            .map(|(index, metadata)| (index.as_ref(), metadata))
+    }
+
+    /** Get the metadata for an index. */
+    pub fn index(&self, index: &str) -> Option<&IndexMetadata> {
+        self.indices.get(index)
+    }
+
+    /** Get any other metadata by name, like `templates` or `cluster_coordination`. */
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.other.get(name)
+    }
+}
+
+/** Metadata for an index. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndexMetadata {
+    state: String,
+    #[serde(default)]
+    settings: SettingsMap,
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+impl IndexMetadata {
+    /** The state of the index, like `open` or `close`. */
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /** The settings of the index, like `index.number_of_shards`. */
+    pub fn settings(&self) -> &SettingsMap {
+        &self.settings
+    }
+
+    /** Iterate over the aliases that point to the index. */
+    pub fn aliases(&self) -> impl Iterator<Item = &str> {
+        self.aliases.iter().map(|alias| alias.as_ref())
+    }
+}
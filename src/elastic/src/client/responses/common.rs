@@ -2,6 +2,11 @@
 Types that are common to many responses.
 */
 
+use serde_json::{
+    Map,
+    Value,
+};
+
 /** A default type for allocated fields in responses. */
 pub(crate) type DefaultAllocatedField = String;
 
@@ -30,6 +35,43 @@ impl Shards {
     }
 }
 
+/**
+Stored fields and doc value fields returned for a document.
+
+Elasticsearch always returns the values of a field as an array, even if the field only has a single value.
+*/
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Fields(Map<String, Value>);
+
+impl Fields {
+    /** Get all values of a field. */
+    pub fn get(&self, name: impl AsRef<str>) -> Option<&[Value]> {
+        self.0
+            .get(name.as_ref())
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+    }
+
+    /** Get the first value of a field. */
+    pub fn first(&self, name: impl AsRef<str>) -> Option<&Value> {
+        self.get(name).and_then(|values| values.first())
+    }
+
+    /** Iterate over the fields and their values. */
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[Value])> {
+        self.0.iter().filter_map(|(name, values)| {
+            values
+                .as_array()
+                .map(|values| (name.as_ref(), values.as_slice()))
+        })
+    }
+
+    /** Whether or not there are any fields. */
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
 #[derive(Clone, Copy, Deserialize, Debug, PartialEq, Eq)]
 pub(crate) enum DocumentResult {
     #[serde(rename = "deleted")]
@@ -33,11 +33,11 @@ pub struct DeleteResponse {
     #[serde(rename = "_id")]
     id: String,
     #[serde(rename = "_version")]
-    version: Option<u32>,
+    version: Option<u64>,
     #[serde(rename = "_seq_no")]
-    sequence_number: Option<u32>,
+    sequence_number: Option<u64>,
     #[serde(rename = "_primary_term")]
-    primary_term: Option<u32>,
+    primary_term: Option<u64>,
     #[serde(rename = "_routing")]
     routing: Option<String>,
     result: DocumentResult,
@@ -68,7 +68,7 @@ impl DeleteResponse {
     }
 
     /** The version of the document. */
-    pub fn version(&self) -> Option<u32> {
+    pub fn version(&self) -> Option<u64> {
         self.version
     }
 
@@ -77,7 +77,7 @@ impl DeleteResponse {
      *
      * [sequence number]: https://www.elastic.co/guide/en/elasticsearch/reference/current/optimistic-concurrency-control.html
      */
-    pub fn sequence_number(&self) -> Option<u32> {
+    pub fn sequence_number(&self) -> Option<u64> {
         self.sequence_number
     }
 
@@ -86,7 +86,7 @@ impl DeleteResponse {
      *
      * [primary term]: https://www.elastic.co/guide/en/elasticsearch/reference/current/optimistic-concurrency-control.html
      */
-    pub fn primary_term(&self) -> Option<u32> {
+    pub fn primary_term(&self) -> Option<u64> {
         self.primary_term
     }
 }
@@ -0,0 +1,105 @@
+/*!
+Response types for an [explain request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html).
+*/
+
+use crate::http::{
+    receiver::{
+        HttpResponseHead,
+        IsOk,
+        MaybeOkResponse,
+        ParseError,
+        ResponseBody,
+        Unbuffered,
+    },
+    StatusCode,
+};
+
+/** Response for an [explain request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html). */
+#[derive(Deserialize, Debug, Clone)]
+pub struct ExplainResponse {
+    #[serde(rename = "_index")]
+    index: String,
+    #[serde(rename = "_id")]
+    id: String,
+    matched: bool,
+    explanation: Option<Explanation>,
+}
+
+impl ExplainResponse {
+    /** The index of the document. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The id of the document. */
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /**
+    Whether or not the document matched the query.
+
+    This will be `false` if the document doesn't exist.
+    */
+    pub fn matched(&self) -> bool {
+        self.matched
+    }
+
+    /** How the score of the document was computed. */
+    pub fn explanation(&self) -> Option<&Explanation> {
+        self.explanation.as_ref()
+    }
+}
+
+impl IsOk for ExplainResponse {
+    fn is_ok<B: ResponseBody>(
+        head: HttpResponseHead,
+        body: Unbuffered<B>,
+    ) -> Result<MaybeOkResponse<B>, ParseError> {
+        match head.status() {
+            status if status.is_success() => Ok(MaybeOkResponse::ok(body)),
+            StatusCode::NOT_FOUND => {
+                // A missing document is returned as a 404 without a root 'error' node
+                let (maybe_err, body) = body.body()?;
+
+                let is_ok = maybe_err
+                    .as_object()
+                    .and_then(|maybe_err| maybe_err.get("error"))
+                    .is_none();
+
+                Ok(MaybeOkResponse::new(is_ok, body))
+            }
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/**
+A step in computing the score of a document.
+
+Each explanation has a value and a description of how it was computed, along with the explanations it was computed from.
+*/
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Explanation {
+    value: f32,
+    description: String,
+    #[serde(default)]
+    details: Vec<Explanation>,
+}
+
+impl Explanation {
+    /** The value computed at this step. */
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /** A description of how the value was computed, like `sum of:` or `weight(title:rust in 0)`. */
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /** The explanations the value was computed from. */
+    pub fn details(&self) -> &[Explanation] {
+        &self.details
+    }
+}
@@ -5,6 +5,8 @@ Response types for a [get document request](https://www.elastic.co/guide/en/elas
 use crate::http::StatusCode;
 use serde::de::DeserializeOwned;
 
+use super::common::Fields;
+
 use crate::{
     http::receiver::{
         HttpResponseHead,
@@ -17,6 +19,7 @@ use crate::{
     types::document::{
         Id,
         Index,
+        Routing,
         Type,
     },
 };
@@ -31,16 +34,18 @@ pub struct GetResponse<T> {
     #[serde(rename = "_id")]
     id: String,
     #[serde(rename = "_version")]
-    version: Option<u32>,
+    version: Option<u64>,
     #[serde(rename = "_seq_no")]
-    sequence_number: Option<u32>,
+    sequence_number: Option<u64>,
     #[serde(rename = "_primary_term")]
-    primary_term: Option<u32>,
+    primary_term: Option<u64>,
     found: bool,
     #[serde(rename = "_source")]
     source: Option<T>,
     #[serde(rename = "_routing")]
     routing: Option<String>,
+    #[serde(default)]
+    fields: Fields,
 }
 
 impl<T> GetResponse<T> {
@@ -54,6 +59,15 @@ impl<T> GetResponse<T> {
         self.source
     }
 
+    /**
+    The stored fields returned for the document.
+
+    Fields are only returned if they were requested using `stored_fields` on the request.
+    */
+    pub fn fields(&self) -> &Fields {
+        &self.fields
+    }
+
     /** Whether or not a matching document was found. */
     pub fn found(&self) -> bool {
         self.found
@@ -74,8 +88,13 @@ impl<T> GetResponse<T> {
         Id::from(&self.id)
     }
 
+    /** The routing value the document was indexed with. */
+    pub fn routing(&self) -> Option<Routing> {
+        self.routing.as_ref().map(Routing::from)
+    }
+
     /** The version of the document. */
-    pub fn version(&self) -> Option<u32> {
+    pub fn version(&self) -> Option<u64> {
         self.version
     }
 
@@ -84,7 +103,7 @@ impl<T> GetResponse<T> {
      *
      * [sequence number]: https://www.elastic.co/guide/en/elasticsearch/reference/current/optimistic-concurrency-control.html
      */
-    pub fn sequence_number(&self) -> Option<u32> {
+    pub fn sequence_number(&self) -> Option<u64> {
         self.sequence_number
     }
 
@@ -93,7 +112,7 @@ impl<T> GetResponse<T> {
      *
      * [primary term]: https://www.elastic.co/guide/en/elasticsearch/reference/current/optimistic-concurrency-control.html
      */
-    pub fn primary_term(&self) -> Option<u32> {
+    pub fn primary_term(&self) -> Option<u64> {
         self.primary_term
     }
 }
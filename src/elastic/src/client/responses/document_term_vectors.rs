@@ -0,0 +1,223 @@
+/*!
+Response types for [term vectors](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-termvectors.html) and [multi term vectors](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-termvectors.html) requests.
+*/
+
+use std::collections::BTreeMap;
+
+use crate::http::receiver::IsOkOnSuccess;
+
+/**
+Response for a [term vectors request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-termvectors.html).
+
+The response maps each field to its terms, and each term to its statistics.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct TermVectorsResponse {
+    #[serde(rename = "_index")]
+    index: String,
+    #[serde(rename = "_id")]
+    id: Option<String>,
+    #[serde(rename = "_version")]
+    version: Option<u32>,
+    #[serde(default)]
+    found: bool,
+    #[serde(default)]
+    took: u64,
+    #[serde(default)]
+    term_vectors: BTreeMap<String, FieldTermVectors>,
+}
+
+impl TermVectorsResponse {
+    /** The index of the document. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /**
+    The id of the document.
+
+    This will be `None` for an artificial document.
+    */
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_ref().map(|id| id.as_ref())
+    }
+
+    /** The version of the document. */
+    pub fn version(&self) -> Option<u32> {
+        self.version
+    }
+
+    /** Whether or not the document was found. */
+    pub fn found(&self) -> bool {
+        self.found
+    }
+
+    /** The time taken to compute the term vectors in milliseconds. */
+    pub fn took(&self) -> u64 {
+        self.took
+    }
+
+    /** Iterate over the fields and their term vectors. */
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &FieldTermVectors)> {
+        self.term_vectors
+            .iter()
+            .map(|(field, term_vectors)| (field.as_ref(), term_vectors))
+    }
+
+    /** Get the term vectors for a field. */
+    pub fn field(&self, field: &str) -> Option<&FieldTermVectors> {
+        self.term_vectors.get(field)
+    }
+}
+
+impl IsOkOnSuccess for TermVectorsResponse {}
+
+/**
+Response for a [multi term vectors request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-termvectors.html).
+
+The term vectors of each document are returned in the same order they were requested.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct MtermVectorsResponse {
+    docs: Vec<TermVectorsResponse>,
+}
+
+impl MtermVectorsResponse {
+    /** Iterate over the term vectors of each document. */
+    pub fn docs(&self) -> impl Iterator<Item = &TermVectorsResponse> {
+        self.docs.iter()
+    }
+}
+
+impl IsOkOnSuccess for MtermVectorsResponse {}
+
+/** The terms of a single field. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct FieldTermVectors {
+    field_statistics: Option<FieldStatistics>,
+    #[serde(default)]
+    terms: BTreeMap<String, TermStatistics>,
+}
+
+impl FieldTermVectors {
+    /**
+    Statistics for the field across the shard.
+
+    This will be `None` if field statistics weren't requested.
+    */
+    pub fn field_statistics(&self) -> Option<&FieldStatistics> {
+        self.field_statistics.as_ref()
+    }
+
+    /** Iterate over the terms and their statistics. */
+    pub fn terms(&self) -> impl Iterator<Item = (&str, &TermStatistics)> {
+        self.terms
+            .iter()
+            .map(|(term, stats)| (term.as_ref(), stats))
+    }
+
+    /** Get the statistics for a term. */
+    pub fn term(&self, term: &str) -> Option<&TermStatistics> {
+        self.terms.get(term)
+    }
+}
+
+/** Statistics for a field across the shard. */
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct FieldStatistics {
+    sum_doc_freq: u64,
+    doc_count: u64,
+    sum_ttf: u64,
+}
+
+impl FieldStatistics {
+    /** The sum of the document frequencies of all terms in the field. */
+    pub fn sum_doc_freq(&self) -> u64 {
+        self.sum_doc_freq
+    }
+
+    /** The number of documents that contain at least one term in the field. */
+    pub fn doc_count(&self) -> u64 {
+        self.doc_count
+    }
+
+    /** The sum of the total term frequencies of all terms in the field. */
+    pub fn sum_ttf(&self) -> u64 {
+        self.sum_ttf
+    }
+}
+
+/** Statistics for a single term of a field. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TermStatistics {
+    term_freq: u32,
+    doc_freq: Option<u64>,
+    ttf: Option<u64>,
+    #[serde(default)]
+    tokens: Vec<Token>,
+}
+
+impl TermStatistics {
+    /** The number of times the term appears in the field of this document. */
+    pub fn term_freq(&self) -> u32 {
+        self.term_freq
+    }
+
+    /**
+    The number of documents in the shard that contain the term.
+
+    This will be `None` if term statistics weren't requested.
+    */
+    pub fn doc_freq(&self) -> Option<u64> {
+        self.doc_freq
+    }
+
+    /**
+    The number of times the term appears across all documents in the shard.
+
+    This will be `None` if term statistics weren't requested.
+    */
+    pub fn ttf(&self) -> Option<u64> {
+        self.ttf
+    }
+
+    /**
+    Each occurrence of the term in the field.
+
+    This will be empty if positions, offsets and payloads weren't requested.
+    */
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+}
+
+/** A single occurrence of a term in a field. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Token {
+    position: Option<u32>,
+    start_offset: Option<u32>,
+    end_offset: Option<u32>,
+    payload: Option<String>,
+}
+
+impl Token {
+    /** The position of the term in the field. */
+    pub fn position(&self) -> Option<u32> {
+        self.position
+    }
+
+    /** The character offset the term starts at. */
+    pub fn start_offset(&self) -> Option<u32> {
+        self.start_offset
+    }
+
+    /** The character offset the term ends at. */
+    pub fn end_offset(&self) -> Option<u32> {
+        self.end_offset
+    }
+
+    /** The base64 encoded payload of the term. */
+    pub fn payload(&self) -> Option<&str> {
+        self.payload.as_ref().map(|payload| payload.as_ref())
+    }
+}
@@ -5,8 +5,6 @@ Response types for a [update document request](https://www.elastic.co/guide/en/e
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
-use super::common::DocumentResult;
-
 use crate::{
     http::receiver::IsOkOnSuccess,
     types::document::{
@@ -26,17 +24,34 @@ pub struct UpdateResponse {
     #[serde(rename = "_id")]
     id: String,
     #[serde(rename = "_version")]
-    version: Option<u32>,
+    version: Option<u64>,
     #[serde(rename = "_seq_no")]
-    sequence_number: Option<u32>,
+    sequence_number: Option<u64>,
     #[serde(rename = "_primary_term")]
-    primary_term: Option<u32>,
+    primary_term: Option<u64>,
     #[serde(rename = "_routing")]
     routing: Option<String>,
-    result: DocumentResult,
+    result: UpdateResult,
     get: Option<Value>,
 }
 
+/** The result of an [update document request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update.html). */
+#[derive(Clone, Copy, Deserialize, Debug, PartialEq, Eq)]
+pub enum UpdateResult {
+    /** The document didn't exist, so the upsert document was indexed. */
+    #[serde(rename = "created")]
+    Created,
+    /** The document was updated. */
+    #[serde(rename = "updated")]
+    Updated,
+    /** The document was deleted by the update script. */
+    #[serde(rename = "deleted")]
+    Deleted,
+    /** The update didn't change the document, or the update script chose not to do anything. */
+    #[serde(rename = "noop")]
+    NoOp,
+}
+
 impl UpdateResponse {
     /**
     Convert the source in the response into the updated document.
@@ -85,10 +100,15 @@ impl UpdateResponse {
         )
     }
 
+    /** What happened to the document. */
+    pub fn result(&self) -> UpdateResult {
+        self.result
+    }
+
     /** Whether or not the document was updated. */
     pub fn updated(&self) -> bool {
         match self.result {
-            DocumentResult::Updated => true,
+            UpdateResult::Updated => true,
             _ => false,
         }
     }
@@ -109,7 +129,7 @@ impl UpdateResponse {
     }
 
     /** The version of the document. */
-    pub fn version(&self) -> Option<u32> {
+    pub fn version(&self) -> Option<u64> {
         self.version
     }
 
@@ -118,7 +138,7 @@ impl UpdateResponse {
      *
      * [sequence number]: https://www.elastic.co/guide/en/elasticsearch/reference/current/optimistic-concurrency-control.html
      */
-    pub fn sequence_number(&self) -> Option<u32> {
+    pub fn sequence_number(&self) -> Option<u64> {
         self.sequence_number
     }
 
@@ -127,7 +147,7 @@ impl UpdateResponse {
      *
      * [primary term]: https://www.elastic.co/guide/en/elasticsearch/reference/current/optimistic-concurrency-control.html
      */
-    pub fn primary_term(&self) -> Option<u32> {
+    pub fn primary_term(&self) -> Option<u64> {
         self.primary_term
     }
 }
@@ -0,0 +1,146 @@
+/*!
+Response types for [alias requests](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html).
+*/
+
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::http::{
+    receiver::{
+        HttpResponseHead,
+        IsOk,
+        IsOkOnSuccess,
+        MaybeOkResponse,
+        ParseError,
+        ResponseBody,
+        Unbuffered,
+    },
+    StatusCode,
+};
+
+/**
+Response for a [get alias request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html).
+
+The response maps each index to the aliases that point to it.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndicesGetAliasResponse(HashMap<String, IndexAliases>);
+
+impl IndicesGetAliasResponse {
+    /** Iterate over the indices and their aliases. */
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &IndexAliases)> {
+        self.0
+            .iter()
+            .map(|(index, aliases)| (index.as_ref(), aliases))
+    }
+
+    /** Get the aliases for an index. */
+    pub fn index(&self, index: &str) -> Option<&IndexAliases> {
+        self.0.get(index)
+    }
+
+    /** Iterate over the indices that an alias points to. */
+    pub fn indices_for_alias<'a>(&'a self, alias: &'a str) -> impl Iterator<Item = &'a str> {
+        self.iter()
+            .filter(move |(_, aliases)| aliases.contains(alias))
+            .map(|(index, _)| index)
+    }
+}
+
+impl IsOkOnSuccess for IndicesGetAliasResponse {}
+
+/** The aliases for a single index. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndexAliases {
+    aliases: HashMap<String, AliasMetadata>,
+}
+
+impl IndexAliases {
+    /** Iterate over the aliases and their properties. */
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &AliasMetadata)> {
+        self.aliases
+            .iter()
+            .map(|(alias, metadata)| (alias.as_ref(), metadata))
+    }
+
+    /** Get the properties of an alias. */
+    pub fn get(&self, alias: &str) -> Option<&AliasMetadata> {
+        self.aliases.get(alias)
+    }
+
+    /** Whether or not the index has the given alias. */
+    pub fn contains(&self, alias: &str) -> bool {
+        self.aliases.contains_key(alias)
+    }
+
+    /** The number of aliases for the index. */
+    pub fn len(&self) -> usize {
+        self.aliases.len()
+    }
+
+    /** Whether or not the index has no aliases. */
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+    }
+}
+
+/** The properties of an alias. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct AliasMetadata {
+    filter: Option<Value>,
+    index_routing: Option<String>,
+    search_routing: Option<String>,
+    is_write_index: Option<bool>,
+}
+
+impl AliasMetadata {
+    /** The query filter for the alias. */
+    pub fn filter(&self) -> Option<&Value> {
+        self.filter.as_ref()
+    }
+
+    /** The routing value used for indexing through the alias. */
+    pub fn index_routing(&self) -> Option<&str> {
+        self.index_routing.as_ref().map(|routing| routing.as_ref())
+    }
+
+    /** The routing value used for searching through the alias. */
+    pub fn search_routing(&self) -> Option<&str> {
+        self.search_routing.as_ref().map(|routing| routing.as_ref())
+    }
+
+    /**
+    Whether or not the index is the write index for the alias.
+
+    This will be `None` if the write index wasn't set explicitly.
+    */
+    pub fn is_write_index(&self) -> Option<bool> {
+        self.is_write_index
+    }
+}
+
+/** Response for an [alias exists request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html). */
+#[derive(Deserialize, Debug)]
+pub struct IndicesExistsAliasResponse {
+    exists: bool,
+}
+
+impl IndicesExistsAliasResponse {
+    /** Whether or not the alias exists. */
+    pub fn exists(&self) -> bool {
+        self.exists
+    }
+}
+
+impl IsOk for IndicesExistsAliasResponse {
+    fn is_ok<B: ResponseBody>(
+        head: HttpResponseHead,
+        body: Unbuffered<B>,
+    ) -> Result<MaybeOkResponse<B>, ParseError> {
+        match head.status() {
+            status if status.is_success() => Ok(MaybeOkResponse::ok(json!({ "exists": true }))),
+            StatusCode::NOT_FOUND => Ok(MaybeOkResponse::ok(json!({ "exists": false }))),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,581 @@
+/*!
+Response types for [get mapping](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html) and [get field mapping](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-field-mapping.html) requests.
+*/
+
+use serde_json::{
+    self,
+    Map,
+    Value,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        HashMap,
+    },
+    fmt,
+};
+
+use crate::{
+    http::receiver::IsOkOnSuccess,
+    types::document::DocumentType,
+};
+
+const OBJECT_DATATYPE: &str = "object";
+
+/**
+Response for a [get mapping request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html).
+
+The response maps each index to its mapping.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndicesGetMappingResponse(HashMap<String, IndexMappingEntry>);
+
+#[derive(Deserialize, Debug, Clone)]
+struct IndexMappingEntry {
+    #[serde(default)]
+    mappings: IndexMapping,
+}
+
+impl IndicesGetMappingResponse {
+    /** Iterate over the indices and their mappings. */
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &IndexMapping)> {
+        self.0
+            .iter()
+            .map(|(index, entry)| (index.as_ref(), &entry.mappings))
+    }
+
+    /** Get the mapping for an index. */
+    pub fn index(&self, index: &str) -> Option<&IndexMapping> {
+        self.0.get(index).map(|entry| &entry.mappings)
+    }
+}
+
+impl IsOkOnSuccess for IndicesGetMappingResponse {}
+
+/**
+Response for a [get field mapping request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-field-mapping.html).
+
+The response maps each index to the full names of its matching fields.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndicesGetFieldMappingResponse(HashMap<String, FieldMappingEntry>);
+
+#[derive(Deserialize, Debug, Clone)]
+struct FieldMappingEntry {
+    #[serde(default)]
+    mappings: BTreeMap<String, FieldMappingSource>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct FieldMappingSource {
+    mapping: BTreeMap<String, MappedField>,
+}
+
+impl FieldMappingSource {
+    fn field(&self) -> Option<&MappedField> {
+        self.mapping.values().next()
+    }
+}
+
+impl IndicesGetFieldMappingResponse {
+    /**
+    Iterate over the indices, full field names and their mappings.
+
+    Fields that were requested but aren't mapped in an index aren't included.
+    */
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str, &MappedField)> {
+        self.0.iter().flat_map(|(index, entry)| {
+            entry.mappings.iter().filter_map(move |(name, source)| {
+                source
+                    .field()
+                    .map(|field| (index.as_ref(), name.as_ref(), field))
+            })
+        })
+    }
+
+    /** Get the mapping for a field in an index by its full name, like `title.keyword`. */
+    pub fn field(&self, index: &str, name: &str) -> Option<&MappedField> {
+        self.0
+            .get(index)
+            .and_then(|entry| entry.mappings.get(name))
+            .and_then(|source| source.field())
+    }
+}
+
+impl IsOkOnSuccess for IndicesGetFieldMappingResponse {}
+
+/**
+The mapping for an index.
+
+An `IndexMapping` is a tree of fields, along with any mapping metadata like `dynamic` or `_routing`.
+A mapping for a [`DocumentType`][DocumentType] can be built with [`from_document`][from_document] so it can be compared to the mapping returned by Elasticsearch.
+
+# Examples
+
+Check whether the live mapping for an index matches the mapping for a document type:
+
+```no_run
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::prelude::*;
+# fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+# #[derive(Serialize, Deserialize, ElasticType)]
+# struct MyType { }
+# let client = SyncClientBuilder::new().build()?;
+let response = client.index("mytype").get_mapping().send()?;
+
+let expected = IndexMapping::from_document::<MyType>()?;
+
+if let Some(mapping) = response.index("mytype") {
+    println!("up to date: {}", mapping.properties_eq(&expected));
+}
+# Ok(())
+# }
+```
+
+[DocumentType]: ../../types/document/trait.DocumentType.html
+[from_document]: #method.from_document
+*/
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct IndexMapping {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    properties: BTreeMap<String, MappedField>,
+    #[serde(flatten)]
+    meta: Map<String, Value>,
+}
+
+impl IndexMapping {
+    /** Build the mapping for a document type. */
+    pub fn from_document<TDocument>() -> Result<Self, serde_json::Error>
+    where
+        TDocument: DocumentType,
+    {
+        serde_json::to_value(TDocument::index_mapping()).and_then(serde_json::from_value)
+    }
+
+    /** Iterate over the top-level fields in the mapping. */
+    pub fn properties(&self) -> impl Iterator<Item = (&str, &MappedField)> {
+        self.properties
+            .iter()
+            .map(|(name, field)| (name.as_ref(), field))
+    }
+
+    /**
+    Iterate over every field in the mapping by its full name.
+
+    Object properties and multi-fields are included using their dotted paths, like `title.keyword`.
+    */
+    pub fn fields(&self) -> impl Iterator<Item = (String, &MappedField)> {
+        let mut fields = Vec::new();
+        collect_fields(None, &self.properties, &mut fields);
+
+        fields.into_iter()
+    }
+
+    /**
+    Get a field in the mapping by its full name.
+
+    The name is a dotted path through object properties and multi-fields, like `address.street` or `title.keyword`.
+    */
+    pub fn field(&self, name: &str) -> Option<&MappedField> {
+        let mut parts = name.split('.');
+        let mut field = self.properties.get(parts.next()?)?;
+
+        for part in parts {
+            field = field
+                .properties
+                .get(part)
+                .or_else(|| field.fields.get(part))?;
+        }
+
+        Some(field)
+    }
+
+    /** Get a mapping metadata value, like `dynamic` or `_routing`. */
+    pub fn get_meta(&self, key: &str) -> Option<&Value> {
+        self.meta.get(key)
+    }
+
+    /**
+    Whether or not this mapping has the same fields as another.
+
+    Unlike `==`, mapping metadata like `dynamic` or `_source` isn't compared.
+    */
+    pub fn properties_eq(&self, other: &IndexMapping) -> bool {
+        self.properties == other.properties
+    }
+
+    /**
+    Compare this live mapping with an expected mapping, like one built for a document type.
+
+    Fields are compared by their full names.
+    If an object or multi-field is added, removed or conflicting then its children aren't reported separately.
+    */
+    pub fn diff(&self, expected: &IndexMapping) -> MappingDiff {
+        let live = self.fields().collect::<BTreeMap<_, _>>();
+        let expected = expected.fields().collect::<BTreeMap<_, _>>();
+
+        let mut changes: Vec<MappingChange> = Vec::new();
+
+        let names = live.keys().chain(expected.keys()).collect::<BTreeSet<_>>();
+
+        for name in names {
+            let is_reported = changes.iter().any(|change| {
+                !matches!(change, MappingChange::Updated { .. })
+                    && name.starts_with(change.name())
+                    && name[change.name().len()..].starts_with('.')
+            });
+
+            if is_reported {
+                continue;
+            }
+
+            let change = match (live.get(name), expected.get(name)) {
+                (None, Some(expected)) => MappingChange::Added {
+                    name: name.clone(),
+                    expected: MappedField::clone(expected),
+                },
+                (Some(live), None) => MappingChange::Removed {
+                    name: name.clone(),
+                    live: MappedField::clone(live),
+                },
+                (Some(live), Some(expected)) if !live.shallow_eq(expected) => {
+                    MappingChange::Conflict {
+                        name: name.clone(),
+                        live: MappedField::clone(live),
+                        expected: MappedField::clone(expected),
+                    }
+                }
+                (Some(live), Some(expected)) if live.params != expected.params => {
+                    MappingChange::Updated {
+                        name: name.clone(),
+                        live: MappedField::clone(live),
+                        expected: MappedField::clone(expected),
+                    }
+                }
+                _ => continue,
+            };
+
+            changes.push(change);
+        }
+
+        MappingDiff { changes }
+    }
+}
+
+fn collect_fields<'a>(
+    parent: Option<&str>,
+    properties: &'a BTreeMap<String, MappedField>,
+    fields: &mut Vec<(String, &'a MappedField)>,
+) {
+    for (name, field) in properties {
+        let path = match parent {
+            Some(parent) => format!("{}.{}", parent, name),
+            None => name.clone(),
+        };
+
+        collect_fields(Some(&path), &field.properties, fields);
+        collect_fields(Some(&path), &field.fields, fields);
+
+        fields.push((path, field));
+    }
+}
+
+/**
+The mapping for a single field.
+
+Objects are mapped with nested [`properties`][properties] and fields like `text` can have [multi-fields][fields].
+Any other parameters, like `format` or `analyzer`, are available through [`param`][param].
+
+[properties]: #method.properties
+[fields]: #method.fields
+[param]: #method.param
+*/
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MappedField {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    ty: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    properties: BTreeMap<String, MappedField>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    fields: BTreeMap<String, MappedField>,
+    #[serde(flatten)]
+    params: Map<String, Value>,
+}
+
+impl MappedField {
+    /**
+    The datatype of the field, like `keyword` or `date`.
+
+    Elasticsearch doesn't return a type for objects, so fields without a type are `object`s.
+    */
+    pub fn ty(&self) -> &str {
+        self.ty
+            .as_ref()
+            .map(AsRef::as_ref)
+            .unwrap_or(OBJECT_DATATYPE)
+    }
+
+    /** Get a mapping parameter for the field, like `format` or `analyzer`. */
+    pub fn param(&self, name: &str) -> Option<&Value> {
+        self.params.get(name)
+    }
+
+    /** Iterate over the mapping parameters for the field. */
+    pub fn params(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.params
+            .iter()
+            .map(|(name, value)| (name.as_ref(), value))
+    }
+
+    /** Iterate over the properties of an object field. */
+    pub fn properties(&self) -> impl Iterator<Item = (&str, &MappedField)> {
+        self.properties
+            .iter()
+            .map(|(name, field)| (name.as_ref(), field))
+    }
+
+    /** Iterate over the multi-fields of the field. */
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &MappedField)> {
+        self.fields
+            .iter()
+            .map(|(name, field)| (name.as_ref(), field))
+    }
+}
+
+/**
+Mapping parameters that can be changed on an existing field with a put mapping request.
+
+See the docs for [updating existing fields](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-put-mapping.html#updating-field-mappings).
+*/
+const UPDATABLE_PARAMS: &[&str] = &[
+    "copy_to",
+    "eager_global_ordinals",
+    "ignore_above",
+    "ignore_malformed",
+    "search_analyzer",
+    "search_quote_analyzer",
+];
+
+impl MappedField {
+    /**
+    Whether or not the field has the same type and parameters as another, ignoring child fields.
+
+    Parameters that can be updated in place aren't compared.
+    */
+    fn shallow_eq(&self, other: &MappedField) -> bool {
+        fn fixed_params(field: &MappedField) -> impl Iterator<Item = (&String, &Value)> {
+            field
+                .params
+                .iter()
+                .filter(|(name, _)| !UPDATABLE_PARAMS.contains(&name.as_str()))
+        }
+
+        self.ty() == other.ty() && fixed_params(self).eq(fixed_params(other))
+    }
+}
+
+impl PartialEq for MappedField {
+    fn eq(&self, other: &MappedField) -> bool {
+        self.ty() == other.ty()
+            && self.properties == other.properties
+            && self.fields == other.fields
+            && self.params == other.params
+    }
+}
+
+/**
+The differences between a live index mapping and an expected mapping.
+
+A `MappingDiff` is returned by [`IndexMapping.diff`][IndexMapping.diff].
+New fields can be added to an existing index with a put mapping request, and fields that are no longer expected can be left in place.
+A few parameters of an existing field, like `ignore_above` or `search_analyzer`, can also be updated with a put mapping request.
+Changing the type or any other parameters of an existing field requires the index to be reindexed.
+
+# Examples
+
+Refuse to run against an index whose mapping conflicts with a document type:
+
+```no_run
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::prelude::*;
+# fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+# #[derive(Serialize, Deserialize, ElasticType)]
+# struct MyType { }
+# let client = SyncClientBuilder::new().build()?;
+let diff = client.document::<MyType>().check_mapping().send()?;
+
+if diff.requires_reindex() {
+    for conflict in diff.conflicts() {
+        println!("{}", conflict);
+    }
+
+    panic!("the index mapping is incompatible with `MyType`");
+}
+# Ok(())
+# }
+```
+
+[IndexMapping.diff]: struct.IndexMapping.html#method.diff
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MappingDiff {
+    changes: Vec<MappingChange>,
+}
+
+impl MappingDiff {
+    /** Iterate over all changes between the live and expected mappings. */
+    pub fn iter(&self) -> impl Iterator<Item = &MappingChange> {
+        self.changes.iter()
+    }
+
+    /** Iterate over fields that are expected but aren't in the live mapping. */
+    pub fn added(&self) -> impl Iterator<Item = &MappingChange> {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, MappingChange::Added { .. }))
+    }
+
+    /** Iterate over fields that are in the live mapping but aren't expected. */
+    pub fn removed(&self) -> impl Iterator<Item = &MappingChange> {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, MappingChange::Removed { .. }))
+    }
+
+    /** Iterate over fields whose parameters can be updated to the expected ones in place. */
+    pub fn updated(&self) -> impl Iterator<Item = &MappingChange> {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, MappingChange::Updated { .. }))
+    }
+
+    /** Iterate over fields whose live mapping conflicts with the expected one. */
+    pub fn conflicts(&self) -> impl Iterator<Item = &MappingChange> {
+        self.changes
+            .iter()
+            .filter(|change| change.requires_reindex())
+    }
+
+    /** Whether or not the live and expected mappings have the same fields. */
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /**
+    Whether or not the live mapping can be updated to the expected one in place.
+
+    This is the case when there are no conflicting fields.
+    */
+    pub fn is_compatible(&self) -> bool {
+        !self.requires_reindex()
+    }
+
+    /** Whether or not any field conflicts, so the index needs to be reindexed. */
+    pub fn requires_reindex(&self) -> bool {
+        self.changes.iter().any(MappingChange::requires_reindex)
+    }
+}
+
+/** A single difference between a live index mapping and an expected mapping. */
+#[derive(Debug, Clone, PartialEq)]
+pub enum MappingChange {
+    /**
+    A field is expected but isn't in the live mapping.
+
+    This is a compatible update.
+    */
+    Added {
+        /** The full name of the field. */
+        name: String,
+        /** The expected mapping for the field. */
+        expected: MappedField,
+    },
+    /**
+    A field is in the live mapping but isn't expected.
+
+    Fields can't be removed from a mapping, but they don't need to be.
+    This is a compatible update.
+    */
+    Removed {
+        /** The full name of the field. */
+        name: String,
+        /** The live mapping for the field. */
+        live: MappedField,
+    },
+    /**
+    A field has different parameters in the live mapping that can be updated in place, like `ignore_above`.
+
+    This is a compatible update.
+    */
+    Updated {
+        /** The full name of the field. */
+        name: String,
+        /** The live mapping for the field. */
+        live: MappedField,
+        /** The expected mapping for the field. */
+        expected: MappedField,
+    },
+    /**
+    A field has a different type or parameters in the live mapping.
+
+    This requires the index to be reindexed.
+    */
+    Conflict {
+        /** The full name of the field. */
+        name: String,
+        /** The live mapping for the field. */
+        live: MappedField,
+        /** The expected mapping for the field. */
+        expected: MappedField,
+    },
+}
+
+impl MappingChange {
+    /** The full name of the changed field, like `title.keyword`. */
+    pub fn name(&self) -> &str {
+        match self {
+            MappingChange::Added { name, .. }
+            | MappingChange::Removed { name, .. }
+            | MappingChange::Updated { name, .. }
+            | MappingChange::Conflict { name, .. } => name,
+        }
+    }
+
+    /** Whether or not this change requires the index to be reindexed. */
+    pub fn requires_reindex(&self) -> bool {
+        matches!(self, MappingChange::Conflict { .. })
+    }
+}
+
+impl fmt::Display for MappingChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MappingChange::Added { name, expected } => {
+                write!(f, "added field `{}` of type `{}`", name, expected.ty())
+            }
+            MappingChange::Removed { name, live } => {
+                write!(f, "removed field `{}` of type `{}`", name, live.ty())
+            }
+            MappingChange::Updated { name, .. } => {
+                write!(f, "updated field `{}`: mapping parameters differ", name)
+            }
+            MappingChange::Conflict {
+                name,
+                live,
+                expected,
+            } if live.ty() != expected.ty() => write!(
+                f,
+                "conflicting field `{}`: expected type `{}` but found `{}`",
+                name,
+                expected.ty(),
+                live.ty()
+            ),
+            MappingChange::Conflict { name, .. } => {
+                write!(f, "conflicting field `{}`: mapping parameters differ", name)
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+/*!
+Response types for [shrink](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-shrink-index.html) and [split](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-split-index.html) index requests.
+*/
+
+use crate::http::receiver::IsOkOnSuccess;
+
+/**
+Response for a [shrink](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-shrink-index.html) or [split](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-split-index.html) index request.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndicesResizeResponse {
+    acknowledged: bool,
+    shards_acknowledged: bool,
+    index: String,
+}
+
+impl IndicesResizeResponse {
+    /** Whether or not the target index was created. */
+    pub fn acknowledged(&self) -> bool {
+        self.acknowledged
+    }
+
+    /** Whether or not the shards of the target index were started before the request timed out. */
+    pub fn shards_acknowledged(&self) -> bool {
+        self.shards_acknowledged
+    }
+
+    /** The name of the target index. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+}
+
+impl IsOkOnSuccess for IndicesResizeResponse {}
@@ -0,0 +1,78 @@
+/*!
+Response types for [rollover index requests](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-rollover-index.html).
+*/
+
+use std::collections::HashMap;
+
+use crate::http::receiver::IsOkOnSuccess;
+
+/**
+Response for a [rollover index request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-rollover-index.html).
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndicesRolloverResponse {
+    acknowledged: bool,
+    shards_acknowledged: bool,
+    old_index: String,
+    new_index: String,
+    rolled_over: bool,
+    dry_run: bool,
+    #[serde(default)]
+    conditions: HashMap<String, bool>,
+}
+
+impl IndicesRolloverResponse {
+    /** Whether or not the rollover was acknowledged by the cluster. */
+    pub fn acknowledged(&self) -> bool {
+        self.acknowledged
+    }
+
+    /** Whether or not the shards of the new index were started before the request timed out. */
+    pub fn shards_acknowledged(&self) -> bool {
+        self.shards_acknowledged
+    }
+
+    /** The index the alias pointed to before the rollover. */
+    pub fn old_index(&self) -> &str {
+        &self.old_index
+    }
+
+    /** The index the alias points to after the rollover. */
+    pub fn new_index(&self) -> &str {
+        &self.new_index
+    }
+
+    /**
+    Whether or not the alias was rolled over.
+
+    This is always `false` for a dry run.
+    */
+    pub fn rolled_over(&self) -> bool {
+        self.rolled_over
+    }
+
+    /** Whether or not the request was a dry run. */
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /**
+    Iterate over the conditions and whether or not they matched.
+
+    Conditions are formatted by Elasticsearch, like `[max_docs: 1000]`.
+    */
+    pub fn conditions(&self) -> impl Iterator<Item = (&str, bool)> {
+        self.conditions
+            .iter()
+            .map(|(condition, &matched)| (condition.as_ref(), matched))
+    }
+
+    /** Iterate over the conditions that matched. */
+    pub fn matched_conditions(&self) -> impl Iterator<Item = &str> {
+        self.conditions()
+            .filter(|&(_, matched)| matched)
+            .map(|(condition, _)| condition)
+    }
+}
+
+impl IsOkOnSuccess for IndicesRolloverResponse {}
@@ -0,0 +1,161 @@
+/*!
+Response types for an [index segments request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-segments.html).
+*/
+
+use std::collections::{
+    BTreeMap,
+    HashMap,
+};
+
+use super::{
+    common::Shards,
+    index_stats::ShardRouting,
+};
+
+use crate::http::receiver::IsOkOnSuccess;
+
+/**
+Response for an [index segments request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-segments.html).
+
+The response maps each index to the segments in each of its shards.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndicesSegmentsResponse {
+    #[serde(rename = "_shards")]
+    shards: Shards,
+    #[serde(default)]
+    indices: HashMap<String, IndexSegments>,
+}
+
+impl IndicesSegmentsResponse {
+    /** Shards metadata for the request. */
+    pub fn shards(&self) -> &Shards {
+        &self.shards
+    }
+
+    /** Iterate over the indices and their segments. */
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &IndexSegments)> {
+        self.indices
+            .iter()
+            .map(|(index, segments)| (index.as_ref(), segments))
+    }
+
+    /** Get the segments for an index. */
+    pub fn index(&self, index: &str) -> Option<&IndexSegments> {
+        self.indices.get(index)
+    }
+}
+
+impl IsOkOnSuccess for IndicesSegmentsResponse {}
+
+/** The segments for each shard of an index. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndexSegments {
+    #[serde(default)]
+    shards: BTreeMap<u32, Vec<ShardSegments>>,
+}
+
+impl IndexSegments {
+    /** Iterate over the shard numbers and segments for each copy of that shard. */
+    pub fn shards(&self) -> impl Iterator<Item = (u32, &ShardSegments)> {
+        self.shards
+            .iter()
+            .flat_map(|(shard, copies)| copies.iter().map(move |segments| (*shard, segments)))
+    }
+}
+
+/** The segments for a single copy of a shard. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct ShardSegments {
+    routing: ShardRouting,
+    num_committed_segments: u32,
+    num_search_segments: u32,
+    #[serde(default)]
+    segments: BTreeMap<String, Segment>,
+}
+
+impl ShardSegments {
+    /** Where the shard is allocated. */
+    pub fn routing(&self) -> &ShardRouting {
+        &self.routing
+    }
+
+    /** The number of segments that have been committed to disk. */
+    pub fn num_committed_segments(&self) -> u32 {
+        self.num_committed_segments
+    }
+
+    /** The number of segments that are searchable. */
+    pub fn num_search_segments(&self) -> u32 {
+        self.num_search_segments
+    }
+
+    /** Iterate over the names and details of the segments in the shard. */
+    pub fn segments(&self) -> impl Iterator<Item = (&str, &Segment)> {
+        self.segments
+            .iter()
+            .map(|(name, segment)| (name.as_ref(), segment))
+    }
+}
+
+/** A single Lucene segment. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct Segment {
+    generation: u64,
+    num_docs: u64,
+    deleted_docs: u64,
+    size_in_bytes: u64,
+    #[serde(default)]
+    memory_in_bytes: u64,
+    committed: bool,
+    search: bool,
+    version: String,
+    compound: bool,
+}
+
+impl Segment {
+    /** The generation of the segment. */
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /** The number of documents in the segment, excluding deleted ones. */
+    pub fn num_docs(&self) -> u64 {
+        self.num_docs
+    }
+
+    /** The number of deleted documents in the segment. */
+    pub fn deleted_docs(&self) -> u64 {
+        self.deleted_docs
+    }
+
+    /** The size of the segment on disk in bytes. */
+    pub fn size_in_bytes(&self) -> u64 {
+        self.size_in_bytes
+    }
+
+    /** The memory used by the segment in bytes. */
+    pub fn memory_in_bytes(&self) -> u64 {
+        self.memory_in_bytes
+    }
+
+    /** Whether or not the segment has been committed to disk. */
+    pub fn committed(&self) -> bool {
+        self.committed
+    }
+
+    /** Whether or not the segment is searchable. */
+    pub fn search(&self) -> bool {
+        self.search
+    }
+
+    /** The Lucene version that wrote the segment. */
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /** Whether or not the segment is stored in a compound file. */
+    pub fn compound(&self) -> bool {
+        self.compound
+    }
+}
@@ -0,0 +1,45 @@
+/*!
+Response types for a [get index settings request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-settings.html).
+*/
+
+use std::collections::HashMap;
+
+use crate::{
+    client::requests::index_settings::IndexSettings,
+    http::receiver::IsOkOnSuccess,
+};
+
+/**
+Response for a [get index settings request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-settings.html).
+
+The response maps each index to its settings.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndicesGetSettingsResponse(HashMap<String, IndexSettingsEntry>);
+
+#[derive(Deserialize, Debug, Clone)]
+struct IndexSettingsEntry {
+    settings: IndexSettingsNamespace,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct IndexSettingsNamespace {
+    #[serde(default)]
+    index: IndexSettings,
+}
+
+impl IndicesGetSettingsResponse {
+    /** Iterate over the indices and their settings. */
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &IndexSettings)> {
+        self.0
+            .iter()
+            .map(|(index, entry)| (index.as_ref(), &entry.settings.index))
+    }
+
+    /** Get the settings for an index. */
+    pub fn index(&self, index: &str) -> Option<&IndexSettings> {
+        self.0.get(index).map(|entry| &entry.settings.index)
+    }
+}
+
+impl IsOkOnSuccess for IndicesGetSettingsResponse {}
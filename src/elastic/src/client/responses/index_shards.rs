@@ -0,0 +1,32 @@
+/*!
+Response types for index maintenance requests, like [refresh](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-refresh.html) and [flush](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-flush.html).
+*/
+
+use super::common::Shards;
+
+use crate::http::receiver::IsOkOnSuccess;
+
+/**
+Response for an index maintenance request that is broadcast to shards.
+
+This is returned by refresh, flush, force merge and clear cache requests.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndicesShardsResponse {
+    #[serde(rename = "_shards")]
+    shards: Shards,
+}
+
+impl IndicesShardsResponse {
+    /** Shards metadata for the request. */
+    pub fn shards(&self) -> &Shards {
+        &self.shards
+    }
+
+    /** Whether or not every shard successfully processed the request. */
+    pub fn all_successful(&self) -> bool {
+        self.shards.failed() == 0
+    }
+}
+
+impl IsOkOnSuccess for IndicesShardsResponse {}
@@ -0,0 +1,474 @@
+/*!
+Response types for an [index stats request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-stats.html).
+*/
+
+use serde_json::{
+    Map,
+    Value,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    time::Duration,
+};
+
+use super::common::Shards;
+
+use crate::http::receiver::IsOkOnSuccess;
+
+/**
+Response for an [index stats request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-stats.html).
+
+Stats are aggregated across all indices in the request, and are also available for each index unless the `cluster` level was requested.
+Stats for each shard are only available if the `shards` level was requested.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndicesStatsResponse {
+    #[serde(rename = "_shards")]
+    shards: Shards,
+    #[serde(rename = "_all")]
+    all: IndexStats,
+    #[serde(default)]
+    indices: HashMap<String, IndexStats>,
+}
+
+impl IndicesStatsResponse {
+    /** Shards metadata for the request. */
+    pub fn shards(&self) -> &Shards {
+        &self.shards
+    }
+
+    /** Stats aggregated across all indices in the request. */
+    pub fn all(&self) -> &IndexStats {
+        &self.all
+    }
+
+    /** Iterate over the indices and their stats. */
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &IndexStats)> {
+        self.indices
+            .iter()
+            .map(|(index, stats)| (index.as_ref(), stats))
+    }
+
+    /** Get the stats for an index. */
+    pub fn index(&self, index: &str) -> Option<&IndexStats> {
+        self.indices.get(index)
+    }
+}
+
+impl IsOkOnSuccess for IndicesStatsResponse {}
+
+/** Stats for an index, or for a set of indices. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndexStats {
+    uuid: Option<String>,
+    primaries: IndexMetrics,
+    total: IndexMetrics,
+    #[serde(default)]
+    shards: BTreeMap<u32, Vec<ShardStats>>,
+}
+
+impl IndexStats {
+    /** The unique id of the index. */
+    pub fn uuid(&self) -> Option<&str> {
+        self.uuid.as_ref().map(|uuid| uuid.as_ref())
+    }
+
+    /** Stats for primary shards only. */
+    pub fn primaries(&self) -> &IndexMetrics {
+        &self.primaries
+    }
+
+    /** Stats for all primary and replica shards. */
+    pub fn total(&self) -> &IndexMetrics {
+        &self.total
+    }
+
+    /**
+    Iterate over the shard numbers and stats for each copy of that shard.
+
+    This is only populated if the `shards` level was requested.
+    */
+    pub fn shards(&self) -> impl Iterator<Item = (u32, &ShardStats)> {
+        self.shards
+            .iter()
+            .flat_map(|(shard, copies)| copies.iter().map(move |stats| (*shard, stats)))
+    }
+}
+
+/** Stats for a single copy of a shard. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct ShardStats {
+    routing: ShardRouting,
+    #[serde(flatten)]
+    metrics: IndexMetrics,
+}
+
+impl ShardStats {
+    /** Where the shard is allocated. */
+    pub fn routing(&self) -> &ShardRouting {
+        &self.routing
+    }
+
+    /** Stats for the shard. */
+    pub fn metrics(&self) -> &IndexMetrics {
+        &self.metrics
+    }
+}
+
+/** Where a copy of a shard is allocated. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct ShardRouting {
+    state: String,
+    primary: bool,
+    node: Option<String>,
+}
+
+impl ShardRouting {
+    /** The state of the shard, like `STARTED` or `RELOCATING`. */
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /** Whether or not the shard is a primary. */
+    pub fn primary(&self) -> bool {
+        self.primary
+    }
+
+    /** The id of the node the shard is allocated to. */
+    pub fn node(&self) -> Option<&str> {
+        self.node.as_ref().map(|node| node.as_ref())
+    }
+}
+
+/**
+The stats collected for an index or shard.
+
+Only the metrics that were requested are returned.
+Stats like `indexing` and `search` are totals since the shard was allocated, so rates can be calculated by comparing two samples over time.
+Metrics without a typed accessor are available through [`get_metric`][get_metric].
+
+[get_metric]: #method.get_metric
+*/
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct IndexMetrics {
+    docs: Option<DocsStats>,
+    store: Option<StoreStats>,
+    indexing: Option<IndexingStats>,
+    get: Option<GetStats>,
+    search: Option<SearchStats>,
+    merges: Option<MergeStats>,
+    refresh: Option<TimedStats>,
+    flush: Option<TimedStats>,
+    segments: Option<SegmentsStats>,
+    #[serde(flatten)]
+    other: Map<String, Value>,
+}
+
+impl IndexMetrics {
+    /** Document counts. */
+    pub fn docs(&self) -> Option<&DocsStats> {
+        self.docs.as_ref()
+    }
+
+    /** Store size. */
+    pub fn store(&self) -> Option<&StoreStats> {
+        self.store.as_ref()
+    }
+
+    /** Indexing operations. */
+    pub fn indexing(&self) -> Option<&IndexingStats> {
+        self.indexing.as_ref()
+    }
+
+    /** Get operations. */
+    pub fn get(&self) -> Option<&GetStats> {
+        self.get.as_ref()
+    }
+
+    /** Search operations. */
+    pub fn search(&self) -> Option<&SearchStats> {
+        self.search.as_ref()
+    }
+
+    /** Merge operations. */
+    pub fn merges(&self) -> Option<&MergeStats> {
+        self.merges.as_ref()
+    }
+
+    /** Refresh operations. */
+    pub fn refresh(&self) -> Option<&TimedStats> {
+        self.refresh.as_ref()
+    }
+
+    /** Flush operations. */
+    pub fn flush(&self) -> Option<&TimedStats> {
+        self.flush.as_ref()
+    }
+
+    /** Segment counts and memory. */
+    pub fn segments(&self) -> Option<&SegmentsStats> {
+        self.segments.as_ref()
+    }
+
+    /** Get any other metric by name, like `query_cache` or `translog`. */
+    pub fn get_metric(&self, name: &str) -> Option<&Value> {
+        self.other.get(name)
+    }
+}
+
+/** Document counts. */
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct DocsStats {
+    count: u64,
+    deleted: u64,
+}
+
+impl DocsStats {
+    /** The number of documents, excluding deleted ones. */
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /** The number of deleted documents that haven't been merged away yet. */
+    pub fn deleted(&self) -> u64 {
+        self.deleted
+    }
+}
+
+/** Store size. */
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct StoreStats {
+    size_in_bytes: u64,
+}
+
+impl StoreStats {
+    /** The size of the store in bytes. */
+    pub fn size_in_bytes(&self) -> u64 {
+        self.size_in_bytes
+    }
+}
+
+/** Indexing operations. */
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct IndexingStats {
+    index_total: u64,
+    index_time_in_millis: u64,
+    index_current: u64,
+    index_failed: u64,
+    delete_total: u64,
+    delete_time_in_millis: u64,
+    delete_current: u64,
+}
+
+impl IndexingStats {
+    /** The total number of index operations. */
+    pub fn index_total(&self) -> u64 {
+        self.index_total
+    }
+
+    /** The total time spent on index operations. */
+    pub fn index_time(&self) -> Duration {
+        Duration::from_millis(self.index_time_in_millis)
+    }
+
+    /** The number of index operations currently running. */
+    pub fn index_current(&self) -> u64 {
+        self.index_current
+    }
+
+    /** The total number of failed index operations. */
+    pub fn index_failed(&self) -> u64 {
+        self.index_failed
+    }
+
+    /** The total number of delete operations. */
+    pub fn delete_total(&self) -> u64 {
+        self.delete_total
+    }
+
+    /** The total time spent on delete operations. */
+    pub fn delete_time(&self) -> Duration {
+        Duration::from_millis(self.delete_time_in_millis)
+    }
+
+    /** The number of delete operations currently running. */
+    pub fn delete_current(&self) -> u64 {
+        self.delete_current
+    }
+}
+
+/** Get operations. */
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct GetStats {
+    total: u64,
+    time_in_millis: u64,
+    exists_total: u64,
+    missing_total: u64,
+    current: u64,
+}
+
+impl GetStats {
+    /** The total number of get operations. */
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /** The total time spent on get operations. */
+    pub fn time(&self) -> Duration {
+        Duration::from_millis(self.time_in_millis)
+    }
+
+    /** The total number of get operations for documents that exist. */
+    pub fn exists_total(&self) -> u64 {
+        self.exists_total
+    }
+
+    /** The total number of get operations for documents that are missing. */
+    pub fn missing_total(&self) -> u64 {
+        self.missing_total
+    }
+
+    /** The number of get operations currently running. */
+    pub fn current(&self) -> u64 {
+        self.current
+    }
+}
+
+/** Search operations. */
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct SearchStats {
+    open_contexts: u64,
+    query_total: u64,
+    query_time_in_millis: u64,
+    query_current: u64,
+    fetch_total: u64,
+    fetch_time_in_millis: u64,
+    fetch_current: u64,
+    scroll_total: u64,
+    scroll_time_in_millis: u64,
+    scroll_current: u64,
+}
+
+impl SearchStats {
+    /** The number of open search contexts. */
+    pub fn open_contexts(&self) -> u64 {
+        self.open_contexts
+    }
+
+    /** The total number of query phases. */
+    pub fn query_total(&self) -> u64 {
+        self.query_total
+    }
+
+    /** The total time spent in query phases. */
+    pub fn query_time(&self) -> Duration {
+        Duration::from_millis(self.query_time_in_millis)
+    }
+
+    /** The number of query phases currently running. */
+    pub fn query_current(&self) -> u64 {
+        self.query_current
+    }
+
+    /** The total number of fetch phases. */
+    pub fn fetch_total(&self) -> u64 {
+        self.fetch_total
+    }
+
+    /** The total time spent in fetch phases. */
+    pub fn fetch_time(&self) -> Duration {
+        Duration::from_millis(self.fetch_time_in_millis)
+    }
+
+    /** The number of fetch phases currently running. */
+    pub fn fetch_current(&self) -> u64 {
+        self.fetch_current
+    }
+
+    /** The total number of scrolls. */
+    pub fn scroll_total(&self) -> u64 {
+        self.scroll_total
+    }
+
+    /** The total time scrolls were kept open. */
+    pub fn scroll_time(&self) -> Duration {
+        Duration::from_millis(self.scroll_time_in_millis)
+    }
+
+    /** The number of scrolls currently open. */
+    pub fn scroll_current(&self) -> u64 {
+        self.scroll_current
+    }
+}
+
+/** Merge operations. */
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct MergeStats {
+    current: u64,
+    total: u64,
+    total_time_in_millis: u64,
+    total_size_in_bytes: u64,
+}
+
+impl MergeStats {
+    /** The number of merges currently running. */
+    pub fn current(&self) -> u64 {
+        self.current
+    }
+
+    /** The total number of merges. */
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /** The total time spent merging. */
+    pub fn total_time(&self) -> Duration {
+        Duration::from_millis(self.total_time_in_millis)
+    }
+
+    /** The total size of merged segments in bytes. */
+    pub fn total_size_in_bytes(&self) -> u64 {
+        self.total_size_in_bytes
+    }
+}
+
+/** Operations that are only counted and timed, like refreshes and flushes. */
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct TimedStats {
+    total: u64,
+    total_time_in_millis: u64,
+}
+
+impl TimedStats {
+    /** The total number of operations. */
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /** The total time spent on operations. */
+    pub fn total_time(&self) -> Duration {
+        Duration::from_millis(self.total_time_in_millis)
+    }
+}
+
+/** Segment counts and memory. */
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct SegmentsStats {
+    count: u64,
+    memory_in_bytes: u64,
+}
+
+impl SegmentsStats {
+    /** The number of segments. */
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /** The memory used by segments in bytes. */
+    pub fn memory_in_bytes(&self) -> u64 {
+        self.memory_in_bytes
+    }
+}
@@ -0,0 +1,126 @@
+/*!
+Response types for [index template requests](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html).
+*/
+
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::http::{
+    receiver::{
+        HttpResponseHead,
+        IsOk,
+        IsOkOnSuccess,
+        MaybeOkResponse,
+        ParseError,
+        ResponseBody,
+        Unbuffered,
+    },
+    StatusCode,
+};
+
+/**
+Response for a [get index template request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html).
+
+The response maps each template name to its definition.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndicesGetTemplateResponse(HashMap<String, IndexTemplate>);
+
+impl IndicesGetTemplateResponse {
+    /** Iterate over the templates and their definitions. */
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &IndexTemplate)> {
+        self.0
+            .iter()
+            .map(|(name, template)| (name.as_ref(), template))
+    }
+
+    /** Get the definition of a template. */
+    pub fn template(&self, name: &str) -> Option<&IndexTemplate> {
+        self.0.get(name)
+    }
+
+    /** The number of templates in the response. */
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /** Whether or not the response contains no templates. */
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl IsOkOnSuccess for IndicesGetTemplateResponse {}
+
+/** The definition of an index template. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndexTemplate {
+    index_patterns: Vec<String>,
+    #[serde(default)]
+    order: i32,
+    version: Option<i32>,
+    #[serde(default)]
+    settings: Value,
+    #[serde(default)]
+    mappings: Value,
+    #[serde(default)]
+    aliases: Value,
+}
+
+impl IndexTemplate {
+    /** Iterate over the index patterns the template applies to. */
+    pub fn index_patterns(&self) -> impl Iterator<Item = &str> {
+        self.index_patterns.iter().map(|pattern| pattern.as_ref())
+    }
+
+    /** The order of the template. */
+    pub fn order(&self) -> i32 {
+        self.order
+    }
+
+    /** The version number of the template, if one was set. */
+    pub fn version(&self) -> Option<i32> {
+        self.version
+    }
+
+    /** The index settings applied by the template. */
+    pub fn settings(&self) -> &Value {
+        &self.settings
+    }
+
+    /** The mappings applied by the template. */
+    pub fn mappings(&self) -> &Value {
+        &self.mappings
+    }
+
+    /** The aliases applied by the template. */
+    pub fn aliases(&self) -> &Value {
+        &self.aliases
+    }
+}
+
+/** Response for an [index template exists request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html). */
+#[derive(Deserialize, Debug)]
+pub struct IndicesExistsTemplateResponse {
+    exists: bool,
+}
+
+impl IndicesExistsTemplateResponse {
+    /** Whether or not the template exists. */
+    pub fn exists(&self) -> bool {
+        self.exists
+    }
+}
+
+impl IsOk for IndicesExistsTemplateResponse {
+    fn is_ok<B: ResponseBody>(
+        head: HttpResponseHead,
+        body: Unbuffered<B>,
+    ) -> Result<MaybeOkResponse<B>, ParseError> {
+        match head.status() {
+            status if status.is_success() => Ok(MaybeOkResponse::ok(json!({ "exists": true }))),
+            StatusCode::NOT_FOUND => Ok(MaybeOkResponse::ok(json!({ "exists": false }))),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
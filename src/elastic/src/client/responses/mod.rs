@@ -5,18 +5,35 @@ This module contains implementation details that are useful if you want to custo
 */
 
 pub mod bulk;
+mod bulk_by_scroll;
 mod command;
 pub mod common;
 mod document_delete;
+mod document_explain;
 mod document_get;
 mod document_index;
+mod document_term_vectors;
 mod document_update;
 pub mod nodes_info;
 mod ping;
 pub mod search;
 mod sql;
+mod tasks;
 
+mod index_alias;
 mod index_exists;
+mod index_mapping;
+mod index_resize;
+mod index_rollover;
+mod index_segments;
+mod index_settings;
+mod index_shards;
+mod index_stats;
+mod index_template;
+
+mod cluster_health;
+mod cluster_settings;
+mod cluster_state;
 
 #[cfg(test)]
 mod tests;
@@ -27,35 +44,91 @@ pub use self::{
         BulkErrorsResponse,
         BulkResponse,
     },
+    bulk_by_scroll::*,
     command::*,
     document_delete::*,
+    document_explain::*,
     document_get::*,
     document_index::*,
+    document_term_vectors::*,
     document_update::*,
     nodes_info::NodesInfoResponse,
     ping::*,
     search::SearchResponse,
     sql::*,
+    tasks::*,
 };
 
-pub use self::index_exists::*;
+pub use self::{
+    cluster_health::*,
+    cluster_settings::*,
+    cluster_state::*,
+    index_alias::*,
+    index_exists::*,
+    index_mapping::*,
+    index_resize::*,
+    index_rollover::*,
+    index_segments::*,
+    index_settings::*,
+    index_shards::*,
+    index_stats::*,
+    index_template::*,
+};
 
 pub mod prelude {
     /*! A glob import for convenience. */
 
     pub use super::{
         bulk::Action as BulkAction,
+        BulkByScrollResponse,
+        BulkByScrollStatus,
         BulkErrorsResponse,
         BulkResponse,
+        ClusterGetSettingsResponse,
+        ClusterHealthResponse,
+        ClusterPutSettingsResponse,
+        ClusterStateResponse,
         CommandResponse,
         DeleteResponse,
+        ExplainResponse,
+        Explanation,
+        FieldTermVectors,
         GetResponse,
+        HealthStatus,
+        IndexMapping,
         IndexResponse,
+        IndicesExistsAliasResponse,
         IndicesExistsResponse,
+        IndicesExistsTemplateResponse,
+        IndicesGetAliasResponse,
+        IndicesGetFieldMappingResponse,
+        IndicesGetMappingResponse,
+        IndicesGetSettingsResponse,
+        IndicesGetTemplateResponse,
+        IndicesResizeResponse,
+        IndicesRolloverResponse,
+        IndicesSegmentsResponse,
+        IndicesShardsResponse,
+        IndicesStatsResponse,
+        MappedField,
+        MappingChange,
+        MappingDiff,
+        MtermVectorsResponse,
         NodesInfoResponse,
         PingResponse,
+        ReindexRethrottleResponse,
         SearchResponse,
+        SettingsMap,
         SqlQueryResponse,
+        TaskFailure,
+        TaskInfo,
+        TaskResponse,
+        TasksCancelResponse,
+        TasksGetResponse,
+        TasksListResponse,
+        TermStatistics,
+        TermVectorsResponse,
         UpdateResponse,
+        UpdateResult,
     };
 }
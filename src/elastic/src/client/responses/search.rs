@@ -8,7 +8,10 @@ use serde_json::{
     Value,
 };
 
-use super::common::Shards;
+use super::common::{
+    Fields,
+    Shards,
+};
 
 use crate::{
     http::receiver::IsOkOnSuccess,
@@ -265,6 +268,8 @@ pub struct Hit<T> {
     routing: Option<String>,
     highlight: Option<Value>,
     sort: Option<Value>,
+    #[serde(default)]
+    fields: Fields,
 }
 
 impl<T> Hit<T> {
@@ -278,6 +283,15 @@ impl<T> Hit<T> {
         self.source
     }
 
+    /**
+    The stored fields and doc value fields returned for the hit.
+
+    Fields are only returned if they were requested using `stored_fields` or `docvalue_fields` on the request.
+    */
+    pub fn fields(&self) -> &Fields {
+        &self.fields
+    }
+
     /** The index for the hit. */
     pub fn index(&self) -> Index {
         Index::from(&self.index)
@@ -0,0 +1,298 @@
+/*!
+Response types for requests that run as [tasks](https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html).
+*/
+
+use serde::de::DeserializeOwned;
+use serde_json::{
+    Map,
+    Value,
+};
+use std::{
+    collections::BTreeMap,
+    time::Duration,
+};
+
+use crate::{
+    error::{
+        self,
+        Error,
+    },
+    http::{
+        receiver::{
+            IsOkOnSuccess,
+            ParseError,
+            ParsedApiError,
+            ResponseError,
+            UnknownApiError,
+        },
+        StatusCode,
+    },
+    params::TaskId,
+};
+
+/**
+Response for a request that was started as a background task.
+
+The task id can be used to check on the progress of the task using the tasks API.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct TaskResponse {
+    task: String,
+}
+
+impl TaskResponse {
+    /** The id of the task, in the form `node_id:task_number`. */
+    pub fn task(&self) -> TaskId {
+        TaskId::from(&self.task)
+    }
+}
+
+impl IsOkOnSuccess for TaskResponse {}
+
+/**
+Details of a task that's running on a node.
+
+The status of the task depends on the kind of request it's running.
+By default it's left as a `serde_json::Value`.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct TaskInfo<TStatus = Value> {
+    node: String,
+    id: u64,
+    action: String,
+    description: Option<String>,
+    start_time_in_millis: u64,
+    running_time_in_nanos: u64,
+    #[serde(default)]
+    cancellable: bool,
+    parent_task_id: Option<String>,
+    status: Option<TStatus>,
+}
+
+impl<TStatus> TaskInfo<TStatus> {
+    /** The id of the task, in the form `node_id:task_number`. */
+    pub fn task(&self) -> TaskId<'static> {
+        TaskId::from(format!("{}:{}", self.node, self.id))
+    }
+
+    /** The id of the node the task is running on. */
+    pub fn node(&self) -> &str {
+        &self.node
+    }
+
+    /** The action the task is running, like `indices:data/write/reindex`. */
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    /** A description of what the task is doing. */
+    pub fn description(&self) -> Option<&str> {
+        self.description
+            .as_ref()
+            .map(|description| description.as_ref())
+    }
+
+    /** The time the task started, in milliseconds since the epoch. */
+    pub fn start_time_in_millis(&self) -> u64 {
+        self.start_time_in_millis
+    }
+
+    /** How long the task has been running for. */
+    pub fn running_time(&self) -> Duration {
+        Duration::from_nanos(self.running_time_in_nanos)
+    }
+
+    /** Whether or not the task can be cancelled. */
+    pub fn cancellable(&self) -> bool {
+        self.cancellable
+    }
+
+    /** The id of the task that started this one, if it's a sub-task. */
+    pub fn parent_task(&self) -> Option<TaskId> {
+        self.parent_task_id.as_ref().map(TaskId::from)
+    }
+
+    /** The progress of the task. */
+    pub fn status(&self) -> Option<&TStatus> {
+        self.status.as_ref()
+    }
+}
+
+/** Response for a [list tasks request](https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html). */
+#[derive(Deserialize, Debug, Clone)]
+pub struct TasksListResponse<TStatus = Value> {
+    #[serde(default = "BTreeMap::new")]
+    nodes: BTreeMap<String, TaskNode<TStatus>>,
+    #[serde(default)]
+    node_failures: Vec<Value>,
+    #[serde(default)]
+    task_failures: Vec<TaskFailure>,
+}
+
+impl<TStatus> TasksListResponse<TStatus> {
+    /** Iterate over the tasks running on all nodes. */
+    pub fn tasks(&self) -> impl Iterator<Item = &TaskInfo<TStatus>> {
+        self.nodes.values().flat_map(|node| node.tasks.values())
+    }
+
+    /** Errors from nodes that couldn't list their tasks. */
+    pub fn node_failures(&self) -> &[Value] {
+        &self.node_failures
+    }
+
+    /** Errors from individual tasks. */
+    pub fn task_failures(&self) -> &[TaskFailure] {
+        &self.task_failures
+    }
+}
+
+impl<TStatus> IsOkOnSuccess for TasksListResponse<TStatus> {}
+
+/** Response for a [cancel task request](https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html#task-cancellation). */
+#[derive(Deserialize, Debug, Clone)]
+pub struct TasksCancelResponse {
+    #[serde(default)]
+    nodes: BTreeMap<String, TaskNode<Value>>,
+    #[serde(default)]
+    node_failures: Vec<Value>,
+    #[serde(default)]
+    task_failures: Vec<TaskFailure>,
+}
+
+impl TasksCancelResponse {
+    /**
+    Iterate over the tasks that were cancelled.
+
+    Tasks may keep running for a short time after they've been cancelled.
+    */
+    pub fn tasks(&self) -> impl Iterator<Item = &TaskInfo> {
+        self.nodes.values().flat_map(|node| node.tasks.values())
+    }
+
+    /** Errors from nodes that couldn't cancel their tasks. */
+    pub fn node_failures(&self) -> &[Value] {
+        &self.node_failures
+    }
+
+    /**
+    Errors from individual tasks.
+
+    A task that can't be cancelled will be returned here.
+    */
+    pub fn task_failures(&self) -> &[TaskFailure] {
+        &self.task_failures
+    }
+}
+
+impl IsOkOnSuccess for TasksCancelResponse {}
+
+#[derive(Deserialize, Debug, Clone)]
+struct TaskNode<TStatus> {
+    #[serde(default = "BTreeMap::new")]
+    tasks: BTreeMap<String, TaskInfo<TStatus>>,
+}
+
+/** A task that failed to be listed or cancelled. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct TaskFailure {
+    node_id: String,
+    task_id: u64,
+    status: String,
+    reason: Value,
+}
+
+impl TaskFailure {
+    /** The id of the task, in the form `node_id:task_number`. */
+    pub fn task(&self) -> TaskId<'static> {
+        TaskId::from(format!("{}:{}", self.node_id, self.task_id))
+    }
+
+    /** The status of the failure, like `INTERNAL_SERVER_ERROR`. */
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    /** The kind of error, like `illegal_argument_exception`. */
+    pub fn kind(&self) -> Option<&str> {
+        self.reason.get("type").and_then(|kind| kind.as_str())
+    }
+
+    /** The reason for the failure. */
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.get("reason").and_then(|reason| reason.as_str())
+    }
+}
+
+/**
+Response for a [get task request](https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html).
+
+Once the task has completed, the response will contain either the result of the request it ran or an error.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct TasksGetResponse<TStatus = Value, TResponse = Value> {
+    completed: bool,
+    task: TaskInfo<TStatus>,
+    response: Option<TResponse>,
+    error: Option<Map<String, Value>>,
+}
+
+impl<TStatus, TResponse> TasksGetResponse<TStatus, TResponse> {
+    /** Whether or not the task has finished running. */
+    pub fn completed(&self) -> bool {
+        self.completed
+    }
+
+    /** Details of the task, including its progress. */
+    pub fn task(&self) -> &TaskInfo<TStatus> {
+        &self.task
+    }
+
+    /** The result of the request the task ran, if it completed successfully. */
+    pub fn response(&self) -> Option<&TResponse> {
+        self.response.as_ref()
+    }
+
+    /** The raw error the task failed with, if it completed unsuccessfully. */
+    pub fn error(&self) -> Option<&Map<String, Value>> {
+        self.error.as_ref()
+    }
+}
+
+impl<TStatus, TResponse> TasksGetResponse<TStatus, TResponse>
+where
+    TResponse: DeserializeOwned,
+{
+    /**
+    Get the outcome of the task if it's completed.
+
+    A task that completed with an error is converted into an `Error`.
+    */
+    pub(crate) fn into_completed(self) -> Result<Option<TResponse>, Error> {
+        if !self.completed {
+            return Ok(None);
+        }
+
+        if let Some(err) = self.error {
+            let mut body = Map::new();
+            body.insert("error".to_owned(), Value::Object(err));
+
+            let err = match ParsedApiError::from(body) {
+                ParsedApiError::Known(err) => ResponseError::Api(err),
+                ParsedApiError::Unknown(err) => {
+                    ResponseError::Parse(ParseError::new(UnknownApiError(err)))
+                }
+            };
+
+            return Err(error::response(StatusCode::OK, err));
+        }
+
+        match self.response {
+            Some(response) => Ok(Some(response)),
+            None => serde_json::from_value(Value::Null)
+                .map(Some)
+                .map_err(|err| error::response(StatusCode::OK, err)),
+        }
+    }
+}
+
+impl<TStatus, TResponse> IsOkOnSuccess for TasksGetResponse<TStatus, TResponse> {}
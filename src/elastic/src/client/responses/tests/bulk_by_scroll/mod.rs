@@ -0,0 +1,66 @@
+use crate::{
+    client::responses::*,
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_delete_by_query() {
+    let f = include_bytes!("delete_by_query.json");
+    let deserialized = parse::<BulkByScrollResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!(147, deserialized.took());
+    assert!(!deserialized.timed_out());
+    assert_eq!(119, deserialized.total());
+    assert_eq!(119, deserialized.deleted());
+    assert_eq!(0, deserialized.updated());
+    assert_eq!(0, deserialized.version_conflicts());
+    assert_eq!(None, deserialized.requests_per_second());
+    assert!(deserialized.failures().is_empty());
+}
+
+#[test]
+fn success_parse_update_by_query_with_conflicts() {
+    let f = include_bytes!("update_by_query_conflicts.json");
+    let deserialized = parse::<BulkByScrollResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!(1, deserialized.updated());
+    assert_eq!(1, deserialized.version_conflicts());
+    assert_eq!(Some(500.0), deserialized.requests_per_second());
+
+    let failure = &deserialized.failures()[0];
+
+    assert_eq!(Some("twitter"), failure.index());
+    assert_eq!(Some("1"), failure.id());
+    assert_eq!(Some(409), failure.status());
+    assert_eq!(Some("version_conflict_engine_exception"), failure.kind());
+}
+
+#[test]
+fn success_parse_reindex_rethrottle() {
+    let f = include_bytes!("reindex_rethrottle.json");
+    let deserialized = parse::<ReindexRethrottleResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    let tasks: Vec<_> = deserialized.tasks().collect();
+    assert_eq!(1, tasks.len());
+
+    let task = tasks[0];
+    assert_eq!("oTUltX4IQMOUUVeiohTt8A:12345", task.task());
+    assert_eq!("indices:data/write/reindex", task.action());
+    assert!(task.cancellable());
+
+    let status = task.status().unwrap();
+    assert_eq!(6154, status.total());
+    assert_eq!(3500, status.created());
+    assert_eq!(3500, status.processed());
+    assert_eq!(36, status.batches());
+    assert_eq!(None, status.requests_per_second());
+}
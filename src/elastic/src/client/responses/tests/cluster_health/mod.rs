@@ -0,0 +1,41 @@
+use crate::{
+    client::responses::*,
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_health_response() {
+    let f = include_bytes!("health.json");
+    let deserialized = parse::<ClusterHealthResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!("docker-cluster", deserialized.cluster_name());
+    assert_eq!(HealthStatus::Yellow, deserialized.status());
+    assert!(deserialized.status() >= HealthStatus::Yellow);
+    assert!(deserialized.status() < HealthStatus::Green);
+    assert!(!deserialized.timed_out());
+    assert_eq!(1, deserialized.number_of_nodes());
+    assert_eq!(5, deserialized.unassigned_shards());
+    assert_eq!(50.0, deserialized.active_shards_percent());
+
+    let index = deserialized.index("myindex").unwrap();
+    assert_eq!(HealthStatus::Yellow, index.status());
+    assert_eq!(5, index.number_of_shards());
+    assert_eq!(1, index.number_of_replicas());
+}
+
+#[test]
+fn success_parse_timed_out_health_response() {
+    let f = include_bytes!("health_timed_out.json");
+    let deserialized = parse::<ClusterHealthResponse>()
+        .from_slice(StatusCode::REQUEST_TIMEOUT, f as &[_])
+        .unwrap();
+
+    assert!(deserialized.timed_out());
+    assert_eq!(HealthStatus::Red, deserialized.status());
+    assert_eq!(0, deserialized.indices().count());
+}
@@ -0,0 +1,101 @@
+use crate::{
+    client::{
+        requests::ClusterSetting,
+        responses::*,
+    },
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_get_settings_response() {
+    let f = include_bytes!("get.json");
+    let deserialized = parse::<ClusterGetSettingsResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!(
+        Some("primaries"),
+        deserialized
+            .persistent()
+            .get_str(ClusterSetting::AllocationEnable)
+    );
+    assert_eq!(
+        Some("node-1,node-2"),
+        deserialized
+            .transient()
+            .get_str("cluster.routing.allocation.exclude._name")
+    );
+    assert_eq!(
+        Some("1000"),
+        deserialized
+            .defaults()
+            .get_str(ClusterSetting::MaxShardsPerNode)
+    );
+
+    // Transient settings take precedence
+    assert_eq!(
+        Some("all"),
+        deserialized
+            .get(ClusterSetting::AllocationEnable)
+            .and_then(|value| value.as_str())
+    );
+    assert_eq!(
+        Some("1000"),
+        deserialized
+            .get(ClusterSetting::MaxShardsPerNode)
+            .and_then(|value| value.as_str())
+    );
+    assert!(deserialized.get("cluster.routing").is_some());
+    assert!(deserialized.get("cluster.routing.missing").is_none());
+
+    let transient: Vec<_> = deserialized
+        .transient()
+        .iter()
+        .map(|(key, _)| key)
+        .collect();
+
+    assert_eq!(
+        vec![
+            "cluster.routing.allocation.enable",
+            "cluster.routing.allocation.exclude._name",
+        ],
+        transient
+    );
+}
+
+#[test]
+fn success_parse_flat_get_settings_response() {
+    let f = include_bytes!("get_flat.json");
+    let deserialized = parse::<ClusterGetSettingsResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!(
+        Some("primaries"),
+        deserialized
+            .persistent()
+            .get_str(ClusterSetting::AllocationEnable)
+    );
+    assert!(deserialized.transient().is_empty());
+    assert!(deserialized.defaults().is_empty());
+}
+
+#[test]
+fn success_parse_put_settings_response() {
+    let f = include_bytes!("put.json");
+    let deserialized = parse::<ClusterPutSettingsResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert!(deserialized.acknowledged());
+    assert!(deserialized.persistent().is_empty());
+    assert_eq!(
+        Some("node-1"),
+        deserialized
+            .transient()
+            .get_str(ClusterSetting::AllocationExcludeName)
+    );
+}
@@ -0,0 +1,48 @@
+use crate::{
+    client::responses::*,
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_state_response() {
+    let f = include_bytes!("state.json");
+    let deserialized = parse::<ClusterStateResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!("docker-cluster", deserialized.cluster_name());
+    assert_eq!(Some(42), deserialized.version());
+    assert_eq!(Some("Cl0fzr6dQXy0UDmaYwQgAA"), deserialized.master_node());
+    assert!(deserialized.get_metric("blocks").is_some());
+
+    let node = deserialized.node("Cl0fzr6dQXy0UDmaYwQgAA").unwrap();
+    assert_eq!("es-node-1", node.name());
+    assert_eq!("172.17.0.2:9300", node.transport_address());
+    assert_eq!(Some("a"), node.attribute("zone"));
+
+    let metadata = deserialized.metadata().unwrap();
+    assert!(metadata.get("templates").is_some());
+
+    let index = metadata.index("myindex").unwrap();
+    assert_eq!("open", index.state());
+    assert_eq!(
+        Some("1"),
+        index.settings().get_str("index.number_of_shards")
+    );
+    assert_eq!(vec!["myalias"], index.aliases().collect::<Vec<_>>());
+}
+
+#[test]
+fn success_parse_state_response_without_metrics() {
+    let f = br#"{ "cluster_name": "docker-cluster", "cluster_uuid": "Y2-pJ-n5TeaYKjTk3yqnvQ" }"#;
+    let deserialized = parse::<ClusterStateResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!(None, deserialized.version());
+    assert_eq!(0, deserialized.nodes().count());
+    assert!(deserialized.metadata().is_none());
+}
@@ -0,0 +1,61 @@
+use crate::{
+    client::responses::*,
+    error::*,
+    http::{
+        receiver::{
+            parse,
+            ResponseError,
+        },
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_matched_explanation() {
+    let f = include_bytes!("explain_matched.json");
+    let deserialized = parse::<ExplainResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!("twitter", deserialized.index());
+    assert_eq!("0", deserialized.id());
+    assert!(deserialized.matched());
+
+    let explanation = deserialized.explanation().unwrap();
+    assert_eq!(1.6943598, explanation.value());
+    assert_eq!(1, explanation.details().len());
+
+    let score = &explanation.details()[0];
+    assert_eq!(3, score.details().len());
+
+    let idf = &score.details()[1];
+    assert!(idf.description().starts_with("idf"));
+    assert_eq!(2, idf.details().len());
+    assert!(idf.details()[0].details().is_empty());
+}
+
+#[test]
+fn success_parse_not_found_explanation() {
+    let f = include_bytes!("explain_not_found.json");
+    let deserialized = parse::<ExplainResponse>()
+        .from_slice(StatusCode::NOT_FOUND, f as &[_])
+        .unwrap();
+
+    assert!(!deserialized.matched());
+    assert!(deserialized.explanation().is_none());
+}
+
+#[test]
+fn error_parse_index_not_found() {
+    let f = include_bytes!("../error/error_index_not_found.json");
+    let deserialized = parse::<ExplainResponse>()
+        .from_slice(StatusCode::NOT_FOUND, f as &[_])
+        .unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::IndexNotFound { ref index }) if index == "carrots" => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
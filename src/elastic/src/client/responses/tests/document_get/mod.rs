@@ -47,6 +47,21 @@ fn success_into_document() {
     }
 }
 
+#[test]
+fn success_parse_stored_fields() {
+    let f = include_bytes!("get_stored_fields.json");
+    let deserialized = parse::<GetResponse<Value>>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert!(deserialized.found());
+    assert!(deserialized.document().is_none());
+    assert_eq!(
+        Some(&json!("A title")),
+        deserialized.fields().first("title")
+    );
+}
+
 #[test]
 fn success_parse_not_found_doc_response() {
     let f = include_bytes!("get_not_found.json");
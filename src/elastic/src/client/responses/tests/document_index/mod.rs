@@ -40,3 +40,39 @@ fn error_parse_index_already_exists() {
 
     assert!(valid);
 }
+
+#[test]
+fn error_parse_version_conflict() {
+    let f = include_bytes!("../error/error_version_conflict.json");
+    let deserialized = parse::<IndexResponse>()
+        .from_slice(StatusCode::CONFLICT, f as &[_])
+        .unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::VersionConflict { ref index, .. }) if index == "carrots" => {
+            true
+        }
+        _ => false,
+    };
+
+    assert!(valid);
+}
+
+#[test]
+fn error_parse_document_already_exists() {
+    let f = include_bytes!("../error/error_document_already_exists.json");
+    let deserialized = parse::<IndexResponse>()
+        .from_slice(StatusCode::CONFLICT, f as &[_])
+        .unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::DocumentAlreadyExists { ref index, ref id })
+            if index == "carrots" && id == "1" =>
+        {
+            true
+        }
+        _ => false,
+    };
+
+    assert!(valid);
+}
@@ -0,0 +1,88 @@
+use crate::{
+    client::responses::*,
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_term_vectors() {
+    let f = include_bytes!("term_vectors.json");
+    let deserialized = parse::<TermVectorsResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!("twitter", deserialized.index());
+    assert_eq!(Some("1"), deserialized.id());
+    assert_eq!(Some(1), deserialized.version());
+    assert!(deserialized.found());
+    assert_eq!(6, deserialized.took());
+
+    let fields: Vec<_> = deserialized.fields().map(|(field, _)| field).collect();
+    assert_eq!(vec!["text"], fields);
+
+    let text = deserialized.field("text").unwrap();
+
+    let field_statistics = text.field_statistics().unwrap();
+    assert_eq!(4, field_statistics.sum_doc_freq());
+    assert_eq!(2, field_statistics.doc_count());
+    assert_eq!(6, field_statistics.sum_ttf());
+
+    let terms: Vec<_> = text.terms().map(|(term, _)| term).collect();
+    assert_eq!(vec!["test", "twitter"], terms);
+
+    let test = text.term("test").unwrap();
+    assert_eq!(3, test.term_freq());
+    assert_eq!(Some(2), test.doc_freq());
+    assert_eq!(Some(4), test.ttf());
+    assert_eq!(3, test.tokens().len());
+
+    let token = &test.tokens()[1];
+    assert_eq!(Some(2), token.position());
+    assert_eq!(Some(13), token.start_offset());
+    assert_eq!(Some(17), token.end_offset());
+    assert_eq!(Some("d29yZA=="), token.payload());
+}
+
+#[test]
+fn success_parse_artificial_term_vectors() {
+    let f = include_bytes!("term_vectors_artificial.json");
+    let deserialized = parse::<TermVectorsResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!(None, deserialized.id());
+    assert!(deserialized.found());
+
+    let term = deserialized
+        .field("fullname")
+        .and_then(|fullname| fullname.term("John Doe"))
+        .unwrap();
+
+    assert_eq!(1, term.term_freq());
+    assert_eq!(None, term.doc_freq());
+    assert!(term.tokens().is_empty());
+}
+
+#[test]
+fn success_parse_mterm_vectors() {
+    let f = include_bytes!("mterm_vectors.json");
+    let deserialized = parse::<MtermVectorsResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    let docs: Vec<_> = deserialized.docs().collect();
+    assert_eq!(2, docs.len());
+
+    assert_eq!(Some("1"), docs[0].id());
+    assert!(docs[0].found());
+
+    let text = docs[0].field("text").unwrap();
+    assert!(text.field_statistics().is_none());
+    assert_eq!(Some(1), text.term("twitter").map(|term| term.term_freq()));
+
+    assert_eq!(Some("2"), docs[1].id());
+    assert!(!docs[1].found());
+    assert_eq!(0, docs[1].fields().count());
+}
@@ -26,9 +26,28 @@ fn success_parse_updated_doc_response() {
     assert_eq!("1", deserialized.id());
     assert_eq!(Some(5), deserialized.version());
 
+    assert_eq!(UpdateResult::Updated, deserialized.result());
     assert!(deserialized.updated());
 }
 
+#[test]
+fn success_parse_upserted_doc_response() {
+    let f = include_bytes!("update_created.json");
+    let deserialized = parse::<UpdateResponse>()
+        .from_slice(StatusCode::CREATED, f as &[_])
+        .unwrap();
+
+    assert_eq!("testindex", deserialized.index());
+    assert_eq!("1", deserialized.id());
+    assert_eq!(Some(1), deserialized.version());
+
+    assert_eq!(UpdateResult::Created, deserialized.result());
+    assert!(!deserialized.updated());
+
+    let doc = deserialized.into_document::<serde_json::Value>().unwrap();
+    assert_eq!(1, doc["count"]);
+}
+
 #[test]
 fn success_parse_noop_doc_response() {
     let f = include_bytes!("update_noop.json");
@@ -41,6 +60,7 @@ fn success_parse_noop_doc_response() {
     assert_eq!("1", deserialized.id());
     assert_eq!(Some(4), deserialized.version());
 
+    assert_eq!(UpdateResult::NoOp, deserialized.result());
     assert!(!deserialized.updated());
 }
 
@@ -0,0 +1,58 @@
+use crate::{
+    client::responses::*,
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_get_alias_response() {
+    let f = include_bytes!("get_alias.json");
+    let deserialized = parse::<IndicesGetAliasResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!(3, deserialized.iter().count());
+
+    let mut indices: Vec<_> = deserialized.indices_for_alias("logs").collect();
+    indices.sort();
+
+    assert_eq!(vec!["logs-2019.01", "logs-2019.02"], indices);
+
+    let old = deserialized
+        .index("logs-2019.01")
+        .unwrap()
+        .get("logs")
+        .unwrap();
+
+    assert!(old.filter().is_some());
+    assert_eq!(Some("1"), old.index_routing());
+    assert_eq!(Some("1,2"), old.search_routing());
+    assert_eq!(None, old.is_write_index());
+
+    let current = deserialized.index("logs-2019.02").unwrap();
+
+    assert_eq!(2, current.len());
+    assert_eq!(Some(true), current.get("logs").unwrap().is_write_index());
+
+    assert!(deserialized.index("metrics").unwrap().is_empty());
+}
+
+#[test]
+fn success_parse_response_alias_exists() {
+    let deserialized = parse::<IndicesExistsAliasResponse>()
+        .from_slice(StatusCode::OK, b"")
+        .unwrap();
+
+    assert!(deserialized.exists());
+}
+
+#[test]
+fn success_parse_response_alias_not_exists() {
+    let deserialized = parse::<IndicesExistsAliasResponse>()
+        .from_slice(StatusCode::NOT_FOUND, b"")
+        .unwrap();
+
+    assert!(!deserialized.exists());
+}
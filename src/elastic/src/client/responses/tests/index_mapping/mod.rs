@@ -0,0 +1,269 @@
+use serde_json;
+
+use crate::{
+    client::responses::*,
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+    types::prelude::*,
+};
+
+#[test]
+fn success_parse_get_mapping_response() {
+    let f = include_bytes!("get_mapping.json");
+    let deserialized = parse::<IndicesGetMappingResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    let mapping = deserialized.index("myindex").unwrap();
+
+    assert_eq!(Some(&json!("strict")), mapping.get_meta("dynamic"));
+    assert_eq!(4, mapping.properties().count());
+
+    assert_eq!("keyword", mapping.field("id").unwrap().ty());
+    assert_eq!("keyword", mapping.field("title.keyword").unwrap().ty());
+    assert_eq!("object", mapping.field("address").unwrap().ty());
+    assert_eq!("text", mapping.field("address.street").unwrap().ty());
+    assert!(mapping.field("title.missing").is_none());
+
+    let timestamp = mapping.field("timestamp").unwrap();
+    assert_eq!(Some(&json!("epoch_millis")), timestamp.param("format"));
+
+    let mut fields = mapping.fields().map(|(name, _)| name).collect::<Vec<_>>();
+    fields.sort();
+
+    assert_eq!(
+        vec![
+            "address",
+            "address.street",
+            "id",
+            "timestamp",
+            "title",
+            "title.keyword",
+        ],
+        fields
+    );
+
+    let empty = deserialized.index("emptyindex").unwrap();
+    assert_eq!(0, empty.properties().count());
+}
+
+#[test]
+fn success_parse_get_field_mapping_response() {
+    let f = include_bytes!("get_field_mapping.json");
+    let deserialized = parse::<IndicesGetFieldMappingResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    let keyword = deserialized.field("myindex", "title.keyword").unwrap();
+    assert_eq!("keyword", keyword.ty());
+    assert_eq!(Some(&json!(256)), keyword.param("ignore_above"));
+
+    assert!(deserialized.field("otherindex", "title.keyword").is_none());
+    assert_eq!(2, deserialized.iter().count());
+}
+
+#[derive(ElasticType)]
+#[elastic(crate_root = "crate::types")]
+#[allow(dead_code)]
+struct MappedDoc {
+    id: Keyword<DefaultKeywordMapping>,
+    title: String,
+    timestamp: Date<DefaultDateMapping<EpochMillis>>,
+}
+
+#[test]
+fn document_mapping_eq_live_mapping() {
+    let live: IndexMapping = serde_json::from_value(json!({
+        "properties": {
+            "id": {
+                "type": "keyword"
+            },
+            "title": {
+                "type": "text",
+                "fields": {
+                    "keyword": {
+                        "type": "keyword",
+                        "ignore_above": 256
+                    }
+                }
+            },
+            "timestamp": {
+                "type": "date",
+                "format": "epoch_millis"
+            }
+        }
+    }))
+    .unwrap();
+
+    let expected = IndexMapping::from_document::<MappedDoc>().unwrap();
+
+    assert_eq!(expected, live);
+}
+
+#[test]
+fn object_fields_without_type_eq_object_fields_with_type() {
+    let live: MappedField = serde_json::from_value(json!({
+        "properties": {
+            "street": { "type": "text" }
+        }
+    }))
+    .unwrap();
+
+    let expected: MappedField = serde_json::from_value(json!({
+        "type": "object",
+        "properties": {
+            "street": { "type": "text" }
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(expected, live);
+}
+
+#[test]
+fn diff_compatible_mapping() {
+    let live: IndexMapping = serde_json::from_value(json!({
+        "properties": {
+            "id": { "type": "keyword" },
+            "old": { "type": "keyword" }
+        }
+    }))
+    .unwrap();
+
+    let expected: IndexMapping = serde_json::from_value(json!({
+        "properties": {
+            "id": { "type": "keyword" },
+            "address": {
+                "type": "object",
+                "properties": {
+                    "street": { "type": "text" }
+                }
+            }
+        }
+    }))
+    .unwrap();
+
+    let diff = live.diff(&expected);
+
+    assert!(diff.is_compatible());
+    assert!(!diff.is_empty());
+
+    let added = diff.added().map(|change| change.name()).collect::<Vec<_>>();
+    let removed = diff
+        .removed()
+        .map(|change| change.name())
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec!["address"], added);
+    assert_eq!(vec!["old"], removed);
+}
+
+#[test]
+fn diff_conflicting_mapping() {
+    let live: IndexMapping = serde_json::from_value(json!({
+        "properties": {
+            "timestamp": { "type": "date", "format": "epoch_millis" },
+            "title": {
+                "type": "text",
+                "fields": {
+                    "keyword": { "type": "keyword", "ignore_above": 256 }
+                }
+            }
+        }
+    }))
+    .unwrap();
+
+    let expected: IndexMapping = serde_json::from_value(json!({
+        "properties": {
+            "timestamp": { "type": "date", "format": "basic_date_time" },
+            "title": { "type": "keyword" }
+        }
+    }))
+    .unwrap();
+
+    let diff = live.diff(&expected);
+
+    assert!(diff.requires_reindex());
+    assert_eq!(2, diff.iter().count());
+
+    let conflicts = diff
+        .conflicts()
+        .map(|change| change.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            "conflicting field `timestamp`: mapping parameters differ",
+            "conflicting field `title`: expected type `keyword` but found `text`",
+        ],
+        conflicts
+    );
+}
+
+#[test]
+fn diff_updatable_mapping() {
+    let live: IndexMapping = serde_json::from_value(json!({
+        "properties": {
+            "title": {
+                "type": "text",
+                "analyzer": "standard",
+                "fields": {
+                    "keyword": { "type": "keyword", "ignore_above": 256 }
+                }
+            }
+        }
+    }))
+    .unwrap();
+
+    let expected: IndexMapping = serde_json::from_value(json!({
+        "properties": {
+            "title": {
+                "type": "text",
+                "analyzer": "standard",
+                "search_analyzer": "simple",
+                "fields": {
+                    "keyword": { "type": "keyword", "ignore_above": 512 },
+                    "english": { "type": "text", "analyzer": "english" }
+                }
+            }
+        }
+    }))
+    .unwrap();
+
+    let diff = live.diff(&expected);
+
+    assert!(diff.is_compatible());
+
+    let changes = diff
+        .iter()
+        .map(|change| change.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            "updated field `title`: mapping parameters differ",
+            "added field `title.english` of type `text`",
+            "updated field `title.keyword`: mapping parameters differ",
+        ],
+        changes
+    );
+
+    let updated = diff
+        .updated()
+        .map(|change| change.name())
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec!["title", "title.keyword"], updated);
+}
+
+#[test]
+fn diff_equal_mapping() {
+    let expected = IndexMapping::from_document::<MappedDoc>().unwrap();
+
+    let diff = expected.diff(&expected);
+
+    assert!(diff.is_empty());
+    assert!(diff.is_compatible());
+}
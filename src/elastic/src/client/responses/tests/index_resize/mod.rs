@@ -0,0 +1,19 @@
+use crate::{
+    client::responses::*,
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_resize_response() {
+    let f = include_bytes!("resize.json");
+    let deserialized = parse::<IndicesResizeResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert!(deserialized.acknowledged());
+    assert!(deserialized.shards_acknowledged());
+    assert_eq!("logs-shrunk", deserialized.index());
+}
@@ -0,0 +1,28 @@
+use crate::{
+    client::responses::*,
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_rollover_response() {
+    let f = include_bytes!("rollover.json");
+    let deserialized = parse::<IndicesRolloverResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert!(deserialized.acknowledged());
+    assert!(deserialized.shards_acknowledged());
+    assert!(deserialized.rolled_over());
+    assert!(!deserialized.dry_run());
+    assert_eq!("logs-000001", deserialized.old_index());
+    assert_eq!("logs-000002", deserialized.new_index());
+
+    assert_eq!(2, deserialized.conditions().count());
+    assert_eq!(
+        vec!["[max_docs: 1000]"],
+        deserialized.matched_conditions().collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,35 @@
+use crate::{
+    client::responses::*,
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_segments_response() {
+    let f = include_bytes!("segments.json");
+    let deserialized = parse::<IndicesSegmentsResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    let index = deserialized.index("myindex").unwrap();
+    let shards = index.shards().collect::<Vec<_>>();
+    assert_eq!(1, shards.len());
+
+    let (shard, copy) = shards[0];
+    assert_eq!(0, shard);
+    assert_eq!(Some("tkpq7TWARpGlDo2Yh-zZjw"), copy.routing().node());
+    assert_eq!(1, copy.num_committed_segments());
+    assert_eq!(2, copy.num_search_segments());
+
+    let segments = copy.segments().collect::<Vec<_>>();
+    assert_eq!(2, segments.len());
+
+    let (name, segment) = segments[0];
+    assert_eq!("_0", name);
+    assert_eq!(9, segment.num_docs());
+    assert_eq!(1, segment.deleted_docs());
+    assert!(segment.committed());
+    assert_eq!("8.0.0", segment.version());
+}
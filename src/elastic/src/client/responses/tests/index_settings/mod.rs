@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use crate::{
+    client::{
+        requests::index_settings::{
+            Codec,
+            RefreshInterval,
+        },
+        responses::*,
+    },
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_get_settings_response() {
+    let f = include_bytes!("get_settings.json");
+    let deserialized = parse::<IndicesGetSettingsResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!(1, deserialized.iter().count());
+
+    let settings = deserialized.index("twitter").unwrap();
+
+    assert_eq!(Some(3), settings.get_number_of_shards());
+    assert_eq!(Some(1), settings.get_number_of_replicas());
+    assert_eq!(
+        Some(RefreshInterval::Every(Duration::from_secs(30))),
+        settings.get_refresh_interval()
+    );
+    assert_eq!(None, settings.get_max_result_window());
+    assert_eq!(Some(Codec::BestCompression), settings.get_codec());
+    assert_eq!(
+        Some("Z9-4WOcTSB-4o1ihJk8P0A"),
+        settings.get_setting("uuid").and_then(|uuid| uuid.as_str())
+    );
+
+    let analysis = settings.get_analysis().unwrap();
+
+    let analyzer = analysis.get_analyzer("my_analyzer").unwrap();
+    assert_eq!(Some("standard"), analyzer.tokenizer());
+    assert_eq!(vec!["lowercase"], analyzer.filters().collect::<Vec<_>>());
+
+    let normalizer = analysis.get_normalizer("my_normalizer").unwrap();
+    assert_eq!(
+        vec!["lowercase", "asciifolding"],
+        normalizer.filters().collect::<Vec<_>>()
+    );
+
+    let tokenizer = analysis.get_tokenizer("my_tokenizer").unwrap();
+    assert_eq!("ngram", tokenizer.ty());
+    assert_eq!(
+        Some("3"),
+        tokenizer.get_option("min_gram").and_then(|v| v.as_str())
+    );
+}
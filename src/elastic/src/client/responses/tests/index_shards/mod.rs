@@ -0,0 +1,30 @@
+use crate::{
+    client::responses::*,
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_shards_response() {
+    let f = include_bytes!("refresh.json");
+    let deserialized = parse::<IndicesShardsResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert!(deserialized.all_successful());
+    assert_eq!(10, deserialized.shards().total());
+    assert_eq!(5, deserialized.shards().successful());
+}
+
+#[test]
+fn success_parse_shards_response_with_failures() {
+    let f = include_bytes!("refresh_failed.json");
+    let deserialized = parse::<IndicesShardsResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert!(!deserialized.all_successful());
+    assert_eq!(1, deserialized.shards().failed());
+}
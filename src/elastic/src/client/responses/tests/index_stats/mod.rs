@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use crate::{
+    client::responses::*,
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_stats_response() {
+    let f = include_bytes!("stats.json");
+    let deserialized = parse::<IndicesStatsResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!(2, deserialized.shards().total());
+
+    let all = deserialized.all().total();
+    assert_eq!(10, all.docs().unwrap().count());
+    assert_eq!(4096, all.store().unwrap().size_in_bytes());
+    assert!(all.indexing().is_none());
+
+    let index = deserialized.index("myindex").unwrap();
+    assert_eq!(Some("Bh4oeGaGQWCHVkTYvOnrNw"), index.uuid());
+
+    let primaries = index.primaries();
+    let indexing = primaries.indexing().unwrap();
+    assert_eq!(11, indexing.index_total());
+    assert_eq!(Duration::from_millis(250), indexing.index_time());
+
+    let search = primaries.search().unwrap();
+    assert_eq!(42, search.query_total());
+    assert_eq!(Duration::from_millis(120), search.query_time());
+
+    assert_eq!(3, primaries.segments().unwrap().count());
+    assert!(primaries.get_metric("query_cache").is_some());
+
+    let shards = index.shards().collect::<Vec<_>>();
+    assert_eq!(1, shards.len());
+
+    let (shard, stats) = shards[0];
+    assert_eq!(0, shard);
+    assert!(stats.routing().primary());
+    assert_eq!("STARTED", stats.routing().state());
+    assert_eq!(10, stats.metrics().docs().unwrap().count());
+}
@@ -0,0 +1,52 @@
+use crate::{
+    client::responses::*,
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_get_template_response() {
+    let f = include_bytes!("get_template.json");
+    let deserialized = parse::<IndicesGetTemplateResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!(2, deserialized.len());
+
+    let logs = deserialized.template("logs").unwrap();
+
+    assert_eq!(
+        vec!["logs-*", "events-*"],
+        logs.index_patterns().collect::<Vec<_>>()
+    );
+    assert_eq!(1, logs.order());
+    assert_eq!(Some(3), logs.version());
+    assert_eq!("1", logs.settings()["index"]["number_of_shards"]);
+    assert_eq!("date", logs.mappings()["properties"]["timestamp"]["type"]);
+    assert!(logs.aliases().get("all-logs").is_some());
+
+    let metrics = deserialized.template("metrics").unwrap();
+
+    assert_eq!(0, metrics.order());
+    assert_eq!(None, metrics.version());
+}
+
+#[test]
+fn success_parse_response_template_exists() {
+    let deserialized = parse::<IndicesExistsTemplateResponse>()
+        .from_slice(StatusCode::OK, b"")
+        .unwrap();
+
+    assert!(deserialized.exists());
+}
+
+#[test]
+fn success_parse_response_template_not_exists() {
+    let deserialized = parse::<IndicesExistsTemplateResponse>()
+        .from_slice(StatusCode::NOT_FOUND, b"")
+        .unwrap();
+
+    assert!(!deserialized.exists());
+}
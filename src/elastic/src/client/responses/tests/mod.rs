@@ -1,10 +1,26 @@
 mod bulk;
+mod bulk_by_scroll;
+mod cluster_health;
+mod cluster_settings;
+mod cluster_state;
 mod command;
 mod document_delete;
+mod document_explain;
 mod document_get;
 mod document_index;
+mod document_term_vectors;
 mod document_update;
+mod index_alias;
 mod index_exists;
+mod index_mapping;
+mod index_resize;
+mod index_rollover;
+mod index_segments;
+mod index_settings;
+mod index_shards;
+mod index_stats;
+mod index_template;
 mod nodes_info;
 mod ping;
 mod search;
+mod tasks;
@@ -76,6 +76,35 @@ fn success_parse_hits_no_score() {
     assert_eq!(deserialized.hits().count(), 1);
 }
 
+#[test]
+fn success_parse_hits_with_fields() {
+    #[derive(Deserialize)]
+    struct Summary {
+        title: String,
+    }
+
+    let f = include_bytes!("search_fields.json");
+    let deserialized = parse::<SearchResponse<Summary>>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    let hit = deserialized.hits().next().unwrap();
+
+    assert_eq!(
+        Some("A title"),
+        hit.document().map(|doc| doc.title.as_ref())
+    );
+    assert_eq!(
+        Some(&json!("2019-07-01T00:00:00.000Z")),
+        hit.fields().first("timestamp")
+    );
+    assert_eq!(
+        Some(&[json!("a"), json!("b")][..]),
+        hit.fields().get("tags")
+    );
+    assert!(hit.fields().get("title").is_none());
+}
+
 #[test]
 fn success_parse_hits_bank_sample() {
     let f = include_bytes!("search_bank_sample.json");
@@ -0,0 +1,108 @@
+use crate::{
+    client::responses::*,
+    error::{
+        ApiError,
+        Error,
+    },
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_task_started() {
+    let f = include_bytes!("task_started.json");
+    let deserialized = parse::<TaskResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!("oTUltX4IQMOUUVeiohTt8A:12345", deserialized.task());
+}
+
+#[test]
+fn success_parse_tasks_list() {
+    let f = include_bytes!("list.json");
+    let deserialized = parse::<TasksListResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    let tasks: Vec<_> = deserialized.tasks().collect();
+    assert_eq!(2, tasks.len());
+
+    let child = tasks
+        .iter()
+        .find(|task| task.task() == *"oTUltX4IQMOUUVeiohTt8A:124")
+        .unwrap();
+
+    assert_eq!("cluster:monitor/tasks/lists[n]", child.action());
+    assert_eq!(
+        Some("oTUltX4IQMOUUVeiohTt8A:123"),
+        child.parent_task().as_ref().map(|task| task.as_ref())
+    );
+    assert!(child.status().is_none());
+    assert!(deserialized.node_failures().is_empty());
+}
+
+#[test]
+fn success_parse_tasks_get_running() {
+    let f = include_bytes!("get_running.json");
+    let deserialized = parse::<TasksGetResponse<BulkByScrollStatus, BulkByScrollResponse>>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert!(!deserialized.completed());
+    assert!(deserialized.response().is_none());
+
+    let status = deserialized.task().status().unwrap();
+    assert_eq!(6154, status.total());
+    assert_eq!(3500, status.created());
+
+    assert!(deserialized.into_completed().unwrap().is_none());
+}
+
+#[test]
+fn success_parse_tasks_get_completed() {
+    let f = include_bytes!("get_completed.json");
+    let deserialized = parse::<TasksGetResponse<BulkByScrollStatus, BulkByScrollResponse>>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert!(deserialized.completed());
+    assert!(deserialized.error().is_none());
+
+    let response = deserialized.into_completed().unwrap().unwrap();
+    assert_eq!(6154, response.created());
+    assert_eq!(62, response.batches());
+}
+
+#[test]
+fn success_parse_tasks_get_failed() {
+    let f = include_bytes!("get_failed.json");
+    let deserialized = parse::<TasksGetResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert!(deserialized.completed());
+    assert!(deserialized.response().is_none());
+
+    match deserialized.into_completed() {
+        Err(Error::Api(ApiError::IndexNotFound { ref index })) if index == "missing" => (),
+        res => panic!("expected index not found error, got {:?}", res),
+    }
+}
+
+#[test]
+fn success_parse_tasks_cancel() {
+    let f = include_bytes!("cancel.json");
+    let deserialized = parse::<TasksCancelResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!(0, deserialized.tasks().count());
+
+    let failure = &deserialized.task_failures()[0];
+    assert_eq!("oTUltX4IQMOUUVeiohTt8A:124", failure.task());
+    assert_eq!("INTERNAL_SERVER_ERROR", failure.status());
+    assert_eq!(Some("illegal_argument_exception"), failure.kind());
+}
@@ -152,6 +152,15 @@ quick_error! {
             display("index already exists: '{}'", index)
         }
         /**
+        A document already exists but was expected not to.
+
+        Attempting to create a document with an id that's already in use will result in a `DocumentAlreadyExists` error.
+        */
+        DocumentAlreadyExists { index: String, id: String } {
+            description("document already exists")
+            display("document '{}' already exists in index: '{}'", id, index)
+        }
+        /**
         The request body can't be processed.
 
         Some endpoints that expect certain constraints of a request to hold will return an `ActionRequestValidation` error if those constraints aren't met.
@@ -160,6 +169,15 @@ quick_error! {
             description("action request failed validation")
             display("action request failed validation: '{}'", reason)
         }
+        /**
+        A document was changed by another operation.
+
+        Index, update and delete requests that are conditional on a sequence number, primary term or version will return a `VersionConflict` error if the document has changed since it was read.
+        */
+        VersionConflict { index: String, reason: String } {
+            description("version conflict")
+            display("version conflict in index '{}': '{}'", index, reason)
+        }
         /** The request body can't be parsed.  */
         Parsing { reason: String } {
             description("parsing failed")
@@ -194,6 +212,13 @@ macro_rules! error_key {
     }};
 }
 
+fn conflicting_id(reason: &str) -> Option<String> {
+    let end = reason.find("]:")?;
+    let start = reason[..end].rfind('[')? + 1;
+
+    Some(reason[start..end].to_owned())
+}
+
 impl<'de> Deserialize<'de> for ParsedApiError {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -242,6 +267,23 @@ impl From<Map<String, Value>> for ParsedApiError {
 
                 ParsedApiError::Known(ApiError::DocumentMissing { index })
             }
+            "version_conflict_engine_exception" => {
+                let index = error_key!(obj[index]: |v| v.as_str());
+                let reason = error_key!(obj[reason]: |v| v.as_str());
+
+                // Creating a document that already exists is reported as a version conflict
+                // with a reason like `[1]: version conflict, document already exists`
+                if reason.contains("document already exists") {
+                    if let Some(id) = conflicting_id(&reason) {
+                        return ParsedApiError::Known(ApiError::DocumentAlreadyExists {
+                            index,
+                            id,
+                        });
+                    }
+                }
+
+                ParsedApiError::Known(ApiError::VersionConflict { index, reason })
+            }
             "action_request_validation_exception" => {
                 let reason = error_key!(obj[reason]: |v| v.as_str());
 
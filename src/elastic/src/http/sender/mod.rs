@@ -16,6 +16,7 @@ Some notable types include:
 use fluent_builder::{
     SharedFluentBuilder,
     SharedStatefulFluentBuilder,
+    Stack,
 };
 
 pub mod sniffed_nodes;
@@ -78,7 +79,7 @@ pub(crate) enum SendableRequestParams<TParams> {
     Value(RequestParams),
     Builder {
         params: TParams,
-        builder: SharedFluentBuilder<RequestParams>,
+        builder: SharedFluentBuilder<RequestParams, Stack>,
     },
 }
 
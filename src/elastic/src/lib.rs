@@ -281,9 +281,7 @@ mod genned;
 /// owned string.
 /// They can all be constructed from a `String` or an `&str`, but some
 /// parameters may have other implementations in the future.
-pub mod params {
-    pub use super::genned::params::*;
-}
+pub mod params;
 
 /// REST API endpoints.
 ///
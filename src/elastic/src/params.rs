@@ -0,0 +1,127 @@
+pub use crate::genned::params::*;
+
+use std::{
+    borrow::Cow,
+    fmt,
+    ops::Deref,
+};
+
+use crate::types::string::{
+    keyword::mapping::KeywordMapping,
+    Keyword,
+};
+
+/**
+A value used to route a document to a particular shard.
+
+Documents are routed using their id by default.
+Documents with the same routing value are stored on the same shard.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Routing<'a>(pub Cow<'a, str>);
+
+impl<'a> Routing<'a> {
+    /**
+    Convert this routing value into one that owns its data.
+
+    This will clone the value if it's borrowed.
+    */
+    pub fn into_owned(self) -> Routing<'static> {
+        Routing(Cow::Owned(self.0.into_owned()))
+    }
+}
+
+impl<'a> fmt::Display for Routing<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&*self.0, f)
+    }
+}
+
+impl<'a> PartialEq<str> for Routing<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<'a> PartialEq<Routing<'a>> for &str {
+    fn eq(&self, other: &Routing<'a>) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl<'a> From<&'a str> for Routing<'a> {
+    fn from(value: &'a str) -> Routing<'a> {
+        Routing(Cow::Borrowed(value))
+    }
+}
+
+impl<'a> From<String> for Routing<'a> {
+    fn from(value: String) -> Routing<'a> {
+        Routing(Cow::Owned(value))
+    }
+}
+
+impl<'a> From<&'a String> for Routing<'a> {
+    fn from(value: &'a String) -> Routing<'a> {
+        Routing(Cow::Borrowed(&**value))
+    }
+}
+
+impl<'a> From<Routing<'a>> for String {
+    fn from(value: Routing<'a>) -> String {
+        value.0.into_owned()
+    }
+}
+
+impl<'a> Deref for Routing<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'a, M> From<Keyword<M>> for Routing<'a>
+where
+    M: KeywordMapping,
+{
+    fn from(value: Keyword<M>) -> Routing<'a> {
+        let value: String = value.into();
+
+        Routing::from(value)
+    }
+}
+
+impl<'a, M> From<&'a Keyword<M>> for Routing<'a>
+where
+    M: KeywordMapping,
+{
+    fn from(value: &'a Keyword<M>) -> Routing<'a> {
+        let value: &str = value.into();
+
+        Routing::from(value)
+    }
+}
+
+macro_rules! impl_from_num_for_routing {
+    ($num:ty) => {
+        impl<'a> From<$num> for Routing<'a> {
+            fn from(value: $num) -> Routing<'a> {
+                Routing::from(value.to_string())
+            }
+        }
+
+        impl<'a, 'b> From<&'b $num> for Routing<'a> {
+            fn from(value: &'b $num) -> Routing<'a> {
+                Routing::from(value.to_string())
+            }
+        }
+    };
+}
+
+impl_from_num_for_routing!(u32);
+impl_from_num_for_routing!(u64);
+impl_from_num_for_routing!(usize);
+impl_from_num_for_routing!(i32);
+impl_from_num_for_routing!(i64);
+impl_from_num_for_routing!(isize);
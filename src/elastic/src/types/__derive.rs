@@ -38,6 +38,7 @@ pub use crate::types::{
         DocumentType,
         Id,
         Index,
+        Routing,
         StaticIndex,
         StaticType,
         Type,
@@ -14,6 +14,7 @@ use std::{
 pub use crate::params::{
     Id,
     Index,
+    Routing,
     Type,
 };
 
@@ -46,7 +47,16 @@ pub trait DocumentType: ObjectFieldType {
     fn ty(&self) -> Type;
 
     /** Try get an id for this document. */
-    fn partial_id(&self) -> Option<Id>;
+    fn partial_id(&self) -> Option<Id<'_>>;
+
+    /**
+    Try get a routing value for this document.
+
+    Requests for this document will be sent to the shard for this routing value instead of its id.
+    */
+    fn partial_routing(&self) -> Option<Routing<'_>> {
+        None
+    }
 
     /** Try get a statically known index this document belongs to. */
     fn partial_static_index() -> Option<Index<'static>>;
@@ -176,10 +186,14 @@ where
         (*self).ty()
     }
 
-    fn partial_id(&self) -> Option<Id> {
+    fn partial_id(&self) -> Option<Id<'_>> {
         (*self).partial_id()
     }
 
+    fn partial_routing(&self) -> Option<Routing<'_>> {
+        (*self).partial_routing()
+    }
+
     fn partial_static_index() -> Option<Index<'static>> {
         TDocument::partial_static_index()
     }
@@ -209,10 +223,14 @@ where
         self.as_ref().ty()
     }
 
-    fn partial_id(&self) -> Option<Id> {
+    fn partial_id(&self) -> Option<Id<'_>> {
         self.as_ref().partial_id()
     }
 
+    fn partial_routing(&self) -> Option<Routing<'_>> {
+        self.as_ref().partial_routing()
+    }
+
     fn partial_static_index() -> Option<Index<'static>> {
         TDocument::partial_static_index()
     }
@@ -364,6 +382,20 @@ mod tests {
     #[elastic(crate_root = "crate::types")]
     pub struct NoProps {}
 
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct RoutedType {
+        #[elastic(routing)]
+        pub tenant: String,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    #[elastic(routing(expr = "self.tenant_id.to_string()"))]
+    pub struct RoutedByExprType {
+        pub tenant_id: i32,
+    }
+
     #[derive(Default, Serialize)]
     pub struct Index {
         mappings: Mappings,
@@ -416,6 +448,32 @@ mod tests {
         assert_eq!("13", doc.partial_id().unwrap());
     }
 
+    #[test]
+    fn get_default_type_routing() {
+        let doc = SimpleType {
+            field1: Date::now(),
+            field2: SimpleNestedType { field: 1 },
+        };
+
+        assert!(doc.partial_routing().is_none());
+    }
+
+    #[test]
+    fn get_field_type_routing() {
+        let doc = RoutedType {
+            tenant: "tenant-1".to_owned(),
+        };
+
+        assert_eq!("tenant-1", doc.partial_routing().unwrap());
+    }
+
+    #[test]
+    fn get_expr_type_routing() {
+        let doc = RoutedByExprType { tenant_id: 7 };
+
+        assert_eq!("7", doc.partial_routing().unwrap());
+    }
+
     #[test]
     fn derive_custom_type_mapping() {
         assert_eq!(
@@ -495,6 +553,30 @@ mod tests {
         assert_eq!(expected, ser);
     }
 
+    #[test]
+    fn serialise_document_with_routing() {
+        let ser = serde_json::to_value(RoutedType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "_routing": {
+                "required": true
+            },
+            "properties": {
+                "tenant": {
+                    "type": "text",
+                    "fields": {
+                        "keyword":{
+                            "type": "keyword",
+                            "ignore_above": 256
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
     #[test]
     fn serialise_mapping_with_wrapped_types() {
         let ser = serde_json::to_value(&Wrapped::index_mapping()).unwrap();
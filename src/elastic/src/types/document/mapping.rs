@@ -54,6 +54,15 @@ pub trait ObjectMapping {
     fn include_in_all() -> Option<bool> {
         None
     }
+
+    /**
+    Whether or not a routing value is required to index, get, update or delete a document.
+
+    This is only used when the object is mapped as a document type.
+    */
+    fn routing_required() -> Option<bool> {
+        None
+    }
 }
 
 /**
@@ -233,8 +242,17 @@ mod private {
         where
             S: Serializer,
         {
-            let mut state = serializer.serialize_struct("mapping", 1)?;
+            #[derive(Serialize)]
+            struct RoutingMapping {
+                required: bool,
+            }
+
+            let routing = TMapping::routing_required().map(|required| RoutingMapping { required });
+
+            let mut state =
+                serializer.serialize_struct("mapping", 1 + routing.is_some() as usize)?;
 
+            ser_field!(state, "_routing", routing);
             state.serialize_field("properties", &Properties::<TMapping> { _m: PhantomData })?;
 
             state.end()
@@ -185,6 +185,29 @@ pub struct MyType {
 }
 ```
 
+### Specifying a routing field
+
+Documents will default to being routed to a shard using their id.
+The `#[elastic(routing)]` attribute can be used to specify a field to route documents with instead:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+pub struct MyType {
+    #[elastic(id)]
+    pub my_id: String,
+    #[elastic(routing)]
+    pub tenant_id: String,
+    pub my_string: String
+}
+```
+
+Like ids, a routing value can also be calculated using the `#[elastic(routing(expr = "expression"))]` attribute on the struct or a field.
+Index, create and bulk operations for the document will use its routing value automatically.
+Documents that declare a routing value are mapped with `_routing` set to required, so Elasticsearch will reject requests for them that don't include one.
+
 ### Override Default Mapping Properties
 
 You can override the mapping meta properties for an object by providing your own mapping type with `#[elastic(mapping="{TypeName}")]`:
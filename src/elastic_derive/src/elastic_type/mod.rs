@@ -59,7 +59,9 @@ pub fn expand_derive(
         .map(|f| f.unwrap())
         .collect();
 
-    let mapping = get_mapping(&crate_root, input);
+    let routing_required = has_routing(input, &fields);
+
+    let mapping = get_mapping(&crate_root, input, routing_required);
 
     let doc_ty_impl_block = get_doc_ty_impl_block(&crate_root, input, &fields, &mapping.ident);
 
@@ -88,6 +90,7 @@ pub fn expand_derive(
 fn get_mapping(
     crate_root: &proc_macro2::TokenStream,
     input: &DeriveInput,
+    routing_required: bool,
 ) -> ElasticDocumentMapping {
     // Define a struct for the mapping with a few defaults
     fn define_mapping(vis: &Visibility, name: &Ident) -> proc_macro2::TokenStream {
@@ -119,10 +122,23 @@ fn get_mapping(
         crate_root: &proc_macro2::TokenStream,
         mapping: &Ident,
         properties: &Ident,
+        routing_required: bool,
     ) -> proc_macro2::TokenStream {
+        let routing_required = if routing_required {
+            Some(quote!(
+                fn routing_required() -> ::std::option::Option<bool> {
+                    Some(true)
+                }
+            ))
+        } else {
+            None
+        };
+
         quote!(
             impl #crate_root::__derive::ObjectMapping for #mapping {
                 type Properties = #properties;
+
+                #routing_required
             }
         )
     }
@@ -136,7 +152,7 @@ fn get_mapping(
     } else {
         let ident = get_default_mapping(input);
         let definition = define_mapping(&input.vis, &ident);
-        let impl_block = impl_document_mapping(&crate_root, &ident, &input.ident);
+        let impl_block = impl_document_mapping(&crate_root, &ident, &input.ident, routing_required);
 
         ElasticDocumentMapping {
             ident,
@@ -170,6 +186,7 @@ fn get_doc_ty_impl_block(
             ty: proc_macro2::TokenStream,
             ty_is_static: bool,
             id: proc_macro2::TokenStream,
+            routing: proc_macro2::TokenStream,
         }
 
         // Get the default method blocks for `DocumentType`
@@ -223,12 +240,31 @@ fn get_doc_ty_impl_block(
                 .map(|id_expr| quote!(Some((#id_expr).into())))
                 .unwrap_or_else(|| quote!(None));
 
+            let routing = get_method_from_struct(item, "routing")
+                .map(|routing_expr| match routing_expr {
+                    MethodFromStruct::Expr(expr) => expr,
+                    _ => panic!("routing attributes on a struct definition must be of the form #[routing(expr = \"expression\")]"),
+                })
+                .or_else(|| {
+                    get_method_from_fields(fields, "routing").map(|field| match field {
+                        MethodFromField::Field(field) => quote!(&self . #field),
+                        MethodFromField::Expr(field, expr) => quote!({
+                            let #field = &self . #field;
+                            #expr
+                        }),
+                        _ => panic!("routing attributes on a field must be of the form #[routing] or #[routing(expr = \"expression\")]"),
+                    })
+                })
+                .map(|routing_expr| quote!(Some((#routing_expr).into())))
+                .unwrap_or_else(|| quote!(None));
+
             ElasticMetadataMethods {
                 index,
                 index_is_static,
                 ty,
                 ty_is_static,
                 id,
+                routing,
             }
         }
 
@@ -238,6 +274,7 @@ fn get_doc_ty_impl_block(
             ref ty,
             ty_is_static,
             ref id,
+            ref routing,
         } = get_doc_type_methods(crate_root, item, fields);
 
         let doc_ty = &item.ident;
@@ -295,10 +332,14 @@ fn get_doc_ty_impl_block(
                 (#ty).into()
             }
 
-            fn partial_id(&self) -> ::std::option::Option<#crate_root::__derive::Id> {
+            fn partial_id(&self) -> ::std::option::Option<#crate_root::__derive::Id<'_>> {
                 (#id).into()
             }
 
+            fn partial_routing(&self) -> ::std::option::Option<#crate_root::__derive::Routing<'_>> {
+                (#routing).into()
+            }
+
             #partial_static_index
 
             #partial_static_ty
@@ -378,6 +419,12 @@ fn get_props_impl_block(
     )
 }
 
+// Whether a routing value is declared on the struct or one of its fields
+fn has_routing(item: &DeriveInput, fields: &[(Ident, &Field)]) -> bool {
+    get_method_from_struct(item, "routing").is_some()
+        || get_method_from_fields(fields, "routing").is_some()
+}
+
 fn get_ser_field(field: &Field) -> Option<(Ident, &Field)> {
     let ctxt = serde_derive_internals::Ctxt::new();
     let serde_field =
@@ -6,7 +6,10 @@ test_cases![
     raw_index_get,
     raw_upsert,
     raw_upsert_update,
+    stream_flush,
+    stream_ops_limit,
     stream_tiny_size_limit,
+    stream_stats,
     stream_tiny_timeout,
     stream_zero_size_limit,
     stream
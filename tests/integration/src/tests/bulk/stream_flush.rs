@@ -0,0 +1,83 @@
+use elastic::{
+    client::requests::bulk::{
+        BulkShutdownSummary,
+        BulkStatsSample,
+    },
+    error::Error,
+    prelude::*,
+};
+use futures::{
+    stream,
+    Future,
+    Sink,
+    Stream,
+};
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "bulk_stream_flush")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct FlushResult {
+    flushed: BulkStatsSample,
+    shutdown: BulkShutdownSummary,
+}
+
+test! {
+    const description: &'static str = "stream flush and shutdown";
+
+    type Response = FlushResult;
+
+    // Ensure the index doesn't exist
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client
+            .index(Doc::static_index())
+            .delete()
+            .send()
+            .map(|_| ());
+
+        Box::new(delete_res)
+    }
+
+    // Stream some bulk operations, then flush them before the timeout expires
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let (bulk_stream, bulk_responses) = client
+            .bulk_stream()
+            .timeout(Duration::from_secs(300))
+            .build();
+
+        let stats = bulk_stream.stats();
+
+        let ops = (0..10).map(|i| bulk().index(Doc { id: i.to_string() }));
+
+        let req_future = bulk_stream
+            .send_all(stream::iter_ok(ops))
+            .and_then(|(bulk_stream, _)| bulk_stream.flush())
+            .and_then(move |bulk_stream| {
+                let flushed = stats.sample();
+
+                bulk_stream
+                    .shutdown(Duration::from_secs(10))
+                    .map(move |shutdown| FlushResult { flushed, shutdown })
+            });
+
+        let res_future = bulk_responses.for_each(|_| Ok(()));
+
+        Box::new(req_future.join(res_future).map(|(res, _)| res))
+    }
+
+    // Ensure every operation was acknowledged by the flush and nothing was left for shutdown
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        res.flushed.ops() == 10
+            && res.flushed.completed_batches() == 1
+            && !res.shutdown.timed_out()
+            && res.shutdown.unsent_ops() == 0
+    }
+}
@@ -0,0 +1,60 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::{
+    stream,
+    Future,
+    Sink,
+    Stream,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "bulk_stream_ops_limit")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+}
+
+test! {
+    const description: &'static str = "stream with an operation count limit";
+
+    type Response = Vec<usize>;
+
+    // Ensure the index doesn't exist
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client
+            .index(Doc::static_index())
+            .delete()
+            .send()
+            .map(|_| ());
+
+        Box::new(delete_res)
+    }
+
+    // Stream some bulk operations
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let (bulk_stream, bulk_responses) = client.bulk_stream().body_size_ops(5).build();
+
+        let ops = (0..20)
+            .map(|i| bulk().index(Doc { id: i.to_string() }));
+
+        let req_future = bulk_stream.send_all(stream::iter_ok(ops));
+
+        let res_future = bulk_responses.fold(Vec::new(), |mut batches, bulk| {
+            batches.push(bulk.into_iter().filter_map(Result::ok).count());
+
+            Ok(batches)
+        });
+
+        Box::new(req_future.join(res_future).map(|(_, batches)| batches))
+    }
+
+    // Ensure the we see 4 bulk responses with 5 successful items each
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        res == &vec![5, 5, 5, 5]
+    }
+}
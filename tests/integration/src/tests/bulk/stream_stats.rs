@@ -0,0 +1,91 @@
+use elastic::{
+    client::requests::bulk::BulkStatsSample,
+    error::Error,
+    prelude::*,
+};
+use futures::{
+    stream,
+    Future,
+    Sink,
+    Stream,
+};
+use std::sync::{
+    atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+    Arc,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "bulk_stream_stats")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct StatsResult {
+    callbacks: usize,
+    stats: BulkStatsSample,
+}
+
+test! {
+    const description: &'static str = "stream statistics";
+
+    type Response = StatsResult;
+
+    // Ensure the index doesn't exist
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client
+            .index(Doc::static_index())
+            .delete()
+            .send()
+            .map(|_| ());
+
+        Box::new(delete_res)
+    }
+
+    // Stream some bulk operations and sample the stream statistics
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let callbacks = Arc::new(AtomicUsize::new(0));
+
+        let (bulk_stream, bulk_responses) = {
+            let callbacks = callbacks.clone();
+
+            client
+                .bulk_stream()
+                .body_size_bytes(1)
+                .on_batch(move |_| {
+                    callbacks.fetch_add(1, Ordering::SeqCst);
+                })
+                .build()
+        };
+
+        let stats = bulk_responses.stats();
+
+        let ops = (0..20)
+            .map(|i| bulk().index(Doc { id: i.to_string() }));
+
+        let req_future = bulk_stream.send_all(stream::iter_ok(ops));
+
+        let res_future = bulk_responses.for_each(|_| Ok(()));
+
+        Box::new(req_future.join(res_future).map(move |_| StatsResult {
+            callbacks: callbacks.load(Ordering::SeqCst),
+            stats: stats.sample(),
+        }))
+    }
+
+    // Ensure every operation was counted, and each request invoked the callback
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        res.stats.ops() == 20
+            && res.stats.batches() == 20
+            && res.stats.completed_batches() == 20
+            && res.stats.failed_ops() == 0
+            && res.callbacks == 20
+    }
+}
@@ -0,0 +1,48 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+use std::time::Duration;
+
+const INDEX: &str = "cluster_health";
+
+test! {
+    const description: &'static str = "wait for cluster health";
+
+    type Response = ClusterHealthResponse;
+
+    // Ensure the index exists
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let create_res = client.index(INDEX).create().send().map(|_| ());
+
+        Box::new(create_res)
+    }
+
+    // Wait for the index to become available
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let res = client
+            .cluster()
+            .health()
+            .index(INDEX)
+            .wait_for_status(HealthStatus::Yellow)
+            .timeout(Duration::from_secs(30))
+            .level(HealthLevel::Indices)
+            .send();
+
+        Box::new(res)
+    }
+
+    // Ensure the index is at least yellow
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let index_ok = res
+            .index(INDEX)
+            .map(|index| index.status() >= HealthStatus::Yellow)
+            .unwrap_or(false);
+
+        !res.timed_out() && res.status() >= HealthStatus::Yellow && index_ok
+    }
+}
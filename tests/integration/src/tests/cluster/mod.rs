@@ -0,0 +1 @@
+test_cases![health, settings, state];
@@ -0,0 +1,40 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+test! {
+    const description: &'static str = "update and get cluster settings";
+
+    type Response = ClusterGetSettingsResponse;
+
+    // Update a transient setting
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let settings = ClusterSettings::new().setting(ClusterSetting::RecoveryMaxBytesPerSec, "50mb");
+
+        let put_res = client
+            .cluster()
+            .put_settings()
+            .transient(settings)
+            .send()
+            .map(|_| ());
+
+        Box::new(put_res)
+    }
+
+    // Get the cluster settings
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let res = client.cluster().get_settings().flat_settings().send();
+
+        Box::new(res)
+    }
+
+    // Ensure the transient setting is returned
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        res.transient().get_str(ClusterSetting::RecoveryMaxBytesPerSec) == Some("50mb")
+    }
+}
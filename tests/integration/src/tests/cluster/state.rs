@@ -0,0 +1,47 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+const INDEX: &str = "cluster_state";
+
+test! {
+    const description: &'static str = "get cluster state for an index";
+
+    type Response = ClusterStateResponse;
+
+    // Ensure the index exists
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let create_res = client.index(INDEX).create().send().map(|_| ());
+
+        Box::new(create_res)
+    }
+
+    // Get the metadata for the index
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let res = client
+            .cluster()
+            .state()
+            .metric(ClusterStateMetric::Metadata)
+            .metric(ClusterStateMetric::Nodes)
+            .index(INDEX)
+            .send();
+
+        Box::new(res)
+    }
+
+    // Ensure the index is open
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let index_open = res
+            .metadata()
+            .and_then(|metadata| metadata.index(INDEX))
+            .map(|index| index.state() == "open")
+            .unwrap_or(false);
+
+        index_open && res.nodes().count() > 0
+    }
+}
@@ -0,0 +1,64 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "check_mapping")]
+pub struct OldDoc {
+    #[elastic(id)]
+    id: String,
+    timestamp: Date<DefaultDateMapping>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "check_mapping")]
+pub struct NewDoc {
+    #[elastic(id)]
+    id: Keyword<DefaultKeywordMapping>,
+    title: String,
+}
+
+test! {
+    const description: &'static str = "check a derived mapping against a live mapping";
+
+    type Response = MappingDiff;
+
+    // Ensure the index exists with the old document mapping
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client.index(OldDoc::static_index()).delete().send();
+
+        let create_res = client.index(OldDoc::static_index()).create().send();
+        let mapping_res = client.document::<OldDoc>().put_mapping().send();
+
+        Box::new(
+            delete_res
+                .then(|_| create_res)
+                .and_then(|_| mapping_res)
+                .map(|_| ()),
+        )
+    }
+
+    // Check the new document mapping against the live one
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let res = client.document::<NewDoc>().check_mapping().send();
+
+        Box::new(res)
+    }
+
+    // Ensure the changes are reported
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let added = res.added().map(|change| change.name()).collect::<Vec<_>>();
+        let removed = res.removed().map(|change| change.name()).collect::<Vec<_>>();
+        let conflicts = res.conflicts().map(|change| change.name()).collect::<Vec<_>>();
+
+        res.requires_reindex()
+            && added == vec!["title"]
+            && removed == vec!["timestamp"]
+            && conflicts == vec!["id"]
+    }
+}
@@ -0,0 +1,61 @@
+use elastic::{
+    error::{
+        ApiError,
+        Error,
+    },
+    prelude::*,
+};
+use futures::Future;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "create_doc_idx")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+}
+
+const ID: &str = "1";
+
+fn doc() -> Doc {
+    Doc { id: ID.to_owned() }
+}
+
+test! {
+    const description: &'static str = "create an existing document";
+
+    type Response = IndexResponse;
+
+    // Ensure the index doesn't exist
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client
+            .index(Doc::static_index())
+            .delete()
+            .send()
+            .map(|_| ());
+
+        Box::new(delete_res)
+    }
+
+    // Create the same document twice
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let create_client = client.clone();
+        let res = client
+            .document()
+            .create(doc())
+            .send()
+            .and_then(move |_| create_client.document().create(doc()).send());
+
+        Box::new(res)
+    }
+
+    // Ensure a `DocumentAlreadyExists` error is returned
+    fn assert_err(&self, err: &Error) -> bool {
+        match *err {
+            Error::Api(ApiError::DocumentAlreadyExists { ref id, .. }) => id == ID,
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::{
+    future,
+    Future,
+};
+
+#[derive(Debug, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "delete_by_query_doc_idx")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+    status: String,
+}
+
+fn doc(id: i32, status: &str) -> Doc {
+    Doc {
+        id: id.to_string(),
+        status: status.to_owned(),
+    }
+}
+
+test! {
+    const description: &'static str = "delete documents matching a query";
+
+    type Response = BulkByScrollResponse;
+
+    // Ensure the index doesn't exist, then index some documents
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client.index(Doc::static_index()).delete().send();
+
+        let docs = vec![doc(1, "archived"), doc(2, "active"), doc(3, "archived")];
+
+        let index_reqs = future::join_all(docs.into_iter().map(move |doc| {
+            client
+                .document()
+                .index(doc)
+                .params_fluent(|p| p.url_param("refresh", true))
+                .send()
+        }));
+
+        Box::new(delete_res.then(|_| index_reqs.map(|_| ())))
+    }
+
+    // Delete the archived documents
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let res = client
+            .document::<Doc>()
+            .delete_by_query(json!({
+                "term": {
+                    "status": "archived"
+                }
+            }))
+            .conflicts(Conflicts::Proceed)
+            .refresh()
+            .send();
+
+        Box::new(res)
+    }
+
+    // Ensure only the archived documents were deleted
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        res.deleted() == 2 && res.failures().is_empty()
+    }
+}
@@ -0,0 +1,62 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "exists_doc_idx")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+}
+
+const ID: &str = "1";
+const MISSING_ID: &str = "2";
+
+fn doc() -> Doc {
+    Doc { id: ID.to_owned() }
+}
+
+test! {
+    const description: &'static str = "document exists";
+
+    type Response = (bool, bool, bool);
+
+    // Ensure the index doesn't exist
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client
+            .index(Doc::static_index())
+            .delete()
+            .send()
+            .map(|_| ());
+
+        Box::new(delete_res)
+    }
+
+    // Index a document and check whether it and another document exist
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let index_res = client.document().index(doc()).send();
+
+        let exists_res = client.document::<Doc>().exists(ID).send();
+        let source_exists_res = client.document::<Doc>().exists(ID).source().send();
+        let missing_res = client.document::<Doc>().exists(MISSING_ID).send();
+
+        Box::new(index_res.and_then(|_| {
+            exists_res
+                .join(source_exists_res)
+                .join(missing_res)
+                .map(|((exists, source_exists), missing)| (exists, source_exists, missing))
+        }))
+    }
+
+    // Ensure only the indexed document exists
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let (exists, source_exists, missing) = *res;
+
+        exists && source_exists && !missing
+    }
+}
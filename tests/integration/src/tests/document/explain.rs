@@ -0,0 +1,68 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+#[derive(Debug, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "explain_idx")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+    title: String,
+}
+
+const ID: &str = "1";
+
+fn doc() -> Doc {
+    Doc {
+        id: ID.to_owned(),
+        title: "Tuning relevance".to_owned(),
+    }
+}
+
+test! {
+    const description: &'static str = "index then explain a match";
+
+    type Response = ExplainResponse;
+
+    // Ensure the index doesn't exist
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client
+            .index(Doc::static_index())
+            .delete()
+            .send()
+            .map(|_| ());
+
+        Box::new(delete_res)
+    }
+
+    // Index a document, then explain a query that matches it
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let index_res = client
+            .document()
+            .index(doc())
+            .params_fluent(|p| p.url_param("refresh", true))
+            .send();
+
+        let explain_res = client
+            .document::<Doc>()
+            .explain(ID, json!({ "match": { "title": "relevance" } }))
+            .send();
+
+        Box::new(index_res.and_then(|_| explain_res))
+    }
+
+    // Ensure the document matched with a non-zero score
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let explanation = match res.explanation() {
+            Some(explanation) => explanation,
+            None => return false,
+        };
+
+        res.matched() && explanation.value() > 0.0 && !explanation.details().is_empty()
+    }
+}
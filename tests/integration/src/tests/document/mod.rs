@@ -1,12 +1,26 @@
 test_cases![
+    check_mapping,
+    create,
     delete,
+    delete_by_query,
+    exists,
+    explain,
+    get_source,
+    modify,
+    reindex,
+    routing,
     simple_index_get,
     simple_mapping,
+    source_filtering,
+    stale_index,
+    term_vectors,
+    update_by_query,
     update_no_index,
     update_with_doc,
     update_with_inline_script,
     update_with_script,
-    update_with_source
+    update_with_source,
+    update_with_upsert
 ];
 
 mod compile_test;
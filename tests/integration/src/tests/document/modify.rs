@@ -0,0 +1,81 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "modify_doc_idx")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+    likes: i64,
+}
+
+const ID: &str = "1";
+
+fn doc() -> Doc {
+    Doc {
+        id: ID.to_owned(),
+        likes: 0,
+    }
+}
+
+test! {
+    const description: &'static str = "read-modify-write a document";
+
+    type Response = (IndexResponse, GetResponse<Doc>);
+
+    // Ensure the index doesn't exist
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client
+            .index(Doc::static_index())
+            .delete()
+            .send()
+            .map(|_| ());
+
+        Box::new(delete_res)
+    }
+
+    // Index a document and then modify it twice
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let index_res = client
+            .document()
+            .index(doc())
+            .params_fluent(|p| p.url_param("refresh", true))
+            .send();
+
+        let first_modify_res = client
+            .document::<Doc>()
+            .modify(ID, |doc: &mut Doc| doc.likes += 1)
+            .send();
+
+        let second_modify_res = client
+            .document::<Doc>()
+            .modify(ID, |doc: &mut Doc| doc.likes += 1)
+            .send();
+
+        let get_res = client.document().get(ID).send();
+
+        Box::new(
+            index_res
+                .and_then(|_| first_modify_res)
+                .and_then(|_| second_modify_res)
+                .and_then(|modify| get_res.map(|get| (modify, get))),
+        )
+    }
+
+    // Ensure both modifications were applied
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let modify = &res.0;
+        let get = &res.1;
+
+        let correct_version = modify.version() == Some(3);
+        let correct_likes = get.document().map(|doc| doc.likes) == Some(2);
+
+        correct_version && correct_likes
+    }
+}
@@ -0,0 +1,70 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::{
+    future,
+    Future,
+};
+
+#[derive(Debug, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "reindex_source_doc_idx")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+    title: String,
+}
+
+const DEST_INDEX: &str = "reindex_dest_doc_idx";
+
+fn doc(id: i32) -> Doc {
+    Doc {
+        id: id.to_string(),
+        title: format!("Document {}", id),
+    }
+}
+
+test! {
+    const description: &'static str = "reindex documents from one index into another";
+
+    type Response = BulkByScrollResponse;
+
+    // Ensure the indices don't exist, then index some documents
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_src = client.index(Doc::static_index()).delete().send();
+        let delete_dest = client.index(DEST_INDEX).delete().send();
+
+        let index_reqs = future::join_all((1..=3).map(move |id| {
+            client
+                .document()
+                .index(doc(id))
+                .params_fluent(|p| p.url_param("refresh", true))
+                .send()
+        }));
+
+        Box::new(
+            delete_src
+                .then(|_| delete_dest)
+                .then(|_| index_reqs.map(|_| ())),
+        )
+    }
+
+    // Copy every document into the destination index
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let res = client
+            .reindex(Doc::static_index(), DEST_INDEX)
+            .op_type(OpType::Create)
+            .refresh()
+            .send();
+
+        Box::new(res)
+    }
+
+    // Ensure every document was created in the destination
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        res.total() == 3 && res.created() == 3 && res.failures().is_empty()
+    }
+}
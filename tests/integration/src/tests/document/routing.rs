@@ -0,0 +1,70 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "routing_doc_idx")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+    #[elastic(routing)]
+    tenant: String,
+}
+
+const ID: &str = "1";
+const TENANT: &str = "tenant-1";
+
+fn doc() -> Doc {
+    Doc {
+        id: ID.to_owned(),
+        tenant: TENANT.to_owned(),
+    }
+}
+
+test! {
+    const description: &'static str = "index and get a routed document";
+
+    type Response = GetResponse<Doc>;
+
+    // Ensure the index doesn't exist
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client
+            .index(Doc::static_index())
+            .delete()
+            .send()
+            .map(|_| ());
+
+        Box::new(delete_res)
+    }
+
+    // Put a mapping that requires routing, then index and get a document using its routing value
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let create_index = client.index(Doc::static_index()).create().send();
+
+        let put_mapping = client.document::<Doc>().put_mapping().send();
+
+        let index_res = client.document().index(doc()).send();
+
+        let get_res = client.document::<Doc>().get(ID).routing(TENANT).send();
+
+        Box::new(
+            create_index
+                .and_then(|_| put_mapping)
+                .and_then(|_| index_res)
+                .and_then(|_| get_res),
+        )
+    }
+
+    // Ensure the document was found
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let correct_doc = res.document() == Some(&doc());
+        let correct_routing = res.routing().as_deref() == Some(TENANT);
+
+        correct_doc && correct_routing
+    }
+}
@@ -0,0 +1,74 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "source_filtering_doc_idx")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+    title: String,
+    body: String,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Summary {
+    title: String,
+}
+
+const ID: &str = "1";
+const TITLE: &str = "A title";
+
+fn doc() -> Doc {
+    Doc {
+        id: ID.to_owned(),
+        title: TITLE.to_owned(),
+        body: "A long body that we don't want to fetch".to_owned(),
+    }
+}
+
+test! {
+    const description: &'static str = "get part of a document's source";
+
+    type Response = GetResponse<Summary>;
+
+    // Ensure the index doesn't exist
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client
+            .index(Doc::static_index())
+            .delete()
+            .send()
+            .map(|_| ());
+
+        Box::new(delete_res)
+    }
+
+    // Index a document, then get just its title
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let index_res = client.document().index(doc()).send();
+
+        let get_res = client
+            .document::<Doc>()
+            .get(ID)
+            .source_includes(vec!["title"])
+            .project::<Summary>()
+            .send();
+
+        Box::new(index_res.and_then(|_| get_res))
+    }
+
+    // Ensure only the title was returned
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let expected = Summary {
+            title: TITLE.to_owned(),
+        };
+
+        res.document() == Some(&expected)
+    }
+}
@@ -0,0 +1,77 @@
+use elastic::{
+    error::{
+        ApiError,
+        Error,
+    },
+    prelude::*,
+};
+use futures::Future;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "stale_index_idx")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+}
+
+const ID: &str = "1";
+
+fn doc() -> Doc {
+    Doc { id: ID.to_owned() }
+}
+
+test! {
+    const description: &'static str = "index with a stale sequence number";
+
+    type Response = IndexResponse;
+
+    // Ensure the index doesn't exist
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client
+            .index(Doc::static_index())
+            .delete()
+            .send()
+            .map(|_| ());
+
+        Box::new(delete_res)
+    }
+
+    // Index a document twice and then index it again using the first sequence number
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let index_client = client.clone();
+        let res = client
+            .document()
+            .index(doc())
+            .send()
+            .and_then(move |first| {
+                index_client
+                    .document()
+                    .index(doc())
+                    .send()
+                    .map(move |_| (index_client, first))
+            })
+            .and_then(|(client, first)| {
+                let seq_no = first.sequence_number().unwrap_or_default();
+                let primary_term = first.primary_term().unwrap_or_default();
+
+                client
+                    .document()
+                    .index(doc())
+                    .if_seq_no_primary_term(seq_no, primary_term)
+                    .send()
+            });
+
+        Box::new(res)
+    }
+
+    // Ensure a `VersionConflict` error is returned
+    fn assert_err(&self, err: &Error) -> bool {
+        match *err {
+            Error::Api(ApiError::VersionConflict { .. }) => true,
+            _ => false,
+        }
+    }
+}
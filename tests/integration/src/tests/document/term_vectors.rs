@@ -0,0 +1,89 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+#[derive(Debug, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "term_vectors_idx")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+    title: String,
+}
+
+const ID: &str = "1";
+
+fn doc() -> Doc {
+    Doc {
+        id: ID.to_owned(),
+        title: "term vectors for term statistics".to_owned(),
+    }
+}
+
+test! {
+    const description: &'static str = "index then get term vectors";
+
+    type Response = (TermVectorsResponse, MtermVectorsResponse);
+
+    // Ensure the index doesn't exist
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client
+            .index(Doc::static_index())
+            .delete()
+            .send()
+            .map(|_| ());
+
+        Box::new(delete_res)
+    }
+
+    // Index a document, then get its term vectors along with a missing document
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let index_res = client
+            .document()
+            .index(doc())
+            .params_fluent(|p| p.url_param("refresh", true))
+            .send();
+
+        let term_vectors_res = client
+            .document::<Doc>()
+            .term_vectors(ID)
+            .fields(vec!["title"])
+            .term_statistics(true)
+            .send();
+
+        let mterm_vectors_res = client
+            .document::<Doc>()
+            .mterm_vectors(vec![ID, "missing"])
+            .fields(vec!["title"])
+            .send();
+
+        Box::new(
+            index_res
+                .and_then(|_| term_vectors_res)
+                .and_then(|term_vectors| mterm_vectors_res.map(|mterm_vectors| (term_vectors, mterm_vectors))),
+        )
+    }
+
+    // Ensure the repeated term is counted and the missing document isn't found
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let term = res.0.field("title").and_then(|title| title.term("term"));
+
+        let correct_term_freq = term.map(|term| term.term_freq()) == Some(2);
+        let correct_doc_freq = term.and_then(|term| term.doc_freq()) == Some(1);
+        let correct_positions = term.map(|term| {
+            term.tokens()
+                .iter()
+                .filter_map(|token| token.position())
+                .collect::<Vec<_>>()
+        }) == Some(vec![0, 3]);
+
+        let found: Vec<_> = res.1.docs().map(|doc| doc.found()).collect();
+        let correct_found = found == vec![true, false];
+
+        correct_term_freq && correct_doc_freq && correct_positions && correct_found
+    }
+}
@@ -0,0 +1,66 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::{
+    future,
+    Future,
+};
+
+#[derive(Debug, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "update_by_query_doc_idx")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+    likes: i32,
+}
+
+fn doc(id: i32) -> Doc {
+    Doc {
+        id: id.to_string(),
+        likes: 0,
+    }
+}
+
+test! {
+    const description: &'static str = "update documents matching a query with a script";
+
+    type Response = BulkByScrollResponse;
+
+    // Ensure the index doesn't exist, then index some documents
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client.index(Doc::static_index()).delete().send();
+
+        let index_reqs = future::join_all((1..=3).map(move |id| {
+            client
+                .document()
+                .index(doc(id))
+                .params_fluent(|p| p.url_param("refresh", true))
+                .send()
+        }));
+
+        Box::new(delete_res.then(|_| index_reqs.map(|_| ())))
+    }
+
+    // Increment the likes of every document
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let res = client
+            .document::<Doc>()
+            .update_by_query(json!({ "match_all": {} }))
+            .script_fluent("ctx._source.likes += params.n", |script| {
+                script.param("n", 1)
+            })
+            .slices(Slices::Auto)
+            .send();
+
+        Box::new(res)
+    }
+
+    // Ensure every document was updated
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        res.updated() == 3 && res.version_conflicts() == 0
+    }
+}
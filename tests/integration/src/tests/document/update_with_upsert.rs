@@ -0,0 +1,72 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "update_doc_upsert_idx")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+    count: i64,
+}
+
+const ID: &str = "1";
+
+fn doc() -> Doc {
+    Doc {
+        id: ID.to_owned(),
+        count: 0,
+    }
+}
+
+test! {
+    const description: &'static str = "update with scripted upsert";
+
+    type Response = (UpdateResponse, UpdateResponse);
+
+    // Ensure the index doesn't exist
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client.index(Doc::static_index()).delete().send();
+
+        Box::new(delete_res.then(|_| Ok(())))
+    }
+
+    // Execute the same scripted upsert twice: the first creates the document and the second updates it
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let upsert = |client: &AsyncClient| {
+            client
+                .document::<Doc>()
+                .update(ID)
+                .script("ctx._source.count += 1")
+                .scripted_upsert()
+                .upsert(doc())
+                .source()
+                .params_fluent(|p| p.url_param("refresh", true))
+                .send()
+        };
+
+        let res = upsert(&client)
+            .and_then(move |created| upsert(&client).map(|updated| (created, updated)));
+
+        Box::new(res)
+    }
+
+    // Ensure the script ran for both the created and updated document
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let created = &res.0;
+        let updated = &res.1;
+
+        let count = |res: &UpdateResponse| res.into_document::<Doc>().map(|doc| doc.count);
+
+        let correct_results =
+            created.result() == UpdateResult::Created && updated.result() == UpdateResult::Updated;
+        let correct_counts = count(created) == Some(1) && count(updated) == Some(2);
+
+        correct_results && correct_counts
+    }
+}
@@ -0,0 +1,64 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+const OLD_INDEX: &str = "index_aliases_v1";
+const NEW_INDEX: &str = "index_aliases_v2";
+const ALIAS: &str = "index_aliases";
+
+test! {
+    const description: &'static str = "swap an alias between indices";
+
+    type Response = IndicesGetAliasResponse;
+
+    // Ensure both indices exist without any aliases
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client.index("index_aliases_*").delete().send();
+
+        let create_old_res = client.index(OLD_INDEX).create().send();
+        let create_new_res = client.index(NEW_INDEX).create().send();
+
+        Box::new(
+            delete_res
+                .and_then(|_| create_old_res)
+                .and_then(|_| create_new_res)
+                .map(|_| ()),
+        )
+    }
+
+    // Point the alias at the old index, then atomically move it to the new one
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let put_res = client.index(OLD_INDEX).put_alias(ALIAS).send();
+
+        let update_res = client
+            .update_aliases()
+            .remove(OLD_INDEX, ALIAS)
+            .add_fluent(NEW_INDEX, ALIAS, |alias| alias.is_write_index(true))
+            .send();
+
+        let get_res = client.index("_all").get_aliases().alias(ALIAS).send();
+
+        Box::new(
+            put_res
+                .and_then(|_| update_res)
+                .and_then(|_| get_res),
+        )
+    }
+
+    // Ensure the alias only points to the new index
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let indices: Vec<_> = res.indices_for_alias(ALIAS).collect();
+
+        let is_write_index = res
+            .index(NEW_INDEX)
+            .and_then(|aliases| aliases.get(ALIAS))
+            .and_then(|alias| alias.is_write_index());
+
+        indices == vec![NEW_INDEX] && is_write_index == Some(true)
+    }
+}
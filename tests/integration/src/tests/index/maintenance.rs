@@ -0,0 +1,45 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+const INDEX: &str = "index_maintenance";
+
+test! {
+    const description: &'static str = "refresh, flush and force merge an index";
+
+    type Response = IndicesShardsResponse;
+
+    // Ensure the index exists and has been refreshed and flushed
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client.index(INDEX).delete().send();
+
+        let create_res = client.index(INDEX).create().send();
+        let refresh_res = client.index(INDEX).refresh().send();
+        let flush_res = client.index(INDEX).flush().force().send();
+
+        Box::new(
+            delete_res
+                .then(|_| create_res)
+                .and_then(|_| refresh_res)
+                .and_then(|_| flush_res)
+                .map(|_| ()),
+        )
+    }
+
+    // Merge the index down to a single segment
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let res = client.index(INDEX).forcemerge(1).send();
+
+        Box::new(res)
+    }
+
+    // Ensure the merge succeeded on every shard
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        res.all_successful()
+    }
+}
@@ -0,0 +1,52 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "index_mapping")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+    title: String,
+    timestamp: Date<DefaultDateMapping>,
+}
+
+test! {
+    const description: &'static str = "get a typed mapping";
+
+    type Response = IndicesGetMappingResponse;
+
+    // Ensure the index exists with the document mapping
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client.index(Doc::static_index()).delete().send();
+
+        let create_res = client.index(Doc::static_index()).create().send();
+        let mapping_res = client.document::<Doc>().put_mapping().send();
+
+        Box::new(
+            delete_res
+                .then(|_| create_res)
+                .and_then(|_| mapping_res)
+                .map(|_| ()),
+        )
+    }
+
+    // Get the mapping for the index
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let res = client.index(Doc::static_index()).get_mapping().send();
+
+        Box::new(res)
+    }
+
+    // Ensure the mapping matches the document mapping
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let expected = IndexMapping::from_document::<Doc>().unwrap();
+
+        res.index("index_mapping") == Some(&expected)
+    }
+}
@@ -1,4 +1,11 @@
 test_cases![
+    aliases,
     does_not_exist,
-    exists
+    exists,
+    maintenance,
+    mapping,
+    rollover,
+    settings,
+    stats,
+    templates
 ];
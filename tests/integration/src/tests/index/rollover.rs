@@ -0,0 +1,53 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+const ALIAS: &str = "index_rollover";
+const OLD_INDEX: &str = "index_rollover-000001";
+const NEW_INDEX: &str = "index_rollover-000002";
+
+test! {
+    const description: &'static str = "roll an alias over to a new index";
+
+    type Response = IndicesRolloverResponse;
+
+    // Ensure only the first index exists and the alias points to it
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client.index("index_rollover-*").delete().send();
+
+        let create_res = client.index(OLD_INDEX).create().send();
+        let alias_res = client.index(OLD_INDEX).put_alias(ALIAS).send();
+
+        Box::new(
+            delete_res
+                .and_then(|_| create_res)
+                .and_then(|_| alias_res)
+                .map(|_| ()),
+        )
+    }
+
+    // Roll over the alias with a condition that is always met
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let res = client
+            .index(ALIAS)
+            .rollover()
+            .max_docs(0)
+            .settings(IndexSettings::new().number_of_shards(1))
+            .send();
+
+        Box::new(res)
+    }
+
+    // Ensure the alias was rolled over
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        res.rolled_over()
+            && res.old_index() == OLD_INDEX
+            && res.new_index() == NEW_INDEX
+            && res.matched_conditions().count() == 1
+    }
+}
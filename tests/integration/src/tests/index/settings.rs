@@ -0,0 +1,68 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+const INDEX: &str = "index_settings";
+
+test! {
+    const description: &'static str = "create an index with typed settings and update them";
+
+    type Response = IndicesGetSettingsResponse;
+
+    // Ensure the index doesn't exist
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client.index(INDEX).delete().send().map(|_| ());
+
+        Box::new(delete_res)
+    }
+
+    // Create the index with typed settings, then change the number of replicas
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let settings = IndexSettings::new()
+            .number_of_shards(2)
+            .number_of_replicas(0)
+            .max_result_window(500)
+            .analysis(Analysis::new().analyzer(
+                "lowercase_keyword",
+                Analyzer::custom("keyword").filter("lowercase"),
+            ));
+
+        let create_res = client.index(INDEX).create().settings(settings).send();
+
+        let put_res = client
+            .index(INDEX)
+            .put_settings(IndexSettings::new().number_of_replicas(1))
+            .send();
+
+        let get_res = client.index(INDEX).get_settings().send();
+
+        Box::new(
+            create_res
+                .and_then(|_| put_res)
+                .and_then(|_| get_res),
+        )
+    }
+
+    // Ensure the settings were applied
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let settings = match res.index(INDEX) {
+            Some(settings) => settings,
+            None => return false,
+        };
+
+        let has_analyzer = settings
+            .get_analysis()
+            .and_then(|analysis| analysis.get_analyzer("lowercase_keyword"))
+            .is_some();
+
+        settings.get_number_of_shards() == Some(2)
+            && settings.get_number_of_replicas() == Some(1)
+            && settings.get_max_result_window() == Some(500)
+            && has_analyzer
+    }
+}
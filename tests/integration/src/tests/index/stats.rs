@@ -0,0 +1,52 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+const INDEX: &str = "index_stats";
+
+test! {
+    const description: &'static str = "get typed index stats";
+
+    type Response = IndicesStatsResponse;
+
+    // Ensure the index exists
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client.index(INDEX).delete().send();
+        let create_res = client.index(INDEX).create().send();
+
+        Box::new(delete_res.then(|_| create_res).map(|_| ()))
+    }
+
+    // Get the docs and store stats for each shard
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let res = client
+            .index(INDEX)
+            .stats()
+            .metric(StatsMetric::Docs)
+            .metric(StatsMetric::Store)
+            .level(StatsLevel::Shards)
+            .send();
+
+        Box::new(res)
+    }
+
+    // Ensure only the requested stats are returned
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let index = match res.index(INDEX) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        let primaries = index.primaries();
+
+        primaries.docs().map(|docs| docs.count()) == Some(0)
+            && primaries.store().is_some()
+            && primaries.search().is_none()
+            && index.shards().any(|(_, shard)| shard.routing().primary())
+    }
+}
@@ -0,0 +1,65 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::Future;
+
+const TEMPLATE: &str = "index_templates";
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "index_templates")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+    timestamp: Date<DefaultDateMapping>,
+}
+
+test! {
+    const description: &'static str = "put and get an index template";
+
+    type Response = IndicesGetTemplateResponse;
+
+    // Ensure the template doesn't exist
+    // Deleting a missing template isn't an error we can match, so the result is ignored
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_res = client
+            .index_template(TEMPLATE)
+            .delete()
+            .send()
+            .then(|_| Ok(()));
+
+        Box::new(delete_res)
+    }
+
+    // Put a template for the document type and get it back
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let put_res = client
+            .index_template(TEMPLATE)
+            .put()
+            .index_patterns(vec!["index_templates-*"])
+            .order(1)
+            .settings(IndexSettings::new().number_of_shards(1))
+            .mapping::<Doc>()
+            .send();
+
+        let get_res = client.index_template(TEMPLATE).get().send();
+
+        Box::new(put_res.and_then(|_| get_res))
+    }
+
+    // Ensure the template contains the document mapping
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        let template = match res.template(TEMPLATE) {
+            Some(template) => template,
+            None => return false,
+        };
+
+        let patterns: Vec<_> = template.index_patterns().collect();
+        let timestamp = &template.mappings()["properties"]["timestamp"]["type"];
+
+        patterns == vec!["index_templates-*"] && template.order() == 1 && timestamp == "date"
+    }
+}
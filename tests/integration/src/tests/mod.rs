@@ -1,4 +1,4 @@
 #[macro_use]
 mod macros;
 
-test_groups![bulk, document, index, search, sql];
+test_groups![bulk, cluster, document, index, search, sql, tasks];
@@ -0,0 +1,40 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::{
+    future,
+    Future,
+};
+
+test! {
+    const description: &'static str = "list tasks in detail";
+
+    type Response = TasksListResponse;
+
+    // Tasks can be listed without any setup
+    fn prepare(&self, _client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        Box::new(future::ok(()))
+    }
+
+    // List the tasks that are listing tasks
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let res = client
+            .tasks()
+            .list()
+            .actions(vec!["cluster:monitor/tasks/lists*"])
+            .detailed()
+            .send();
+
+        Box::new(res)
+    }
+
+    // Ensure the list request itself was returned
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        res.tasks()
+            .any(|task| task.action().starts_with("cluster:monitor/tasks/lists"))
+    }
+}
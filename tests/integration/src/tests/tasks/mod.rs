@@ -0,0 +1 @@
+test_cases![list, poll_until_complete];
@@ -0,0 +1,71 @@
+use elastic::{
+    error::Error,
+    prelude::*,
+};
+use futures::{
+    future,
+    Future,
+};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize, ElasticType)]
+#[elastic(index = "poll_task_source_doc_idx")]
+pub struct Doc {
+    #[elastic(id)]
+    id: String,
+}
+
+const DEST_INDEX: &str = "poll_task_dest_doc_idx";
+
+test! {
+    const description: &'static str = "poll a background reindex task until it completes";
+
+    type Response = BulkByScrollResponse;
+
+    // Ensure the indices don't exist, then index some documents
+    fn prepare(&self, client: AsyncClient) -> Box<dyn Future<Item = (), Error = Error>> {
+        let delete_src = client.index(Doc::static_index()).delete().send();
+        let delete_dest = client.index(DEST_INDEX).delete().send();
+
+        let index_reqs = future::join_all((1..=3).map(move |id| {
+            client
+                .document()
+                .index(Doc { id: id.to_string() })
+                .params_fluent(|p| p.url_param("refresh", true))
+                .send()
+        }));
+
+        Box::new(
+            delete_src
+                .then(|_| delete_dest)
+                .then(|_| index_reqs.map(|_| ())),
+        )
+    }
+
+    // Start a reindex as a task and wait for it to finish
+    fn request(
+        &self,
+        client: AsyncClient,
+    ) -> Box<dyn Future<Item = Self::Response, Error = Error>> {
+        let tasks = client.tasks();
+
+        let res = client
+            .reindex(Doc::static_index(), DEST_INDEX)
+            .as_task()
+            .send()
+            .and_then(move |task| {
+                tasks
+                    .poll_until_complete(task.task().to_owned())
+                    .interval(Duration::from_millis(100))
+                    .timeout(Duration::from_secs(60))
+                    .send()
+            });
+
+        Box::new(res)
+    }
+
+    // Ensure every document was reindexed
+    fn assert_ok(&self, res: &Self::Response) -> bool {
+        res.total() == 3 && res.created() == 3
+    }
+}
@@ -37,11 +37,14 @@ fn call_future(client: AsyncClient, timeout_secs: u64) -> Box<dyn Future<Item =
                 .map_err(Error::from)
                 .and_then(move |_| {
                     client
-                        .ping()
+                        .cluster()
+                        .health()
+                        .wait_for_status(HealthStatus::Yellow)
+                        .timeout(Duration::from_secs(3))
                         .send()
                         .then(|r| {
                             let r: Result<_, Error> = match r {
-                                Ok(_) => Ok(((), true)),
+                                Ok(health) => Ok(((), !health.timed_out())),
                                 Err(_) => Ok(((), false)),
                             };
 